@@ -0,0 +1,309 @@
+use crate::macros::*;
+use crate::math::Coord;
+
+/// An iterator over the rasterized points of a Bézier curve with `N` control points.
+///
+/// The curve is sampled at the parameters `t = k / n` for `k = 0..=n`,
+/// where `n` is `N - 1` times the largest coordinate difference between consecutive
+/// control points. This bounds the speed of the curve, so consecutive samples
+/// differ by at most one along each axis. Each sample is rounded to the nearest point,
+/// with ties rounding up, using exact integer arithmetic.
+///
+/// Repeated samples are yielded once, and corners are thinned out: a point reached
+/// by a step along the major axis of the curve and left by a step along the minor axis
+/// is skipped, unless the previous point was skipped. This yields an 8-connected path
+/// without consecutive repeated points. A self-intersecting curve may still revisit a point.
+///
+/// Like line segments, curves are half-open: the last point is not yielded,
+/// so curves joined at an endpoint yield the shared point once.
+///
+/// `N` determines the degree:
+/// * `3`: [`QuadBezier`] (quadratic).
+/// * `4`: [`CubicBezier`] (cubic).
+///
+/// # Warning
+///
+/// Internally, this uses integers four times the size of the coordinate type,
+/// so quadratic curves are only available for coordinates of up to 32 bits,
+/// and cubic curves for coordinates of up to 16 bits.
+///
+/// # Performance
+///
+/// Each point takes time linear in the number of samples between it and the previous one.
+/// Clipped curves skip the samples far outside the clipping region.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Bezier<const N: usize, C: Coord> {
+    pub(crate) p: [(C::I2, C::I2); N],
+    /// The number of samples after the first one.
+    pub(crate) n: C::I2,
+    /// The first sample at the current point.
+    pub(crate) k: C::I2,
+    /// The previous and the current point of the path before thinning.
+    pub(crate) prev: Option<(C::I2, C::I2)>,
+    pub(crate) cur: Option<(C::I2, C::I2)>,
+    /// Whether the previous point was thinned out.
+    pub(crate) thin: bool,
+    pub(crate) next: Option<(C::I2, C::I2)>,
+    pub(crate) rows: (C::I2, C::I2),
+    pub(crate) cols: (C::I2, C::I2),
+}
+
+/// An iterator over the rasterized points of a quadratic Bézier curve.
+///
+/// See [`Bezier`] for the rasterization rules.
+pub type QuadBezier<C> = Bezier<3, C>;
+
+/// An iterator over the rasterized points of a cubic Bézier curve.
+///
+/// See [`Bezier`] for the rasterization rules.
+pub type CubicBezier<C> = Bezier<4, C>;
+
+macro_rules! bezier {
+    ($deg:ident $N:literal, $Cu:ty | $Ci:ty, $W:ty) => {
+        bezier!(@$deg $Cu, <$Cu as Coord>::I2, $W);
+        bezier!(@$deg $Ci, <$Ci as Coord>::I2, $W);
+        bezier!(@impl $N, $Cu, <$Cu as Coord>::I2, $W);
+        bezier!(@impl $N, $Ci, <$Ci as Coord>::I2, $W);
+    };
+    (@quad $C:ty, $I2:ty, $W:ty) => {
+        impl Bezier<3, $C> {
+            /// Returns a [`QuadBezier`] over the quadratic curve from `(x0, y0)` to `(x2, y2)`
+            /// with the control point `(x1, y1)`.
+            #[inline]
+            pub const fn new(x0: $C, y0: $C, x1: $C, y1: $C, x2: $C, y2: $C) -> Self {
+                let p = [(x0 as $I2, y0 as $I2), (x1 as $I2, y1 as $I2), (x2 as $I2, y2 as $I2)];
+                let (min, max) = (<$C>::MIN as $I2, <$C>::MAX as $I2);
+                Self::raw(p, (min, max), (min, max))
+            }
+
+            /// Returns the sample `0 <= k <= n`.
+            #[inline]
+            const fn at(&self, k: $I2) -> ($I2, $I2) {
+                let p = self.p;
+                if k == 0 {
+                    return p[0];
+                }
+                let (n, k) = (self.n as $W, k as $W);
+                let (a, b, d) = (2 * k * (n - k), k * k, n * n);
+                let x = a * (p[1].0 - p[0].0) as $W + b * (p[2].0 - p[0].0) as $W;
+                let y = a * (p[1].1 - p[0].1) as $W + b * (p[2].1 - p[0].1) as $W;
+                (p[0].0 + Self::round(x, d), p[0].1 + Self::round(y, d))
+            }
+
+            /// Returns `true` if the curve moves at least as fast along `X`
+            /// as along `Y` at sample `k`.
+            #[inline]
+            const fn x_major(&self, k: $I2) -> bool {
+                let p = self.p;
+                let (n, k) = (self.n as $W, k as $W);
+                let dx = (n - k) * (p[1].0 - p[0].0) as $W + k * (p[2].0 - p[1].0) as $W;
+                let dy = (n - k) * (p[1].1 - p[0].1) as $W + k * (p[2].1 - p[1].1) as $W;
+                dy.unsigned_abs() <= dx.unsigned_abs()
+            }
+        }
+    };
+    (@cubic $C:ty, $I2:ty, $W:ty) => {
+        impl Bezier<4, $C> {
+            /// Returns a [`CubicBezier`] over the cubic curve from `(x0, y0)` to `(x3, y3)`
+            /// with the control points `(x1, y1)` and `(x2, y2)`.
+            #[inline]
+            #[allow(clippy::too_many_arguments)]
+            pub const fn new(
+                x0: $C,
+                y0: $C,
+                x1: $C,
+                y1: $C,
+                x2: $C,
+                y2: $C,
+                x3: $C,
+                y3: $C,
+            ) -> Self {
+                let p = [
+                    (x0 as $I2, y0 as $I2),
+                    (x1 as $I2, y1 as $I2),
+                    (x2 as $I2, y2 as $I2),
+                    (x3 as $I2, y3 as $I2),
+                ];
+                let (min, max) = (<$C>::MIN as $I2, <$C>::MAX as $I2);
+                Self::raw(p, (min, max), (min, max))
+            }
+
+            /// Returns the sample `0 <= k <= n`.
+            #[inline]
+            const fn at(&self, k: $I2) -> ($I2, $I2) {
+                let p = self.p;
+                if k == 0 {
+                    return p[0];
+                }
+                let (n, k) = (self.n as $W, k as $W);
+                let (a, b, c, d) = (3 * k * (n - k) * (n - k), 3 * k * k * (n - k), k * k * k, n * n * n);
+                let x = a * (p[1].0 - p[0].0) as $W
+                    + b * (p[2].0 - p[0].0) as $W
+                    + c * (p[3].0 - p[0].0) as $W;
+                let y = a * (p[1].1 - p[0].1) as $W
+                    + b * (p[2].1 - p[0].1) as $W
+                    + c * (p[3].1 - p[0].1) as $W;
+                (p[0].0 + Self::round(x, d), p[0].1 + Self::round(y, d))
+            }
+
+            /// Returns `true` if the curve moves at least as fast along `X`
+            /// as along `Y` at sample `k`.
+            #[inline]
+            const fn x_major(&self, k: $I2) -> bool {
+                let p = self.p;
+                let (n, k) = (self.n as $W, k as $W);
+                let (a, b, c) = ((n - k) * (n - k), 2 * k * (n - k), k * k);
+                let dx = a * (p[1].0 - p[0].0) as $W
+                    + b * (p[2].0 - p[1].0) as $W
+                    + c * (p[3].0 - p[2].0) as $W;
+                let dy = a * (p[1].1 - p[0].1) as $W
+                    + b * (p[2].1 - p[1].1) as $W
+                    + c * (p[3].1 - p[2].1) as $W;
+                dy.unsigned_abs() <= dx.unsigned_abs()
+            }
+        }
+    };
+    (@impl $N:literal, $C:ty, $I2:ty, $W:ty) => {
+        impl Bezier<$N, $C> {
+            /// Samples at least this far outside the bounds are skipped in bulk.
+            const SKIP: $I2 = 16;
+
+            /// Returns a [`Bezier`] over the curve with control points `p`
+            /// restricted to the closed ranges `rows` and `cols`.
+            #[inline]
+            pub(crate) const fn raw(p: [($I2, $I2); $N], rows: ($I2, $I2), cols: ($I2, $I2)) -> Self {
+                let mut m = 0;
+                let mut i = 1;
+                while i < $N {
+                    let (dx, dy) = ((p[i].0 - p[i - 1].0).abs(), (p[i].1 - p[i - 1].1).abs());
+                    m = if m < dx { dx } else { m };
+                    m = if m < dy { dy } else { m };
+                    i += 1;
+                }
+                let n = m * ($N - 1);
+                let (prev, cur, thin, next) = (None, Some(p[0]), false, None);
+                let mut curve = Self { p, n, k: 0, prev, cur, thin, next, rows, cols };
+                curve.next = curve.seek();
+                curve
+            }
+
+            /// Returns `x / d` rounded to the nearest integer for `0 < d`, with ties rounding up.
+            #[inline]
+            const fn round(x: $W, d: $W) -> $I2 {
+                (2 * x + d).div_euclid(2 * d) as $I2
+            }
+
+            /// Returns `true` if the point `b` is reached from `a` by a step along the major
+            /// axis and left towards `c` by a step along the minor axis.
+            #[inline]
+            const fn corner(a: ($I2, $I2), b: ($I2, $I2), c: ($I2, $I2), x_major: bool) -> bool {
+                if x_major { a.1 == b.1 && b.0 == c.0 } else { a.0 == b.0 && b.1 == c.1 }
+            }
+
+            /// Returns the next point of the unclipped path, or [`None`] if there are none left.
+            #[inline]
+            const fn step(&mut self) -> Option<($I2, $I2)> {
+                loop {
+                    let cur = try_opt!(self.cur);
+                    let mut k = self.k;
+                    let next = loop {
+                        if k == self.n {
+                            break None;
+                        }
+                        k += 1;
+                        let q = self.at(k);
+                        if q.0 != cur.0 || q.1 != cur.1 {
+                            break Some(q);
+                        }
+                    };
+                    let x_major = self.x_major(self.k);
+                    self.k = k;
+                    // The last point is excluded.
+                    let Some(next) = next else {
+                        self.cur = None;
+                        return None;
+                    };
+                    let thin = !self.thin
+                        && match self.prev {
+                            Some(prev) => Self::corner(prev, cur, next, x_major),
+                            None => false,
+                        };
+                    (self.prev, self.cur, self.thin) = (Some(cur), Some(next), thin);
+                    if !thin {
+                        return Some(cur);
+                    }
+                }
+            }
+
+            /// Returns the next point of the path within the bounds, if any.
+            #[inline]
+            const fn seek(&mut self) -> Option<($I2, $I2)> {
+                loop {
+                    if let Some((x, y)) = self.cur {
+                        let d = self.rows.0 - y;
+                        let d = if d < y - self.rows.1 { y - self.rows.1 } else { d };
+                        let d = if d < self.cols.0 - x { self.cols.0 - x } else { d };
+                        let d = if d < x - self.cols.1 { x - self.cols.1 } else { d };
+                        // Samples move by at most one point along each axis, so the next
+                        // `d - SKIP` samples stay far outside. The thinning of the path
+                        // depends only on a few points, and catches up before reaching the bounds.
+                        if Self::SKIP < d {
+                            let k = self.k + (d - Self::SKIP);
+                            self.k = if self.n < k { self.n } else { k };
+                            (self.prev, self.cur, self.thin) = (None, Some(self.at(self.k)), false);
+                        }
+                    }
+                    let (x, y) = try_opt!(self.step());
+                    if self.rows.0 <= y && y <= self.rows.1 && self.cols.0 <= x && x <= self.cols.1 {
+                        return Some((x, y));
+                    }
+                }
+            }
+
+            /// Returns `true` if the iterator is empty.
+            #[inline]
+            pub const fn is_empty(&self) -> bool {
+                self.next.is_none()
+            }
+
+            /// Returns the point at the start of the iterator.
+            /// This does not advance the iterator.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn head(&self) -> Option<($C, $C)> {
+                let (x, y) = try_opt!(self.next);
+                Some((x as $C, y as $C))
+            }
+
+            /// Consumes and returns the point at the start of the iterator.
+            /// This advances the iterator forwards.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn pop_head(&mut self) -> Option<($C, $C)> {
+                let head = try_opt!(self.head());
+                self.next = self.seek();
+                Some(head)
+            }
+        }
+
+        impl Iterator for Bezier<$N, $C> {
+            type Item = ($C, $C);
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.pop_head()
+            }
+        }
+
+        impl core::iter::FusedIterator for Bezier<$N, $C> {}
+    };
+}
+
+clone!([const N: usize, C: Coord] Bezier<N, C>);
+
+bezier!(quad 3, u8 | i8, i64);
+bezier!(quad 3, u16 | i16, i64);
+bezier!(quad 3, u32 | i32, i128);
+bezier!(cubic 4, u8 | i8, i64);
+bezier!(cubic 4, u16 | i16, i128);
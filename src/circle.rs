@@ -0,0 +1,329 @@
+use crate::macros::*;
+use crate::math::Coord;
+
+/// An iterator over the rasterized points of a circle or a circular arc.
+///
+/// Points are computed with the midpoint circle algorithm: in the first octant,
+/// the point in row `y` is in the column `x` closest to `sqrt(r² - y²)`.
+/// The other seven octants are reflections of the first one.
+///
+/// Octants are numbered from `0` to `7`, starting at the positive `X` axis
+/// and turning towards the positive `Y` axis. Points are yielded octant by octant
+/// in this direction, each point exactly once. Points on the boundary between
+/// two octants belong to the even one, and the center of a circle
+/// with a zero radius belongs to octant `0`.
+///
+/// # Warning
+///
+/// Internally, this uses integers twice the size of the coordinate type.
+/// Keep this in mind when using `u64`, `i64`, `usize` and `isize` coordinates.
+/// If you do not need the whole range, prefer smaller coordinate types.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Circle<C: Coord> {
+    pub(crate) xc: C::I2,
+    pub(crate) yc: C::I2,
+    pub(crate) r: C::U,
+    /// The diagonal row of the first octant.
+    pub(crate) m: C::U,
+    /// Whether the first octant has a point exactly on the diagonal.
+    pub(crate) diag: bool,
+    pub(crate) x: C::U,
+    pub(crate) y: C::U,
+    pub(crate) e: C::I2,
+    pub(crate) end: C::U,
+    pub(crate) o: u8,
+    pub(crate) o_end: u8,
+    pub(crate) x_min: C::I2,
+    pub(crate) x_max: C::I2,
+    pub(crate) y_min: C::I2,
+    pub(crate) y_max: C::I2,
+}
+
+macro_rules! circle {
+    ($Cu:ty | $Ci:ty) => {
+        circle!($Cu, <$Cu as Coord>::U, <$Cu as Coord>::U2, <$Cu as Coord>::I2);
+        circle!($Ci, <$Ci as Coord>::U, <$Ci as Coord>::U2, <$Ci as Coord>::I2);
+    };
+    ($C:ty, $U:ty, $U2:ty, $I2:ty) => {
+        impl Circle<$C> {
+            /// Returns a [`Circle`] over the circle centered at `(xc, yc)` with radius `r`.
+            ///
+            /// Points that do not fit into the coordinate type are skipped.
+            #[inline]
+            pub const fn new(xc: $C, yc: $C, r: $U) -> Self {
+                Self::arc(xc, yc, r, 0, 0)
+            }
+
+            /// Returns a [`Circle`] over the arc of the circle centered at `(xc, yc)`
+            /// with radius `r`, covering the octants from `start` up to, but not including, `end`.
+            ///
+            /// Octants are taken modulo `8`. If `start` and `end` are equal,
+            /// this covers the whole circle.
+            ///
+            /// Points that do not fit into the coordinate type are skipped.
+            #[inline]
+            pub const fn arc(xc: $C, yc: $C, r: $U, start: u8, end: u8) -> Self {
+                let (xc, yc) = (xc as $I2, yc as $I2);
+                let (min, max) = (<$C>::MIN as $I2, <$C>::MAX as $I2);
+                let bounds = (min - xc, max - xc, min - yc, max - yc);
+                Self::raw(xc, yc, r, start, end, bounds)
+            }
+
+            /// Returns a [`Circle`] whose points relative to the center `(xc, yc)`
+            /// are restricted to the closed ranges `bounds = (x_min, x_max, y_min, y_max)`.
+            #[inline]
+            pub(crate) const fn raw(
+                xc: $I2,
+                yc: $I2,
+                r: $U,
+                start: u8,
+                end: u8,
+                (x_min, x_max, y_min, y_max): ($I2, $I2, $I2, $I2),
+            ) -> Self {
+                let (start, end) = (start % 8, end % 8);
+                let o_end = if start < end { end } else { end + 8 };
+                let (m, diag) = Self::diagonal(r);
+                let mut circle = Self {
+                    xc,
+                    yc,
+                    r,
+                    m,
+                    diag,
+                    x: 0,
+                    y: 0,
+                    e: 0,
+                    end: 0,
+                    o: start,
+                    o_end,
+                    x_min,
+                    x_max,
+                    y_min,
+                    y_max,
+                };
+                circle.seek(start);
+                circle
+            }
+
+            /// Returns the nearest integer to `sqrt(v)`.
+            #[inline]
+            const fn round_sqrt(v: $U2) -> $U2 {
+                let s = v.isqrt();
+                if s * s + s < v { s + 1 } else { s }
+            }
+
+            /// Returns the smallest integer not less than `sqrt(v)`.
+            #[inline]
+            pub(crate) const fn ceil_sqrt(v: $U2) -> $U2 {
+                let s = v.isqrt();
+                if s * s < v { s + 1 } else { s }
+            }
+
+            /// Returns the column of row `y <= r` in the first octant.
+            #[inline]
+            pub(crate) const fn x_at(r: $U, y: $U) -> $U {
+                let (r, y) = (r as $U2, y as $U2);
+                Self::round_sqrt(r * r - y * y) as $U
+            }
+
+            /// Returns the last row of the first octant,
+            /// and whether its point lies exactly on the diagonal.
+            #[inline]
+            pub(crate) const fn diagonal(r: $U) -> ($U, bool) {
+                if r == 0 {
+                    return (0, true);
+                }
+                let mut m = ((r as $U2 * r as $U2) / 2).isqrt() as $U;
+                while m < r && m < Self::x_at(r, m + 1) {
+                    m += 1;
+                }
+                while Self::x_at(r, m) < m {
+                    m -= 1;
+                }
+                (m, Self::x_at(r, m) == m)
+            }
+
+            /// Returns `(swap, sx, sy)`, such that the point `(x, y)` of the first octant
+            /// maps to `(sx * x, sy * y)` in octant `o`, with `x` and `y` swapped first if `swap`.
+            #[inline]
+            const fn transform(o: u8) -> (bool, bool, bool) {
+                match o % 8 {
+                    0 => (false, false, false),
+                    1 => (true, false, false),
+                    2 => (true, true, false),
+                    3 => (false, true, false),
+                    4 => (false, true, true),
+                    5 => (true, true, true),
+                    6 => (true, false, true),
+                    _ => (false, false, true),
+                }
+            }
+
+            /// Returns the closed range of rows of octant `o` within the bounds.
+            #[inline]
+            const fn run(&self, o: u8) -> Option<($U, $U)> {
+                let (swap, nx, ny) = Self::transform(o);
+                let (xa, xb) =
+                    if nx { (-self.x_max, -self.x_min) } else { (self.x_min, self.x_max) };
+                let (ya, yb) =
+                    if ny { (-self.y_max, -self.y_min) } else { (self.y_min, self.y_max) };
+                let (xa, xb, ya, yb) = if swap { (ya, yb, xa, xb) } else { (xa, xb, ya, yb) };
+                let r = self.r as $I2;
+                let (mut lo, mut hi) = if self.r == 0 && o % 8 != 0 {
+                    return None;
+                } else if o % 2 == 0 {
+                    (0, self.m as $I2)
+                } else {
+                    (1, self.m as $I2 - self.diag as $I2)
+                };
+                if lo < ya {
+                    lo = ya;
+                }
+                if yb < hi {
+                    hi = yb;
+                }
+                if xb < 0 || r < xa {
+                    return None;
+                }
+                let r2 = self.r as $U2 * self.r as $U2;
+                if xb < r {
+                    let xb = xb as $U2;
+                    let y = Self::ceil_sqrt(r2 - xb * xb - xb) as $I2;
+                    if lo < y {
+                        lo = y;
+                    }
+                }
+                if 0 < xa {
+                    let xa = xa as $U2;
+                    let y = Self::ceil_sqrt(r2 - xa * xa + xa) as $I2 - 1;
+                    if y < hi {
+                        hi = y;
+                    }
+                }
+                if hi < lo {
+                    return None;
+                }
+                Some((lo as $U, hi as $U))
+            }
+
+            /// Moves to the first non-empty run, starting with octant `o`.
+            #[inline]
+            const fn seek(&mut self, mut o: u8) {
+                while o < self.o_end {
+                    if let Some((lo, hi)) = self.run(o) {
+                        let (y, end) = if o % 2 == 0 { (lo, hi) } else { (hi, lo) };
+                        let x = Self::x_at(self.r, y);
+                        let (x2, y2) = (x as $U2 * x as $U2, y as $U2 * y as $U2);
+                        let r2 = self.r as $U2 * self.r as $U2;
+                        self.e = (x2 + y2).wrapping_sub(r2) as $I2;
+                        (self.x, self.y, self.end) = (x, y, end);
+                        break;
+                    }
+                    o += 1;
+                }
+                self.o = o;
+            }
+
+            /// Returns the number of points in octant `o`, or in the rest of it if current.
+            #[inline]
+            const fn run_len(&self, o: u8) -> $U2 {
+                if o == self.o {
+                    return (if self.y < self.end { self.end - self.y } else { self.y - self.end })
+                        as $U2
+                        + 1;
+                }
+                match self.run(o) {
+                    Some((lo, hi)) => (hi - lo) as $U2 + 1,
+                    None => 0,
+                }
+            }
+
+            /// Returns `true` if the iterator is empty.
+            #[inline]
+            pub const fn is_empty(&self) -> bool {
+                self.o_end <= self.o
+            }
+
+            /// Returns the remaining length of this iterator.
+            #[inline]
+            pub const fn len(&self) -> $U2 {
+                let mut len = 0;
+                let mut o = self.o;
+                while o < self.o_end {
+                    len += self.run_len(o);
+                    o += 1;
+                }
+                len
+            }
+
+            /// Returns the point at the start of the iterator.
+            /// This does not advance the iterator.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn head(&self) -> Option<($C, $C)> {
+                if self.is_empty() {
+                    return None;
+                }
+                let (swap, nx, ny) = Self::transform(self.o);
+                let (x, y) = (self.x as $I2, self.y as $I2);
+                let (x, y) = if swap { (y, x) } else { (x, y) };
+                let x = if nx { self.xc - x } else { self.xc + x };
+                let y = if ny { self.yc - y } else { self.yc + y };
+                Some((x as $C, y as $C))
+            }
+
+            /// Consumes and returns the point at the start of the iterator.
+            /// This advances the iterator forwards.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn pop_head(&mut self) -> Option<($C, $C)> {
+                let head = try_opt!(self.head());
+                let (x, y) = (self.x as $I2, self.y as $I2);
+                if self.y == self.end {
+                    self.seek(self.o + 1);
+                } else if self.o % 2 == 0 {
+                    self.e += 2 * y + 1;
+                    self.y += 1;
+                    if x <= self.e {
+                        self.e -= 2 * x - 1;
+                        self.x -= 1;
+                    }
+                } else {
+                    self.e -= 2 * y - 1;
+                    self.y -= 1;
+                    if self.e < -x {
+                        self.e += 2 * x + 1;
+                        self.x += 1;
+                    }
+                }
+                Some(head)
+            }
+        }
+
+        impl Iterator for Circle<$C> {
+            type Item = ($C, $C);
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.pop_head()
+            }
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match usize::try_from(self.len()) {
+                    Ok(len) => (len, Some(len)),
+                    Err(_) => (usize::MAX, None),
+                }
+            }
+        }
+
+        impl core::iter::FusedIterator for Circle<$C> {}
+    };
+}
+
+clone!([C: Coord] Circle<C>);
+
+circle!(u8 | i8);
+circle!(u16 | i16);
+circle!(u32 | i32);
+circle!(u64 | i64);
+circle!(usize | isize);
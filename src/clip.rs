@@ -1,10 +1,30 @@
 use crate::macros::*;
 use crate::math::{Coord, ops};
 
+mod bezier;
+mod circle;
+mod classify;
+mod disc;
+mod ellipse;
+mod ellipse_fill;
+mod excluding;
 mod line_a;
 mod line_b;
+mod line_bq;
 mod line_d;
+#[cfg(feature = "float")]
+mod line_f;
+mod line_r;
+mod line_s;
+mod line_thick;
+mod line_w;
 mod point;
+mod polygon_fill;
+mod polyline;
+mod raster_line;
+mod rect;
+mod region;
+mod triangle;
 
 /// A closed[^1] rectangular clipping region with a zero origin and a maximum corner.
 ///
@@ -30,12 +50,28 @@ pub struct Viewport<C: Coord> {
     pub(crate) y_max: C,
 }
 
+/// The visibility of a line segment in a clipping region.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Visibility {
+    /// The segment lies fully inside the region.
+    Inside,
+    /// The segment lies fully outside the region.
+    Outside,
+    /// The segment lies partially inside the region.
+    Partial,
+}
+
 macro_rules! clip {
     ($U:ty|$I:ty) => {
         clip!(@impl Clip<unsigned $U>, $U);
         clip!(@impl Clip<signed $I>, $U);
         clip!(@impl Viewport<$U>, $U);
         clip!(@impl Viewport<$I>, $U);
+
+        clip!(@impl Size for Clip<$U>, <$U as Coord>::U2, <$U as Coord>::I2);
+        clip!(@impl Size for Clip<$I>, <$U as Coord>::U2, <$U as Coord>::I2);
+        clip!(@impl Size for Viewport<$U>, <$U as Coord>::U2, <$U as Coord>::I2);
+        clip!(@impl Size for Viewport<$I>, <$U as Coord>::U2, <$U as Coord>::I2);
     };
     (@impl Clip<$signedness:ident $UI:ty>, $U:ty) => {
         impl Clip<$UI> {
@@ -129,6 +165,80 @@ macro_rules! clip {
             }
         }
     };
+    (@impl Size for Clip<$UI:ty>, $U2:ty, $I2:ty) => {
+        impl Clip<$UI> {
+            /// Returns a [`Clip`] with the given size, or [`None`] if either:
+            /// - `width` or `height` is zero,
+            /// - `width - 1` or `height - 1` is greater than the largest possible coordinate.
+            ///
+            /// Unlike [`Clip::from_size`], this accepts the size of the entire domain.
+            #[inline]
+            pub const fn from_wide_size(width: $U2, height: $U2) -> Option<Self> {
+                const MAX: $U2 = <$UI>::MAX as $U2 + 1;
+                if width == 0 || height == 0 || MAX < width || MAX < height {
+                    return None;
+                }
+                Some(Self { x_max: (width - 1) as $UI, y_max: (height - 1) as $UI })
+            }
+        }
+
+        clip!(@impl Size for Clip<$UI> { $U2, $I2 });
+    };
+    (@impl Size for Viewport<$UI:ty>, $U2:ty, $I2:ty) => {
+        impl Viewport<$UI> {
+            /// Returns a [`Viewport`] with the given minimum corner and size,
+            /// or [`None`] if either:
+            /// - `width` or `height` is zero,
+            /// - `x_min + width - 1` or `y_min + height - 1` is greater
+            ///   than the largest possible coordinate.
+            ///
+            /// Unlike [`Viewport::from_min_size`], this accepts the size of the entire domain.
+            #[inline]
+            pub const fn from_min_wide_size(
+                x_min: $UI,
+                y_min: $UI,
+                width: $U2,
+                height: $U2,
+            ) -> Option<Self> {
+                const MAX: $I2 = <$UI>::MAX as $I2;
+                if width == 0 || height == 0 {
+                    return None;
+                }
+                let (dx, dy) = (width - 1, height - 1);
+                if ((MAX - x_min as $I2) as $U2) < dx || ((MAX - y_min as $I2) as $U2) < dy {
+                    return None;
+                }
+                let x_max = (x_min as $I2 + dx as $I2) as $UI;
+                let y_max = (y_min as $I2 + dy as $I2) as $UI;
+                Some(Self { x_min, y_min, x_max, y_max })
+            }
+        }
+
+        clip!(@impl Size for Viewport<$UI> { $U2, $I2 });
+    };
+    (@impl Size for $Self:ident<$UI:ty> { $U2:ty, $I2:ty }) => {
+        impl $Self<$UI> {
+            /// Returns the number of columns of this clipping region.
+            #[inline]
+            pub const fn width(&self) -> $U2 {
+                (self.x_max as $I2 - self.x_min() as $I2) as $U2 + 1
+            }
+
+            /// Returns the number of rows of this clipping region.
+            #[inline]
+            pub const fn height(&self) -> $U2 {
+                (self.y_max as $I2 - self.y_min() as $I2) as $U2 + 1
+            }
+
+            /// Returns the number of points inside this clipping region,
+            /// or [`None`] if it does not fit into the widened type,
+            /// which only happens if the region covers the entire domain.
+            #[inline]
+            pub const fn area(&self) -> Option<$U2> {
+                self.width().checked_mul(self.height())
+            }
+        }
+    };
     (@impl[$($generics:tt)+] MinMax for $Self:ident<$UI:ty> { $self:ident, $x_min:expr, $y_min:expr }) => {
         impl<$($generics)*> $Self<$UI> {
             /// Returns the minimum `x` coordinate of this clipping region.
@@ -0,0 +1,168 @@
+use crate::bezier::{CubicBezier, QuadBezier};
+use crate::clip::{Clip, Viewport};
+use crate::math::Coord;
+
+macro_rules! clip_bezier {
+    ($U:ty | $I:ty) => {
+        clip_bezier!(@impl Clip<$U>, <$U as Coord>::I2);
+        clip_bezier!(@impl Clip<$I>, <$U as Coord>::I2);
+        clip_bezier!(@impl Viewport<$U>, <$U as Coord>::I2);
+        clip_bezier!(@impl Viewport<$I>, <$U as Coord>::I2);
+    };
+    ($U:ty | $I:ty, $deg:ident) => {
+        clip_bezier!(@pub impl Clip<$U>, $deg, <$U as Coord>::I2);
+        clip_bezier!(@pub impl Clip<$I>, $deg, <$U as Coord>::I2);
+        clip_bezier!(@pub impl Viewport<$U>, $deg, <$U as Coord>::I2);
+        clip_bezier!(@pub impl Viewport<$I>, $deg, <$U as Coord>::I2);
+
+        clip_bezier!(@pub impl Clip<$I, proj $U>, $deg, <$U as Coord>::I2);
+        clip_bezier!(@pub impl Viewport<$U, proj $U>, $deg, <$U as Coord>::I2);
+        clip_bezier!(@pub impl Viewport<$I, proj $U>, $deg, <$U as Coord>::I2);
+    };
+    (@impl $Self:ident<$UI:ty>, $I2:ty) => {
+        impl $Self<$UI> {
+            /// Returns the control points, rows and columns of this region relative to `(x, y)`.
+            #[inline]
+            const fn bezier_frame<const N: usize>(
+                &self,
+                x: $I2,
+                y: $I2,
+                p: [($UI, $UI); N],
+            ) -> ([($I2, $I2); N], ($I2, $I2), ($I2, $I2)) {
+                let mut q = [(0, 0); N];
+                let mut i = 0;
+                while i < N {
+                    q[i] = (p[i].0 as $I2 - x, p[i].1 as $I2 - y);
+                    i += 1;
+                }
+                let rows = (self.y_min() as $I2 - y, self.y_max as $I2 - y);
+                let cols = (self.x_min() as $I2 - x, self.x_max as $I2 - x);
+                (q, rows, cols)
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty>, quad, $I2:ty) => {
+        impl $Self<$UI> {
+            /// Clips the quadratic Bézier curve from `(x0, y0)` to `(x2, y2)`
+            /// with the control point `(x1, y1)` to this region. See [`QuadBezier::new`].
+            ///
+            /// Returns a [`QuadBezier`] over the points of the curve inside this
+            /// clipping region, or [`None`] if there are none.
+            #[inline]
+            pub const fn quad_bezier(
+                &self,
+                x0: $UI,
+                y0: $UI,
+                x1: $UI,
+                y1: $UI,
+                x2: $UI,
+                y2: $UI,
+            ) -> Option<QuadBezier<$UI>> {
+                let (p, rows, cols) = self.bezier_frame(0, 0, [(x0, y0), (x1, y1), (x2, y2)]);
+                let curve = QuadBezier::<$UI>::raw(p, rows, cols);
+                if curve.is_empty() {
+                    return None;
+                }
+                Some(curve)
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty>, cubic, $I2:ty) => {
+        impl $Self<$UI> {
+            /// Clips the cubic Bézier curve from `(x0, y0)` to `(x3, y3)` with the control
+            /// points `(x1, y1)` and `(x2, y2)` to this region. See [`CubicBezier::new`].
+            ///
+            /// Returns a [`CubicBezier`] over the points of the curve inside this
+            /// clipping region, or [`None`] if there are none.
+            #[inline]
+            #[allow(clippy::too_many_arguments)]
+            pub const fn cubic_bezier(
+                &self,
+                x0: $UI,
+                y0: $UI,
+                x1: $UI,
+                y1: $UI,
+                x2: $UI,
+                y2: $UI,
+                x3: $UI,
+                y3: $UI,
+            ) -> Option<CubicBezier<$UI>> {
+                let p = [(x0, y0), (x1, y1), (x2, y2), (x3, y3)];
+                let (p, rows, cols) = self.bezier_frame(0, 0, p);
+                let curve = CubicBezier::<$UI>::raw(p, rows, cols);
+                if curve.is_empty() {
+                    return None;
+                }
+                Some(curve)
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty, proj $U:ty>, quad, $I2:ty) => {
+        impl $Self<$UI> {
+            /// Clips and projects the quadratic Bézier curve from `(x0, y0)` to `(x2, y2)`
+            /// with the control point `(x1, y1)` to this region. See [`QuadBezier::new`].
+            ///
+            /// Returns a [`QuadBezier`] over the points of the curve inside this
+            /// clipping region relative to the region, or [`None`] if there are none.
+            #[inline]
+            pub const fn quad_bezier_proj(
+                &self,
+                x0: $UI,
+                y0: $UI,
+                x1: $UI,
+                y1: $UI,
+                x2: $UI,
+                y2: $UI,
+            ) -> Option<QuadBezier<$U>> {
+                let (x, y) = (self.x_min() as $I2, self.y_min() as $I2);
+                let (p, rows, cols) = self.bezier_frame(x, y, [(x0, y0), (x1, y1), (x2, y2)]);
+                let curve = QuadBezier::<$U>::raw(p, rows, cols);
+                if curve.is_empty() {
+                    return None;
+                }
+                Some(curve)
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty, proj $U:ty>, cubic, $I2:ty) => {
+        impl $Self<$UI> {
+            /// Clips and projects the cubic Bézier curve from `(x0, y0)` to `(x3, y3)`
+            /// with the control points `(x1, y1)` and `(x2, y2)` to this region.
+            /// See [`CubicBezier::new`].
+            ///
+            /// Returns a [`CubicBezier`] over the points of the curve inside this
+            /// clipping region relative to the region, or [`None`] if there are none.
+            #[inline]
+            #[allow(clippy::too_many_arguments)]
+            pub const fn cubic_bezier_proj(
+                &self,
+                x0: $UI,
+                y0: $UI,
+                x1: $UI,
+                y1: $UI,
+                x2: $UI,
+                y2: $UI,
+                x3: $UI,
+                y3: $UI,
+            ) -> Option<CubicBezier<$U>> {
+                let (x, y) = (self.x_min() as $I2, self.y_min() as $I2);
+                let p = [(x0, y0), (x1, y1), (x2, y2), (x3, y3)];
+                let (p, rows, cols) = self.bezier_frame(x, y, p);
+                let curve = CubicBezier::<$U>::raw(p, rows, cols);
+                if curve.is_empty() {
+                    return None;
+                }
+                Some(curve)
+            }
+        }
+    };
+}
+
+clip_bezier!(u8 | i8);
+clip_bezier!(u16 | i16);
+clip_bezier!(u32 | i32);
+clip_bezier!(u8 | i8, quad);
+clip_bezier!(u16 | i16, quad);
+clip_bezier!(u32 | i32, quad);
+clip_bezier!(u8 | i8, cubic);
+clip_bezier!(u16 | i16, cubic);
@@ -0,0 +1,114 @@
+use crate::circle::Circle;
+use crate::clip::{Clip, Viewport};
+use crate::math::Coord;
+
+macro_rules! clip_circle {
+    ($U:ty | $I:ty) => {
+        clip_circle!(@impl Clip<$U>, <$U as Coord>::I2);
+        clip_circle!(@impl Clip<$I>, <$U as Coord>::I2);
+        clip_circle!(@impl Viewport<$U>, <$U as Coord>::I2);
+        clip_circle!(@impl Viewport<$I>, <$U as Coord>::I2);
+
+        clip_circle!(@pub impl Clip<$U>, $U, <$U as Coord>::I2);
+        clip_circle!(@pub impl Clip<$I>, $U, <$U as Coord>::I2);
+        clip_circle!(@pub impl Viewport<$U>, $U, <$U as Coord>::I2);
+        clip_circle!(@pub impl Viewport<$I>, $U, <$U as Coord>::I2);
+
+        clip_circle!(@pub impl Clip<$I, proj $U>);
+        clip_circle!(@pub impl Viewport<$U, proj $U>);
+        clip_circle!(@pub impl Viewport<$I, proj $U>);
+    };
+    (@impl $Self:ident<$UI:ty>, $I2:ty) => {
+        impl $Self<$UI> {
+            /// Returns the bounds of this region relative to `(xc, yc)`.
+            #[inline]
+            pub(super) const fn bounds_from(&self, xc: $UI, yc: $UI) -> ($I2, $I2, $I2, $I2) {
+                let (xc, yc) = (xc as $I2, yc as $I2);
+                let (x_min, x_max) = (self.x_min() as $I2 - xc, self.x_max as $I2 - xc);
+                let (y_min, y_max) = (self.y_min() as $I2 - yc, self.y_max as $I2 - yc);
+                (x_min, x_max, y_min, y_max)
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty>, $U:ty, $I2:ty) => {
+        impl $Self<$UI> {
+            /// Clips the circle centered at `(xc, yc)` with radius `r` to this region.
+            ///
+            /// Returns a [`Circle`] over the points of the circle inside this
+            /// clipping region, or [`None`] if the circle lies fully outside.
+            #[inline]
+            pub const fn circle(&self, xc: $UI, yc: $UI, r: $U) -> Option<Circle<$UI>> {
+                self.arc(xc, yc, r, 0, 0)
+            }
+
+            /// Clips the arc of the circle centered at `(xc, yc)` with radius `r`,
+            /// covering the octants from `start` up to, but not including, `end`,
+            /// to this region. See [`Circle::arc`].
+            ///
+            /// Returns a [`Circle`] over the points of the arc inside this
+            /// clipping region, or [`None`] if the arc lies fully outside.
+            #[inline]
+            pub const fn arc(
+                &self,
+                xc: $UI,
+                yc: $UI,
+                r: $U,
+                start: u8,
+                end: u8,
+            ) -> Option<Circle<$UI>> {
+                let bounds = self.bounds_from(xc, yc);
+                let circle =
+                    Circle::<$UI>::raw(xc as $I2, yc as $I2, r, start, end, bounds);
+                if circle.is_empty() {
+                    return None;
+                }
+                Some(circle)
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty, proj $U:ty>) => {
+        impl $Self<$UI> {
+            /// Clips and projects the circle centered at `(xc, yc)` with radius `r`
+            /// to this region.
+            ///
+            /// Returns a [`Circle`] over the points of the circle inside this
+            /// clipping region relative to the region, or [`None`] if the circle
+            /// lies fully outside.
+            #[inline]
+            pub const fn circle_proj(&self, xc: $UI, yc: $UI, r: $U) -> Option<Circle<$U>> {
+                self.arc_proj(xc, yc, r, 0, 0)
+            }
+
+            /// Clips and projects the arc of the circle centered at `(xc, yc)`
+            /// with radius `r`, covering the octants from `start` up to,
+            /// but not including, `end`, to this region. See [`Circle::arc`].
+            ///
+            /// Returns a [`Circle`] over the points of the arc inside this
+            /// clipping region relative to the region, or [`None`] if the arc
+            /// lies fully outside.
+            #[inline]
+            pub const fn arc_proj(
+                &self,
+                xc: $UI,
+                yc: $UI,
+                r: $U,
+                start: u8,
+                end: u8,
+            ) -> Option<Circle<$U>> {
+                let bounds = self.bounds_from(xc, yc);
+                let (xc, yc) = (-bounds.0, -bounds.2);
+                let circle = Circle::<$U>::raw(xc, yc, r, start, end, bounds);
+                if circle.is_empty() {
+                    return None;
+                }
+                Some(circle)
+            }
+        }
+    };
+}
+
+clip_circle!(u8 | i8);
+clip_circle!(u16 | i16);
+clip_circle!(u32 | i32);
+clip_circle!(u64 | i64);
+clip_circle!(usize | isize);
@@ -0,0 +1,63 @@
+use crate::clip::{Clip, Viewport, Visibility};
+
+macro_rules! clip_classify {
+    ($U:ty | $I:ty) => {
+        clip_classify!(@pub impl Clip<$U>, $U);
+        clip_classify!(@pub impl Clip<$I>, $U);
+        clip_classify!(@pub impl Viewport<$U>, $U);
+        clip_classify!(@pub impl Viewport<$I>, $U);
+    };
+    (@pub impl $Self:ident<$UI:ty>, $U:ty) => {
+        impl $Self<$UI> {
+            /// Classifies the line segment `(x0, y0) -> (x1, y1)` against this region
+            /// by its endpoints and bounding box only.
+            ///
+            /// Returns [`Visibility::Inside`] if both endpoints are inside this region,
+            /// [`Visibility::Outside`] if the bounding box of the segment lies fully outside,
+            /// and [`Visibility::Partial`] otherwise, even if no rasterized point is visible.
+            /// Use [`Self::classify_line_b`] to account for the rasterization.
+            #[inline]
+            pub const fn classify(&self, x0: $UI, y0: $UI, x1: $UI, y1: $UI) -> Visibility {
+                if self.point(x0, y0) && self.point(x1, y1) {
+                    return Visibility::Inside;
+                }
+                let reject = match (x1 < x0, y1 < y0) {
+                    (false, false) => self.reject_bbox_closed::<false, false>(x0, y0, x1, y1),
+                    (false, true) => self.reject_bbox_closed::<false, true>(x0, y0, x1, y1),
+                    (true, false) => self.reject_bbox_closed::<true, false>(x0, y0, x1, y1),
+                    (true, true) => self.reject_bbox_closed::<true, true>(x0, y0, x1, y1),
+                };
+                if reject { Visibility::Outside } else { Visibility::Partial }
+            }
+
+            /// Classifies the directed, half-open line segment `(x0, y0) -> (x1, y1)`
+            /// against this region by the points of its [`LineB`](crate::LineB).
+            ///
+            /// Returns [`Visibility::Inside`] if all points are inside this region,
+            /// [`Visibility::Outside`] if none are, and [`Visibility::Partial`] otherwise.
+            /// A segment without points is [`Visibility::Outside`].
+            #[inline]
+            pub const fn classify_line_b(&self, x0: $UI, y0: $UI, x1: $UI, y1: $UI) -> Visibility {
+                let Some(line) = self.line_b(x0, y0, x1, y1) else {
+                    return Visibility::Outside;
+                };
+                let dx = <$UI>::abs_diff(x0, x1);
+                let dy = <$UI>::abs_diff(y0, y1);
+                let len: $U = if dx < dy { dy } else { dx };
+                if line.is_empty() {
+                    Visibility::Outside
+                } else if line.len() == len {
+                    Visibility::Inside
+                } else {
+                    Visibility::Partial
+                }
+            }
+        }
+    };
+}
+
+clip_classify!(u8 | i8);
+clip_classify!(u16 | i16);
+clip_classify!(u32 | i32);
+clip_classify!(u64 | i64);
+clip_classify!(usize | isize);
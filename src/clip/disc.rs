@@ -0,0 +1,75 @@
+use crate::clip::{Clip, Viewport};
+use crate::disc::Disc;
+use crate::math::Coord;
+
+macro_rules! clip_disc {
+    ($U:ty | $I:ty) => {
+        clip_disc!(@impl Clip<$U>, <$U as Coord>::I2);
+        clip_disc!(@impl Clip<$I>, <$U as Coord>::I2);
+        clip_disc!(@impl Viewport<$U>, <$U as Coord>::I2);
+        clip_disc!(@impl Viewport<$I>, <$U as Coord>::I2);
+
+        clip_disc!(@pub impl Clip<$U>, $U, <$U as Coord>::I2);
+        clip_disc!(@pub impl Clip<$I>, $U, <$U as Coord>::I2);
+        clip_disc!(@pub impl Viewport<$U>, $U, <$U as Coord>::I2);
+        clip_disc!(@pub impl Viewport<$I>, $U, <$U as Coord>::I2);
+
+        clip_disc!(@pub impl Clip<$I, proj $U>);
+        clip_disc!(@pub impl Viewport<$U, proj $U>);
+        clip_disc!(@pub impl Viewport<$I, proj $U>);
+    };
+    (@impl $Self:ident<$UI:ty>, $I2:ty) => {
+        impl $Self<$UI> {
+            /// Returns the rows and columns of this region relative to `(xc, yc)`.
+            #[inline]
+            pub(super) const fn disc_rows_cols(&self, xc: $UI, yc: $UI) -> (($I2, $I2), ($I2, $I2)) {
+                let (xc, yc) = (xc as $I2, yc as $I2);
+                let rows = (self.y_min() as $I2 - yc, self.y_max as $I2 - yc);
+                let cols = (self.x_min() as $I2 - xc, self.x_max as $I2 - xc);
+                (rows, cols)
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty>, $U:ty, $I2:ty) => {
+        impl $Self<$UI> {
+            /// Clips the filled disc centered at `(xc, yc)` with radius `r` to this region.
+            ///
+            /// Returns a [`Disc`] over the spans of the disc inside this
+            /// clipping region, or [`None`] if the disc lies fully outside.
+            #[inline]
+            pub const fn disc(&self, xc: $UI, yc: $UI, r: $U) -> Option<Disc<$UI>> {
+                let (rows, cols) = self.disc_rows_cols(xc, yc);
+                let disc = Disc::<$UI>::raw(xc as $I2, yc as $I2, r, rows, cols);
+                if disc.is_empty() {
+                    return None;
+                }
+                Some(disc)
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty, proj $U:ty>) => {
+        impl $Self<$UI> {
+            /// Clips and projects the filled disc centered at `(xc, yc)` with radius `r`
+            /// to this region.
+            ///
+            /// Returns a [`Disc`] over the spans of the disc inside this
+            /// clipping region relative to the region, or [`None`] if the disc
+            /// lies fully outside.
+            #[inline]
+            pub const fn disc_proj(&self, xc: $UI, yc: $UI, r: $U) -> Option<Disc<$U>> {
+                let (rows, cols) = self.disc_rows_cols(xc, yc);
+                let disc = Disc::<$U>::raw(-cols.0, -rows.0, r, rows, cols);
+                if disc.is_empty() {
+                    return None;
+                }
+                Some(disc)
+            }
+        }
+    };
+}
+
+clip_disc!(u8 | i8);
+clip_disc!(u16 | i16);
+clip_disc!(u32 | i32);
+clip_disc!(u64 | i64);
+clip_disc!(usize | isize);
@@ -0,0 +1,107 @@
+use crate::clip::{Clip, Viewport};
+use crate::ellipse::Ellipse;
+use crate::math::Coord;
+
+macro_rules! clip_ellipse {
+    ($U:ty | $I:ty) => {
+        clip_ellipse!(@pub impl Clip<$U>, $U, <$U as Coord>::I2);
+        clip_ellipse!(@pub impl Clip<$I>, $U, <$U as Coord>::I2);
+        clip_ellipse!(@pub impl Viewport<$U>, $U, <$U as Coord>::I2);
+        clip_ellipse!(@pub impl Viewport<$I>, $U, <$U as Coord>::I2);
+
+        clip_ellipse!(@pub impl Clip<$I, proj $U>);
+        clip_ellipse!(@pub impl Viewport<$U, proj $U>);
+        clip_ellipse!(@pub impl Viewport<$I, proj $U>);
+    };
+    (@pub impl $Self:ident<$UI:ty>, $U:ty, $I2:ty) => {
+        impl $Self<$UI> {
+            /// Clips the ellipse centered at `(xc, yc)` with radii `a` along `X`
+            /// and `b` along `Y` to this region.
+            ///
+            /// Returns an [`Ellipse`] over the points of the ellipse inside this
+            /// clipping region, or [`None`] if the ellipse lies fully outside.
+            #[inline]
+            pub const fn ellipse(&self, xc: $UI, yc: $UI, a: $U, b: $U) -> Option<Ellipse<$UI>> {
+                self.ellipse_arc(xc, yc, a, b, 0, 0)
+            }
+
+            /// Clips the arc of the ellipse centered at `(xc, yc)` with radii `a` along `X`
+            /// and `b` along `Y`, covering the quadrants from `start` up to,
+            /// but not including, `end`, to this region. See [`Ellipse::arc`].
+            ///
+            /// Returns an [`Ellipse`] over the points of the arc inside this
+            /// clipping region, or [`None`] if the arc lies fully outside.
+            #[inline]
+            pub const fn ellipse_arc(
+                &self,
+                xc: $UI,
+                yc: $UI,
+                a: $U,
+                b: $U,
+                start: u8,
+                end: u8,
+            ) -> Option<Ellipse<$UI>> {
+                let bounds = self.bounds_from(xc, yc);
+                let ellipse =
+                    Ellipse::<$UI>::raw(xc as $I2, yc as $I2, a, b, start, end, bounds);
+                if ellipse.is_empty() {
+                    return None;
+                }
+                Some(ellipse)
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty, proj $U:ty>) => {
+        impl $Self<$UI> {
+            /// Clips and projects the ellipse centered at `(xc, yc)` with radii `a`
+            /// along `X` and `b` along `Y` to this region.
+            ///
+            /// Returns an [`Ellipse`] over the points of the ellipse inside this
+            /// clipping region relative to the region, or [`None`] if the ellipse
+            /// lies fully outside.
+            #[inline]
+            pub const fn ellipse_proj(
+                &self,
+                xc: $UI,
+                yc: $UI,
+                a: $U,
+                b: $U,
+            ) -> Option<Ellipse<$U>> {
+                self.ellipse_arc_proj(xc, yc, a, b, 0, 0)
+            }
+
+            /// Clips and projects the arc of the ellipse centered at `(xc, yc)`
+            /// with radii `a` along `X` and `b` along `Y`, covering the quadrants
+            /// from `start` up to, but not including, `end`, to this region.
+            /// See [`Ellipse::arc`].
+            ///
+            /// Returns an [`Ellipse`] over the points of the arc inside this
+            /// clipping region relative to the region, or [`None`] if the arc
+            /// lies fully outside.
+            #[inline]
+            pub const fn ellipse_arc_proj(
+                &self,
+                xc: $UI,
+                yc: $UI,
+                a: $U,
+                b: $U,
+                start: u8,
+                end: u8,
+            ) -> Option<Ellipse<$U>> {
+                let bounds = self.bounds_from(xc, yc);
+                let (xc, yc) = (-bounds.0, -bounds.2);
+                let ellipse = Ellipse::<$U>::raw(xc, yc, a, b, start, end, bounds);
+                if ellipse.is_empty() {
+                    return None;
+                }
+                Some(ellipse)
+            }
+        }
+    };
+}
+
+clip_ellipse!(u8 | i8);
+clip_ellipse!(u16 | i16);
+clip_ellipse!(u32 | i32);
+clip_ellipse!(u64 | i64);
+clip_ellipse!(usize | isize);
@@ -0,0 +1,71 @@
+use crate::clip::{Clip, Viewport};
+use crate::ellipse_fill::EllipseFill;
+use crate::math::Coord;
+
+macro_rules! clip_ellipse_fill {
+    ($U:ty | $I:ty) => {
+        clip_ellipse_fill!(@pub impl Clip<$U>, $U, <$U as Coord>::I2);
+        clip_ellipse_fill!(@pub impl Clip<$I>, $U, <$U as Coord>::I2);
+        clip_ellipse_fill!(@pub impl Viewport<$U>, $U, <$U as Coord>::I2);
+        clip_ellipse_fill!(@pub impl Viewport<$I>, $U, <$U as Coord>::I2);
+
+        clip_ellipse_fill!(@pub impl Clip<$I, proj $U>);
+        clip_ellipse_fill!(@pub impl Viewport<$U, proj $U>);
+        clip_ellipse_fill!(@pub impl Viewport<$I, proj $U>);
+    };
+    (@pub impl $Self:ident<$UI:ty>, $U:ty, $I2:ty) => {
+        impl $Self<$UI> {
+            /// Clips the filled ellipse centered at `(xc, yc)` with radii `a` along `X`
+            /// and `b` along `Y` to this region.
+            ///
+            /// Returns an [`EllipseFill`] over the spans of the ellipse inside this
+            /// clipping region, or [`None`] if the ellipse lies fully outside.
+            #[inline]
+            pub const fn ellipse_fill(
+                &self,
+                xc: $UI,
+                yc: $UI,
+                a: $U,
+                b: $U,
+            ) -> Option<EllipseFill<$UI>> {
+                let (rows, cols) = self.disc_rows_cols(xc, yc);
+                let fill = EllipseFill::<$UI>::raw(xc as $I2, yc as $I2, a, b, rows, cols);
+                if fill.is_empty() {
+                    return None;
+                }
+                Some(fill)
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty, proj $U:ty>) => {
+        impl $Self<$UI> {
+            /// Clips and projects the filled ellipse centered at `(xc, yc)` with radii `a`
+            /// along `X` and `b` along `Y` to this region.
+            ///
+            /// Returns an [`EllipseFill`] over the spans of the ellipse inside this
+            /// clipping region relative to the region, or [`None`] if the ellipse
+            /// lies fully outside.
+            #[inline]
+            pub const fn ellipse_fill_proj(
+                &self,
+                xc: $UI,
+                yc: $UI,
+                a: $U,
+                b: $U,
+            ) -> Option<EllipseFill<$U>> {
+                let (rows, cols) = self.disc_rows_cols(xc, yc);
+                let fill = EllipseFill::<$U>::raw(-cols.0, -rows.0, a, b, rows, cols);
+                if fill.is_empty() {
+                    return None;
+                }
+                Some(fill)
+            }
+        }
+    };
+}
+
+clip_ellipse_fill!(u8 | i8);
+clip_ellipse_fill!(u16 | i16);
+clip_ellipse_fill!(u32 | i32);
+clip_ellipse_fill!(u64 | i64);
+clip_ellipse_fill!(usize | isize);
@@ -0,0 +1,92 @@
+use crate::clip::{Clip, Viewport};
+use crate::excluding::Excluding;
+use crate::line_a::LineA;
+use crate::line_b::LineB;
+use crate::line_d::LineD;
+use crate::macros::*;
+
+macro_rules! clip_excluding {
+    ($U:ty | $I:ty) => {
+        clip_excluding!(@pub impl Clip<$U>);
+        clip_excluding!(@pub impl Clip<$I>);
+        clip_excluding!(@pub impl Viewport<$U>);
+        clip_excluding!(@pub impl Viewport<$I>);
+    };
+    (@pub impl $Self:ident<$UI:ty>) => {
+        impl $Self<$UI> {
+            /// Clips the directed, half-open line segment `(x0, y0) -> (x1, y1)` to this region,
+            /// excluding the points inside any of the `holes`.
+            ///
+            /// Returns an [`Excluding`] over the [`LineB`] pieces of the segment inside this
+            /// clipping region and outside the holes, or [`None`] if there are none.
+            #[inline]
+            pub const fn line_b_excluding<'a>(
+                &self,
+                holes: &'a [Viewport<$UI>],
+                x0: $UI,
+                y0: $UI,
+                x1: $UI,
+                y1: $UI,
+            ) -> Option<Excluding<'a, $UI, LineB<$UI>>> {
+                let line = try_opt!(self.line_b(x0, y0, x1, y1));
+                let pieces = Excluding::<$UI, LineB<$UI>>::raw(line, holes, x0, y0, x1, y1);
+                if pieces.is_empty() {
+                    return None;
+                }
+                Some(pieces)
+            }
+
+            /// Clips the directed, half-open diagonal line segment `(x0, y0) -> (x1, y1)`
+            /// to this region, excluding the points inside any of the `holes`.
+            ///
+            /// Returns an [`Excluding`] over the [`LineD`] pieces of the segment inside this
+            /// clipping region and outside the holes, or [`None`] if the segment is not
+            /// diagonal or there are no pieces.
+            #[inline]
+            pub const fn line_d_excluding<'a>(
+                &self,
+                holes: &'a [Viewport<$UI>],
+                x0: $UI,
+                y0: $UI,
+                x1: $UI,
+                y1: $UI,
+            ) -> Option<Excluding<'a, $UI, LineD<$UI>>> {
+                let line = try_opt!(self.line_d(x0, y0, x1, y1));
+                let pieces = Excluding::<$UI, LineD<$UI>>::raw(line, holes, x0, y0, x1, y1);
+                if pieces.is_empty() {
+                    return None;
+                }
+                Some(pieces)
+            }
+
+            /// Clips the directed, half-open line segment `(x0, y0) -> (x1, y1)` to this region
+            /// if it is aligned to axis `X` or `Y`, excluding the points inside any of the `holes`.
+            ///
+            /// Returns an [`Excluding`] over the [`LineA`] pieces of the segment inside this
+            /// clipping region and outside the holes, or [`None`] if the segment is not
+            /// aligned to an axis or there are no pieces.
+            #[inline]
+            pub const fn line_a_excluding<'a>(
+                &self,
+                holes: &'a [Viewport<$UI>],
+                x0: $UI,
+                y0: $UI,
+                x1: $UI,
+                y1: $UI,
+            ) -> Option<Excluding<'a, $UI, LineA<$UI>>> {
+                let line = try_opt!(self.line_a(x0, y0, x1, y1));
+                let pieces = Excluding::<$UI, LineA<$UI>>::raw(line, holes, x0, y0, x1, y1);
+                if pieces.is_empty() {
+                    return None;
+                }
+                Some(pieces)
+            }
+        }
+    };
+}
+
+clip_excluding!(u8 | i8);
+clip_excluding!(u16 | i16);
+clip_excluding!(u32 | i32);
+clip_excluding!(u64 | i64);
+clip_excluding!(usize | isize);
@@ -22,11 +22,20 @@ macro_rules! clip_line_b {
         clip_line_b!(@pub impl Clip<$I, proj $U>);
         clip_line_b!(@pub impl Viewport<$U, proj $U>);
         clip_line_b!(@pub impl Viewport<$I, proj $U>);
+
+        clip_line_b!(@pub impl Clip<$U>, entry $U);
+        clip_line_b!(@pub impl Clip<$I>, entry $U);
+        clip_line_b!(@pub impl Viewport<$U>, entry $U);
+        clip_line_b!(@pub impl Viewport<$I>, entry $U);
+
+        clip_line_b!(@pub impl Clip<$I, proj $U>, entry);
+        clip_line_b!(@pub impl Viewport<$U, proj $U>, entry);
+        clip_line_b!(@pub impl Viewport<$I, proj $U>, entry);
     };
     (@impl $Self:ident<$UI:ty>, $U:ty, $U2:ty, $I2:ty) => {
         impl $Self<$UI> {
             #[inline]
-            const fn reject_bbox_closed<const FX: bool, const FY: bool>(
+            pub(super) const fn reject_bbox_closed<const FX: bool, const FY: bool>(
                 &self,
                 x0: $UI,
                 y0: $UI,
@@ -635,6 +644,60 @@ macro_rules! clip_line_b {
             }
         }
     };
+    (@pub impl $Self:ident<$UI:ty>, entry $U:ty) => {
+        impl $Self<$UI> {
+            /// Returns the first and last points of the directed, half-open line segment
+            /// `(x0, y0) -> (x1, y1)` inside this region, without iterating over the points.
+            ///
+            /// Each point is returned as `(x, y, i)`, where `i` is its index
+            /// in the unclipped [`LineB`]. Returns [`None`] if the segment lies fully outside.
+            #[inline]
+            pub const fn line_b_entry_exit(
+                &self,
+                x0: $UI,
+                y0: $UI,
+                x1: $UI,
+                y1: $UI,
+            ) -> Option<(($UI, $UI, $U), ($UI, $UI, $U))> {
+                let line = try_opt!(self.line_b(x0, y0, x1, y1));
+                let (cx0, cy0) = try_opt!(line.head());
+                let (cx1, cy1) = try_opt!(line.tail());
+                // The index is the distance along the major axis,
+                // which is never shorter than the one along the minor axis.
+                let (dx0, _) = ops::<$UI>::susub(cx0, x0);
+                let (dy0, _) = ops::<$UI>::susub(cy0, y0);
+                let i0 = if dx0 < dy0 { dy0 } else { dx0 };
+                let i1 = i0 + (line.len() - 1);
+                Some(((cx0, cy0, i0), (cx1, cy1, i1)))
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty, proj $U:ty>, entry) => {
+        impl $Self<$UI> {
+            /// Returns the first and last points of the directed, half-open line segment
+            /// `(x0, y0) -> (x1, y1)` inside this region relative to the region,
+            /// without iterating over the points.
+            ///
+            /// Each point is returned as `(x, y, i)`, where `i` is its index
+            /// in the unclipped [`LineB`]. Returns [`None`] if the segment lies fully outside.
+            #[inline]
+            pub const fn line_b_entry_exit_proj(
+                &self,
+                x0: $UI,
+                y0: $UI,
+                x1: $UI,
+                y1: $UI,
+            ) -> Option<(($U, $U, $U), ($U, $U, $U))> {
+                let ((cx0, cy0, i0), (cx1, cy1, i1)) =
+                    try_opt!(self.line_b_entry_exit(x0, y0, x1, y1));
+                let cx0 = ops::<$UI>::wusub(cx0, self.x_min());
+                let cy0 = ops::<$UI>::wusub(cy0, self.y_min());
+                let cx1 = ops::<$UI>::wusub(cx1, self.x_min());
+                let cy1 = ops::<$UI>::wusub(cy1, self.y_min());
+                Some(((cx0, cy0, i0), (cx1, cy1, i1)))
+            }
+        }
+    };
 }
 
 clip_line_b!(u8 | i8);
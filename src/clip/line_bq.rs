@@ -0,0 +1,74 @@
+use crate::clip::{Clip, Viewport};
+use crate::line_bq::LineBq;
+use crate::macros::*;
+use crate::math::{Coord, ops};
+
+macro_rules! clip_line_bq {
+    ($U:ty | $I:ty) => {
+        clip_line_bq!(@pub impl Clip<$U>, <$U as Coord>::I2);
+        clip_line_bq!(@pub impl Clip<$I>, <$U as Coord>::I2);
+        clip_line_bq!(@pub impl Viewport<$U>, <$U as Coord>::I2);
+        clip_line_bq!(@pub impl Viewport<$I>, <$U as Coord>::I2);
+
+        clip_line_bq!(@pub impl Clip<$I, proj $U>);
+        clip_line_bq!(@pub impl Viewport<$U, proj $U>);
+        clip_line_bq!(@pub impl Viewport<$I, proj $U>);
+    };
+    (@pub impl $Self:ident<$UI:ty>, $I2:ty) => {
+        impl $Self<$UI> {
+            /// Clips the directed, half-open line segment `(x0, y0) -> (x1, y1)`
+            /// between fixed-point positions with `F` fractional bits to this region
+            /// of pixels. See [`LineBq`].
+            ///
+            /// Returns a [`LineBq`] over the points of the segment inside this
+            /// clipping region, or [`None`] if the segment lies fully outside.
+            #[inline]
+            pub const fn line_bq<const F: u32>(
+                &self,
+                x0: $UI,
+                y0: $UI,
+                x1: $UI,
+                y1: $UI,
+            ) -> Option<LineBq<F, $UI>> {
+                let rows = (self.y_min() as $I2, self.y_max as $I2);
+                let cols = (self.x_min() as $I2, self.x_max as $I2);
+                let line = LineBq::<F, $UI>::raw(x0, y0, x1, y1, rows, cols);
+                if line.is_empty() {
+                    return None;
+                }
+                Some(line)
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty, proj $U:ty>) => {
+        impl $Self<$UI> {
+            /// Clips and projects the directed, half-open line segment `(x0, y0) -> (x1, y1)`
+            /// between fixed-point positions with `F` fractional bits to this region
+            /// of pixels. See [`LineBq`].
+            ///
+            /// Returns a [`LineBq`] over the points of the segment inside this
+            /// clipping region relative to the region, or [`None`] if the segment
+            /// lies fully outside.
+            #[inline]
+            pub const fn line_bq_proj<const F: u32>(
+                &self,
+                x0: $UI,
+                y0: $UI,
+                x1: $UI,
+                y1: $UI,
+            ) -> Option<LineBq<F, $U>> {
+                let LineBq { x, y, err, lim, inc, len, sx, sy, yx } =
+                    try_opt!(self.line_bq::<F>(x0, y0, x1, y1));
+                let x = ops::<$UI>::wusub(x, self.x_min());
+                let y = ops::<$UI>::wusub(y, self.y_min());
+                Some(LineBq { x, y, err, lim, inc, len, sx, sy, yx })
+            }
+        }
+    };
+}
+
+clip_line_bq!(u8 | i8);
+clip_line_bq!(u16 | i16);
+clip_line_bq!(u32 | i32);
+clip_line_bq!(u64 | i64);
+clip_line_bq!(usize | isize);
@@ -0,0 +1,81 @@
+use crate::clip::{Clip, Viewport};
+use crate::line_f::LineF;
+use crate::math::Coord;
+
+macro_rules! clip_line_f {
+    ($U:ty | $I:ty) => {
+        clip_line_f!(@impl Clip<$U>, <$U as Coord>::I2);
+        clip_line_f!(@impl Clip<$I>, <$U as Coord>::I2);
+        clip_line_f!(@impl Viewport<$U>, <$U as Coord>::I2);
+        clip_line_f!(@impl Viewport<$I>, <$U as Coord>::I2);
+
+        clip_line_f!(@pub impl Clip<$U>, <$U as Coord>::I2);
+        clip_line_f!(@pub impl Clip<$I>, <$U as Coord>::I2);
+        clip_line_f!(@pub impl Viewport<$U>, <$U as Coord>::I2);
+        clip_line_f!(@pub impl Viewport<$I>, <$U as Coord>::I2);
+
+        clip_line_f!(@pub impl Clip<$I, proj $U>, <$U as Coord>::I2);
+        clip_line_f!(@pub impl Viewport<$U, proj $U>, <$U as Coord>::I2);
+        clip_line_f!(@pub impl Viewport<$I, proj $U>, <$U as Coord>::I2);
+    };
+    (@impl $Self:ident<$UI:ty>, $I2:ty) => {
+        impl $Self<$UI> {
+            /// Returns the closed ranges of rows and columns of this region.
+            #[inline]
+            const fn line_f_bounds(&self) -> (($I2, $I2), ($I2, $I2)) {
+                let rows = (self.y_min() as $I2, self.y_max as $I2);
+                let cols = (self.x_min() as $I2, self.x_max as $I2);
+                (rows, cols)
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty>, $I2:ty) => {
+        impl $Self<$UI> {
+            /// Clips the directed, half-open line segment `(x0, y0) -> (x1, y1)`
+            /// between floating-point positions to this region. See [`LineF`].
+            ///
+            /// Returns a [`LineF`] over the points of the segment inside this
+            /// clipping region, or [`None`] if either:
+            /// - any position is not finite,
+            /// - the segment lies fully outside.
+            #[inline]
+            pub const fn line_f(&self, x0: f64, y0: f64, x1: f64, y1: f64) -> Option<LineF<$UI>> {
+                let (rows, cols) = self.line_f_bounds();
+                match LineF::<$UI>::raw(x0, y0, x1, y1, rows, cols, (0, 0)) {
+                    Some(line) if !line.is_empty() => Some(line),
+                    _ => None,
+                }
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty, proj $U:ty>, $I2:ty) => {
+        impl $Self<$UI> {
+            /// Clips and projects the directed, half-open line segment `(x0, y0) -> (x1, y1)`
+            /// between floating-point positions to this region. See [`LineF`].
+            ///
+            /// Returns a [`LineF`] over the points of the segment inside this
+            /// clipping region relative to the region, or [`None`] if either:
+            /// - any position is not finite,
+            /// - the segment lies fully outside.
+            #[inline]
+            pub const fn line_f_proj(
+                &self,
+                x0: f64,
+                y0: f64,
+                x1: f64,
+                y1: f64,
+            ) -> Option<LineF<$U>> {
+                let (rows, cols) = self.line_f_bounds();
+                let origin = (cols.0, rows.0);
+                match LineF::<$U>::raw(x0, y0, x1, y1, rows, cols, origin) {
+                    Some(line) if !line.is_empty() => Some(line),
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+clip_line_f!(u8 | i8);
+clip_line_f!(u16 | i16);
+clip_line_f!(u32 | i32);
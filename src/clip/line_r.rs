@@ -0,0 +1,177 @@
+use crate::clip::{Clip, Viewport};
+use crate::line_r::LineR;
+use crate::macros::*;
+use crate::math::{Coord, ops};
+
+macro_rules! clip_line_r {
+    ($U:ty | $I:ty) => {
+        clip_line_r!(@impl Clip<$U>, $U, <$U as Coord>::U2);
+        clip_line_r!(@impl Clip<$I>, $U, <$U as Coord>::U2);
+        clip_line_r!(@impl Viewport<$U>, $U, <$U as Coord>::U2);
+        clip_line_r!(@impl Viewport<$I>, $U, <$U as Coord>::U2);
+
+        clip_line_r!(@pub impl Clip<$U>);
+        clip_line_r!(@pub impl Clip<$I>);
+        clip_line_r!(@pub impl Viewport<$U>);
+        clip_line_r!(@pub impl Viewport<$I>);
+
+        clip_line_r!(@pub impl Clip<$I, proj $U>);
+        clip_line_r!(@pub impl Viewport<$U, proj $U>);
+        clip_line_r!(@pub impl Viewport<$I, proj $U>);
+    };
+    (@impl $Self:ident<$UI:ty>, $U:ty, $U2:ty) => {
+        impl $Self<$UI> {
+            /// Returns the closed range of cell offsets `[a, b]` along one axis from `c0`
+            /// in the direction `s` that lie inside `[min, max]` and do not exceed `n`.
+            #[inline]
+            const fn range_r(c0: $UI, s: i8, min: $UI, max: $UI, n: $U) -> Option<($U, $U)> {
+                if 0 < s && max < c0 || s < 0 && c0 < min {
+                    return None;
+                }
+                let (near, far) = if 0 < s { (min, max) } else { (max, min) };
+                let a = if 0 < s && c0 < min || s < 0 && max < c0 {
+                    ops::<$UI>::wusub_s(near, c0, s)
+                } else {
+                    0
+                };
+                if n < a {
+                    return None;
+                }
+                let b = ops::<$UI>::wusub_s(far, c0, s);
+                Some((a, if n < b { n } else { b }))
+            }
+
+            /// Returns the number of crossings along the minor axis `V` strictly before
+            /// crossing number `1 <= k` along the major axis `U`, or at the same time if `incl`.
+            #[inline]
+            const fn crossings_before<const F: u32>(
+                k: $U,
+                du: $U,
+                dv: $U,
+                ru: $U,
+                rv: $U,
+                incl: bool,
+            ) -> $U {
+                let one = (1 as $U2) << F;
+                let a = (ru as $U2 + (k - 1) as $U2 * one) * dv as $U2;
+                let b = rv as $U2 * du as $U2;
+                if incl && b <= a {
+                    ((a - b) / (one * du as $U2) + 1) as $U
+                } else if !incl && b < a {
+                    (a - b).div_ceil(one * du as $U2) as $U
+                } else {
+                    0
+                }
+            }
+
+            /// Clips the cells of a ray segment.
+            ///
+            /// The path is 4-connected and monotone, so its cells inside the region form
+            /// a contiguous range of indices `[n0, n1)`, where the index of a cell is
+            /// the number of crossings before it.
+            #[inline]
+            const fn raw_line_r<const F: u32>(
+                &self,
+                x0: $UI,
+                y0: $UI,
+                x1: $UI,
+                y1: $UI,
+            ) -> Option<LineR<F, $UI>> {
+                let (cx, dx, sx, rx, nx) = LineR::<F, $UI>::axis(x0, x1);
+                let (cy, dy, sy, ry, ny) = LineR::<F, $UI>::axis(y0, y1);
+                if dx == 0 && dy == 0 {
+                    return None;
+                }
+                let (ia, ib) = try_opt!(Self::range_r(cx, sx, self.x_min(), self.x_max, nx));
+                let (ja, jb) = try_opt!(Self::range_r(cy, sy, self.y_min(), self.y_max, ny));
+                let total = nx as $U2 + ny as $U2 + 1;
+                let (mut i, mut j) = (0, 0);
+                let (mut n0, mut n1) = (0, total);
+                if 0 < ia {
+                    j = Self::crossings_before::<F>(ia, dx, dy, rx, ry, false);
+                    i = ia;
+                    n0 = ia as $U2 + j as $U2;
+                }
+                if 0 < ja {
+                    let ci = Self::crossings_before::<F>(ja, dy, dx, ry, rx, true);
+                    if n0 < ci as $U2 + ja as $U2 {
+                        (i, j) = (ci, ja);
+                        n0 = ci as $U2 + ja as $U2;
+                    }
+                }
+                if ib < nx {
+                    let rj = Self::crossings_before::<F>(ib + 1, dx, dy, rx, ry, false);
+                    let e = ib as $U2 + 1 + rj as $U2;
+                    if e < n1 {
+                        n1 = e;
+                    }
+                }
+                if jb < ny {
+                    let ci = Self::crossings_before::<F>(jb + 1, dy, dx, ry, rx, true);
+                    let e = ci as $U2 + jb as $U2 + 1;
+                    if e < n1 {
+                        n1 = e;
+                    }
+                }
+                if n1 <= n0 {
+                    return None;
+                }
+                let err = LineR::<F, $UI>::err_at(dx, dy, rx, ry, i, j);
+                let x0 = ops::<$UI>::wadd_su(cx, i, sx);
+                let y0 = ops::<$UI>::wadd_su(cy, j, sy);
+                Some(LineR { x0, y0, dx, dy, err, len: n1 - n0, sx, sy })
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty>) => {
+        impl $Self<$UI> {
+            /// Clips the directed, half-open ray segment `(x0, y0) -> (x1, y1)`
+            /// between fixed-point positions with `F` fractional bits to this region
+            /// of cells.
+            ///
+            /// Returns a [`LineR`] over the cells of the segment inside this
+            /// clipping region, or [`None`] if the segment lies fully outside.
+            #[inline]
+            pub const fn line_r<const F: u32>(
+                &self,
+                x0: $UI,
+                y0: $UI,
+                x1: $UI,
+                y1: $UI,
+            ) -> Option<LineR<F, $UI>> {
+                self.raw_line_r::<F>(x0, y0, x1, y1)
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty, proj $U:ty>) => {
+        impl $Self<$UI> {
+            /// Clips and projects the directed, half-open ray segment `(x0, y0) -> (x1, y1)`
+            /// between fixed-point positions with `F` fractional bits to this region
+            /// of cells.
+            ///
+            /// Returns a [`LineR`] over the cells of the segment inside this
+            /// clipping region relative to the region, or [`None`] if the segment
+            /// lies fully outside.
+            #[inline]
+            pub const fn line_r_proj<const F: u32>(
+                &self,
+                x0: $UI,
+                y0: $UI,
+                x1: $UI,
+                y1: $UI,
+            ) -> Option<LineR<F, $U>> {
+                let LineR { x0, y0, dx, dy, err, len, sx, sy } =
+                    try_opt!(self.raw_line_r::<F>(x0, y0, x1, y1));
+                let x0 = ops::<$UI>::wusub(x0, self.x_min());
+                let y0 = ops::<$UI>::wusub(y0, self.y_min());
+                Some(LineR { x0, y0, dx, dy, err, len, sx, sy })
+            }
+        }
+    };
+}
+
+clip_line_r!(u8 | i8);
+clip_line_r!(u16 | i16);
+clip_line_r!(u32 | i32);
+clip_line_r!(u64 | i64);
+clip_line_r!(usize | isize);
@@ -0,0 +1,242 @@
+use crate::clip::{Clip, Viewport};
+use crate::line_s::{LineS, LineS4, LineSu};
+use crate::macros::*;
+use crate::math::{Coord, ops};
+
+macro_rules! clip_line_s {
+    ($U:ty | $I:ty) => {
+        clip_line_s!(@impl Clip<$U>, $U, <$U as Coord>::U2, <$U as Coord>::I2);
+        clip_line_s!(@impl Clip<$I>, $U, <$U as Coord>::U2, <$U as Coord>::I2);
+        clip_line_s!(@impl Viewport<$U>, $U, <$U as Coord>::U2, <$U as Coord>::I2);
+        clip_line_s!(@impl Viewport<$I>, $U, <$U as Coord>::U2, <$U as Coord>::I2);
+
+        clip_line_s!(@pub impl Clip<$U>);
+        clip_line_s!(@pub impl Clip<$I>);
+        clip_line_s!(@pub impl Viewport<$U>);
+        clip_line_s!(@pub impl Viewport<$I>);
+
+        clip_line_s!(@pub impl Clip<$I, proj $U>);
+        clip_line_s!(@pub impl Viewport<$U, proj $U>);
+        clip_line_s!(@pub impl Viewport<$I, proj $U>);
+    };
+    (@impl $Self:ident<$UI:ty>, $U:ty, $U2:ty, $I2:ty) => {
+        impl $Self<$UI> {
+            /// Returns the closed range of offsets `[a, b]` along one axis from `c0`
+            /// in the direction `s` that lie inside `[min, max]` and do not exceed `d`.
+            #[inline]
+            const fn range_s(c0: $UI, s: i8, min: $UI, max: $UI, d: $U) -> Option<($U, $U)> {
+                if 0 < s && max < c0 || s < 0 && c0 < min {
+                    return None;
+                }
+                let (near, far) = if 0 < s { (min, max) } else { (max, min) };
+                let a = if 0 < s && c0 < min || s < 0 && max < c0 {
+                    ops::<$UI>::wusub_s(near, c0, s)
+                } else {
+                    0
+                };
+                if d < a {
+                    return None;
+                }
+                let b = ops::<$UI>::wusub_s(far, c0, s);
+                Some((a, if d < b { d } else { b }))
+            }
+
+            /// Returns the row of the 4-connected path when it enters column `1 <= i <= dx`,
+            /// `ceil(((2i - 1) * dy - dx) / 2dx)`.
+            #[inline]
+            const fn row_at(i: $U, dx: $U, dy: $U) -> $U {
+                let t = i as $U2 * dy as $U2;
+                let (q, r) = (t / dx as $U2, (t % dx as $U2) as $I2);
+                let p = 2 * r - dx as $I2 - dy as $I2;
+                (q as $I2 - (-p).div_euclid(2 * dx as $I2)) as $U
+            }
+
+            /// Returns the column of the 4-connected path when it enters row `1 <= j <= dy`,
+            /// `floor(((2j - 1) * dx + dy) / 2dy)`.
+            #[inline]
+            const fn col_at(j: $U, dx: $U, dy: $U) -> $U {
+                let t = j as $U2 * dx as $U2;
+                let (q, r) = (t / dy as $U2, (t % dy as $U2) as $I2);
+                let p = 2 * r + dy as $I2 - dx as $I2;
+                (q as $I2 + p.div_euclid(2 * dy as $I2)) as $U
+            }
+
+            /// Clips the cells of a supercover line segment.
+            ///
+            /// The 4-connected path is monotone, so its cells inside the region form
+            /// a contiguous range of indices `[n0, n1)`, where the index of a cell is
+            /// the sum of its offsets. With `ALL`, the second cell `(i, j + 1)` of each
+            /// corner `(i, j)` is counted separately, as it is not on the 4-connected path.
+            #[inline]
+            const fn raw_line_s<const ALL: bool>(
+                &self,
+                x0: $UI,
+                y0: $UI,
+                x1: $UI,
+                y1: $UI,
+            ) -> Option<LineSu<ALL, $UI>> {
+                let (dx, sx) = ops::<$UI>::susub(x1, x0);
+                let (dy, sy) = ops::<$UI>::susub(y1, y0);
+                let (ia, ib) = try_opt!(Self::range_s(x0, sx, self.x_min(), self.x_max, dx));
+                let (ja, jb) = try_opt!(Self::range_s(y0, sy, self.y_min(), self.y_max, dy));
+                let total = dx as $U2 + dy as $U2;
+                let (mut i, mut j) = (0, 0);
+                let (mut n0, mut n1) = (0, total);
+                if 0 < ia {
+                    j = Self::row_at(ia, dx, dy);
+                    i = ia;
+                    n0 = ia as $U2 + j as $U2;
+                }
+                if 0 < ja {
+                    let ci = Self::col_at(ja, dx, dy);
+                    if n0 < ci as $U2 + ja as $U2 {
+                        (i, j) = (ci, ja);
+                        n0 = ci as $U2 + ja as $U2;
+                    }
+                }
+                if ib < dx {
+                    let e = ib as $U2 + 1 + Self::row_at(ib + 1, dx, dy) as $U2;
+                    if e < n1 {
+                        n1 = e;
+                    }
+                }
+                if jb < dy {
+                    let e = Self::col_at(jb + 1, dx, dy) as $U2 + jb as $U2 + 1;
+                    if e < n1 {
+                        n1 = e;
+                    }
+                }
+                let len4 = if n0 < n1 { n1 - n0 } else { 0 };
+                let mut err = ((2 * i as $I2 + 1).wrapping_mul(dy as $I2))
+                    .wrapping_sub((2 * j as $I2 + 1).wrapping_mul(dx as $I2));
+                let dy2 = 2 * dy as $I2;
+                let mut line = LineSu::<ALL, $UI> {
+                    x0,
+                    y0,
+                    dx,
+                    dy,
+                    err,
+                    len: len4,
+                    sx,
+                    sy,
+                    phase: 0,
+                    skip: false,
+                };
+                let g = LineSu::<ALL, $UI>::corners(dx, dy);
+                if ALL && g != 0 {
+                    let (a, b) = ((dx / g) as $U2, (dy / g) as $U2);
+                    let (ha, hb) = (a.div_ceil(2), b.div_ceil(2));
+                    let (ia, ib, ja, jb) = (ia as $U2, ib as $U2, ja as $U2, jb as $U2);
+                    // the second cell of corner k is (a * k - ha, b * k - hb + 1)
+                    let (lo_i, lo_j) = ((ia + ha).div_ceil(a), (ja + hb - 1).div_ceil(b));
+                    let (hi_i, hi_j) = ((ib + ha) / a, (jb + hb - 1) / b);
+                    let lo = if lo_i < lo_j { lo_j } else { lo_i };
+                    let hi = if hi_j < hi_i { hi_j } else { hi_i };
+                    let lo = if lo < 1 { 1 } else { lo };
+                    let hi = if (g as $U2) < hi { g as $U2 } else { hi };
+                    if lo <= hi {
+                        let cnt = hi - lo + 1;
+                        // the index of the first cell of corner k is (a + b) * k - ha - hb + 1
+                        let nb_lo = (a + b) * lo + 1 - ha - hb;
+                        let nb_hi = (a + b) * hi + 1 - ha - hb;
+                        line.len += cnt;
+                        line.skip = len4 != 0 && nb_hi == n1;
+                        if len4 == 0 || nb_lo < n0 {
+                            (i, j) = ((a * lo - ha) as $U, (b * lo + 1 - hb) as $U);
+                            line.phase = 2;
+                            err = 0;
+                        }
+                    }
+                    if line.phase == 0 && err == dy2 && ia < i as $U2 && j as $U2 < jb {
+                        line.phase = 1;
+                        err = 0;
+                    }
+                }
+                if line.len == 0 {
+                    return None;
+                }
+                line.x0 = ops::<$UI>::wadd_su(x0, i, sx);
+                line.y0 = ops::<$UI>::wadd_su(y0, j, sy);
+                line.err = err;
+                Some(line)
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty>) => {
+        impl $Self<$UI> {
+            /// Clips the directed, half-open line segment `(x0, y0) -> (x1, y1)` to this region.
+            ///
+            /// Returns a [`LineS4`] over the 4-connected cells of the segment inside this
+            /// clipping region, or [`None`] if the segment lies fully outside.
+            #[inline]
+            pub const fn line_s4(&self, x0: $UI, y0: $UI, x1: $UI, y1: $UI) -> Option<LineS4<$UI>> {
+                self.raw_line_s::<false>(x0, y0, x1, y1)
+            }
+
+            /// Clips the directed, half-open line segment `(x0, y0) -> (x1, y1)` to this region.
+            ///
+            /// Returns a [`LineS`] over all cells touched by the segment inside this
+            /// clipping region, or [`None`] if the segment lies fully outside.
+            #[inline]
+            pub const fn line_s(&self, x0: $UI, y0: $UI, x1: $UI, y1: $UI) -> Option<LineS<$UI>> {
+                self.raw_line_s::<true>(x0, y0, x1, y1)
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty, proj $U:ty>) => {
+        impl $Self<$UI> {
+            #[inline]
+            const fn proj_line_s<const ALL: bool>(
+                &self,
+                line: LineSu<ALL, $UI>,
+            ) -> LineSu<ALL, $U> {
+                let LineSu { x0, y0, dx, dy, err, len, sx, sy, phase, skip } = line;
+                let x0 = ops::<$UI>::wusub(x0, self.x_min());
+                let y0 = ops::<$UI>::wusub(y0, self.y_min());
+                LineSu { x0, y0, dx, dy, err, len, sx, sy, phase, skip }
+            }
+
+            /// Clips and projects the directed, half-open line segment `(x0, y0) -> (x1, y1)`
+            /// to this region.
+            ///
+            /// Returns a [`LineS4`] over the 4-connected cells of the segment inside this
+            /// clipping region relative to the region, or [`None`] if the segment
+            /// lies fully outside.
+            #[inline]
+            pub const fn line_s4_proj(
+                &self,
+                x0: $UI,
+                y0: $UI,
+                x1: $UI,
+                y1: $UI,
+            ) -> Option<LineS4<$U>> {
+                let line = try_opt!(self.raw_line_s::<false>(x0, y0, x1, y1));
+                Some(self.proj_line_s(line))
+            }
+
+            /// Clips and projects the directed, half-open line segment `(x0, y0) -> (x1, y1)`
+            /// to this region.
+            ///
+            /// Returns a [`LineS`] over all cells touched by the segment inside this
+            /// clipping region relative to the region, or [`None`] if the segment
+            /// lies fully outside.
+            #[inline]
+            pub const fn line_s_proj(
+                &self,
+                x0: $UI,
+                y0: $UI,
+                x1: $UI,
+                y1: $UI,
+            ) -> Option<LineS<$U>> {
+                let line = try_opt!(self.raw_line_s::<true>(x0, y0, x1, y1));
+                Some(self.proj_line_s(line))
+            }
+        }
+    };
+}
+
+clip_line_s!(u8 | i8);
+clip_line_s!(u16 | i16);
+clip_line_s!(u32 | i32);
+clip_line_s!(u64 | i64);
+clip_line_s!(usize | isize);
@@ -0,0 +1,90 @@
+use crate::clip::{Clip, Viewport};
+use crate::line_thick::{Cap, LineThick};
+use crate::math::{Coord, ops};
+
+macro_rules! clip_line_thick {
+    ($U:ty | $I:ty) => {
+        clip_line_thick!(@impl Clip<$U>, $U, <$U as Coord>::I2);
+        clip_line_thick!(@impl Clip<$I>, $U, <$U as Coord>::I2);
+        clip_line_thick!(@impl Viewport<$U>, $U, <$U as Coord>::I2);
+        clip_line_thick!(@impl Viewport<$I>, $U, <$U as Coord>::I2);
+
+        clip_line_thick!(@pub impl Clip<$U>, $U, <$U as Coord>::I2);
+        clip_line_thick!(@pub impl Clip<$I>, $U, <$U as Coord>::I2);
+        clip_line_thick!(@pub impl Viewport<$U>, $U, <$U as Coord>::I2);
+        clip_line_thick!(@pub impl Viewport<$I>, $U, <$U as Coord>::I2);
+
+        clip_line_thick!(@pub impl Clip<$I, proj $U>);
+        clip_line_thick!(@pub impl Viewport<$U, proj $U>);
+        clip_line_thick!(@pub impl Viewport<$I, proj $U>);
+    };
+    (@impl $Self:ident<$UI:ty>, $U:ty, $I2:ty) => {
+        impl $Self<$UI> {
+            /// Returns the rows and columns of this region relative to `(x0, y0)`.
+            #[inline]
+            const fn rows_cols(&self, x0: $UI, y0: $UI) -> (($I2, $I2), ($I2, $I2)) {
+                let (x0, y0) = (x0 as $I2, y0 as $I2);
+                let rows = (self.y_min() as $I2 - y0, self.y_max as $I2 - y0);
+                let cols = (self.x_min() as $I2 - x0, self.x_max as $I2 - x0);
+                (rows, cols)
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty>, $U:ty, $I2:ty) => {
+        impl $Self<$UI> {
+            /// Clips the thick, closed line segment `(x0, y0) -> (x1, y1)` to this region.
+            ///
+            /// Returns a [`LineThick`] over the spans of the stroke inside this
+            /// clipping region, or [`None`] if the stroke lies fully outside
+            /// or `width` is zero.
+            #[inline]
+            pub const fn line_thick(
+                &self,
+                x0: $UI,
+                y0: $UI,
+                x1: $UI,
+                y1: $UI,
+                width: $U,
+                cap: Cap,
+            ) -> Option<LineThick<$UI>> {
+                let (dx, sx) = ops::<$UI>::susub(x1, x0);
+                let (dy, sy) = ops::<$UI>::susub(y1, y0);
+                let (rows, cols) = self.rows_cols(x0, y0);
+                let (x0, y0) = (x0 as $I2, y0 as $I2);
+                LineThick::<$UI>::raw(x0, y0, dx, dy, sx, sy, width, cap, rows, cols)
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty, proj $U:ty>) => {
+        impl $Self<$UI> {
+            /// Clips and projects the thick, closed line segment `(x0, y0) -> (x1, y1)`
+            /// to this region.
+            ///
+            /// Returns a [`LineThick`] over the spans of the stroke inside this
+            /// clipping region relative to the region, or [`None`] if the stroke
+            /// lies fully outside or `width` is zero.
+            #[inline]
+            pub const fn line_thick_proj(
+                &self,
+                x0: $UI,
+                y0: $UI,
+                x1: $UI,
+                y1: $UI,
+                width: $U,
+                cap: Cap,
+            ) -> Option<LineThick<$U>> {
+                let (dx, sx) = ops::<$UI>::susub(x1, x0);
+                let (dy, sy) = ops::<$UI>::susub(y1, y0);
+                let (rows, cols) = self.rows_cols(x0, y0);
+                let (x0, y0) = (-cols.0, -rows.0);
+                LineThick::<$U>::raw(x0, y0, dx, dy, sx, sy, width, cap, rows, cols)
+            }
+        }
+    };
+}
+
+clip_line_thick!(u8 | i8);
+clip_line_thick!(u16 | i16);
+clip_line_thick!(u32 | i32);
+clip_line_thick!(u64 | i64);
+clip_line_thick!(usize | isize);
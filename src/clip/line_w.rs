@@ -0,0 +1,173 @@
+use crate::clip::{Clip, Viewport};
+use crate::line_w::{LineW, LineWu};
+use crate::macros::*;
+use crate::math::{Coord, ops};
+
+macro_rules! clip_line_w {
+    ($U:ty | $I:ty) => {
+        clip_line_w!(@impl Clip<$U>, $U, <$U as Coord>::U2);
+        clip_line_w!(@impl Clip<$I>, $U, <$U as Coord>::U2);
+        clip_line_w!(@impl Viewport<$U>, $U, <$U as Coord>::U2);
+        clip_line_w!(@impl Viewport<$I>, $U, <$U as Coord>::U2);
+
+        clip_line_w!(@pub impl Clip<$U>);
+        clip_line_w!(@pub impl Clip<$I>);
+        clip_line_w!(@pub impl Viewport<$U>);
+        clip_line_w!(@pub impl Viewport<$I>);
+
+        clip_line_w!(@pub impl Clip<$I, proj $U>);
+        clip_line_w!(@pub impl Viewport<$U, proj $U>);
+        clip_line_w!(@pub impl Viewport<$I, proj $U>);
+    };
+    (@impl $Self:ident<$UI:ty>, $U:ty, $U2:ty) => {
+        impl $Self<$UI> {
+            /// Finds the range of steps `[k0, k1)` that contain at least one visible point.
+            ///
+            /// Along the major axis, this is the intersection with `[u_min, u_max]`.
+            /// Along the minor axis, a step is visible if one of its points is inside
+            /// and has non-zero coverage.
+            #[inline]
+            const fn raw_line_wu<const YX: bool>(
+                &self,
+                u0: $UI,
+                v0: $UI,
+                du: $U,
+                dv: $U,
+                su: i8,
+                sv: i8,
+            ) -> Option<($UI, $UI, $U, $UI)> {
+                if du == 0 {
+                    return None;
+                }
+                let (u_min, v_min, u_max, v_max) = self.uv_min_max::<YX>();
+                let (u_near, u_far) = if 0 < su { (u_min, u_max) } else { (u_max, u_min) };
+                let (v_near, v_far) = if 0 < sv { (v_min, v_max) } else { (v_max, v_min) };
+                if 0 < su && u_max < u0 || su < 0 && u0 < u_min {
+                    return None;
+                }
+                if 0 < sv && v_max < v0 || sv < 0 && v0 < v_min {
+                    return None;
+                }
+                let ku0 = if 0 < su && u0 < u_min || su < 0 && u_max < u0 {
+                    ops::<$UI>::wusub_s(u_near, u0, su)
+                } else {
+                    0
+                };
+                let du_far = ops::<$UI>::wusub_s(u_far, u0, su);
+                let ku1 = if du_far < du { du_far + 1 } else { du };
+                let dv_near = if 0 < sv && v0 < v_min || sv < 0 && v_max < v0 {
+                    ops::<$UI>::wusub_s(v_near, v0, sv)
+                } else {
+                    0
+                };
+                let dv_far = ops::<$UI>::wusub_s(v_far, v0, sv);
+                let (du2, dv2) = (du as $U2, dv as $U2);
+                let kv0 = if dv_near == 0 {
+                    0
+                } else if dv == 0 {
+                    return None;
+                } else {
+                    // the secondary point of the last row before the entry
+                    // must have a coverage of at least 1/256
+                    let t = (dv_near - 1) as $U2 * du2 + du2.div_ceil(256);
+                    t.div_ceil(dv2)
+                };
+                let kv1 = if dv == 0 {
+                    du2
+                } else {
+                    // the primary point of the last row before the exit
+                    // must have a coverage of at least 1/256
+                    let t = dv_far as $U2 * du2 + (du2 * 255).div_ceil(256);
+                    t.div_ceil(dv2)
+                };
+                let k0 = if kv0 < ku0 as $U2 { ku0 as $U2 } else { kv0 };
+                let k1 = if ku1 as $U2 < kv1 { ku1 as $U2 } else { kv1 };
+                if k1 <= k0 {
+                    return None;
+                }
+                let (k0, k1) = (k0 as $U, k1 as $U);
+                let t0 = k0 as $U2 * dv2;
+                let (dvc, rem) = ((t0 / du2) as $U, (t0 % du2) as $U);
+                let cu0 = ops::<$UI>::wadd_su(u0, k0, su);
+                let cv0 = ops::<$UI>::wadd_su(v0, dvc, sv);
+                let cu1 = ops::<$UI>::wadd_su(u0, k1, su);
+                Some((cu0, cv0, rem, cu1))
+            }
+
+            #[inline]
+            const fn raw_line_w(
+                &self,
+                x0: $UI,
+                y0: $UI,
+                x1: $UI,
+                y1: $UI,
+            ) -> Option<(bool, $UI, $UI, $U, $U, $U, $UI, i8, i8)> {
+                let (dx, sx) = ops::<$UI>::susub(x1, x0);
+                let (dy, sy) = ops::<$UI>::susub(y1, y0);
+                if dx < dy {
+                    let (u0, v0, rem, u1) = try_opt!(self.raw_line_wu::<true>(y0, x0, dy, dx, sy, sx));
+                    Some((true, u0, v0, dy, dx, rem, u1, sy, sx))
+                } else {
+                    let (u0, v0, rem, u1) = try_opt!(self.raw_line_wu::<false>(x0, y0, dx, dy, sx, sy));
+                    Some((false, u0, v0, dx, dy, rem, u1, sx, sy))
+                }
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty>) => {
+        impl $Self<$UI> {
+            /// Clips the directed, half-open line segment `(x0, y0) -> (x1, y1)` to this region.
+            ///
+            /// Returns a [`LineW`] over the portion of the segment inside this
+            /// clipping region, or [`None`] if the segment lies fully outside.
+            #[inline]
+            pub const fn line_w(&self, x0: $UI, y0: $UI, x1: $UI, y1: $UI) -> Option<LineW<$UI>> {
+                let (yx, u0, v0, du, dv, rem, u1, su, sv) =
+                    try_opt!(self.raw_line_w(x0, y0, x1, y1));
+                let second = false;
+                Some(if yx {
+                    let (v_min, v_max) = (self.x_min(), self.x_max);
+                    LineW::Wy(LineWu { u0, v0, du, dv, rem, u1, v_min, v_max, su, sv, second })
+                } else {
+                    let (v_min, v_max) = (self.y_min(), self.y_max);
+                    LineW::Wx(LineWu { u0, v0, du, dv, rem, u1, v_min, v_max, su, sv, second })
+                })
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty, proj $U:ty>) => {
+        impl $Self<$UI> {
+            /// Clips and projects the directed, half-open line segment `(x0, y0) -> (x1, y1)`
+            /// to this region.
+            ///
+            /// Returns a [`LineW`] over the portion of the segment inside this
+            /// clipping region relative to the region, or [`None`] if the segment
+            /// lies fully outside.
+            #[inline]
+            pub const fn line_w_proj(&self, x0: $UI, y0: $UI, x1: $UI, y1: $UI) -> Option<LineW<$U>> {
+                let (yx, u0, v0, du, dv, rem, u1, su, sv) =
+                    try_opt!(self.raw_line_w(x0, y0, x1, y1));
+                let second = false;
+                Some(if yx {
+                    let u0 = ops::<$UI>::wusub(u0, self.y_min());
+                    let v0 = ops::<$UI>::wusub(v0, self.x_min());
+                    let u1 = ops::<$UI>::wusub(u1, self.y_min());
+                    let (v_min, v_max) = (0, ops::<$UI>::wusub(self.x_max, self.x_min()));
+                    LineW::Wy(LineWu { u0, v0, du, dv, rem, u1, v_min, v_max, su, sv, second })
+                } else {
+                    let u0 = ops::<$UI>::wusub(u0, self.x_min());
+                    let v0 = ops::<$UI>::wusub(v0, self.y_min());
+                    let u1 = ops::<$UI>::wusub(u1, self.x_min());
+                    let (v_min, v_max) = (0, ops::<$UI>::wusub(self.y_max, self.y_min()));
+                    LineW::Wx(LineWu { u0, v0, du, dv, rem, u1, v_min, v_max, su, sv, second })
+                })
+            }
+        }
+    };
+}
+
+clip_line_w!(u8 | i8);
+clip_line_w!(u16 | i16);
+clip_line_w!(u32 | i32);
+clip_line_w!(u64 | i64);
+clip_line_w!(usize | isize);
@@ -0,0 +1,41 @@
+use crate::clip::{Clip, Viewport};
+use crate::math::Coord;
+use crate::polygon_fill::{FillRule, PolygonFill};
+
+macro_rules! clip_polygon_fill {
+    ($U:ty | $I:ty) => {
+        clip_polygon_fill!(@pub impl Clip<$U>, <$U as Coord>::I2);
+        clip_polygon_fill!(@pub impl Clip<$I>, <$U as Coord>::I2);
+        clip_polygon_fill!(@pub impl Viewport<$U>, <$U as Coord>::I2);
+        clip_polygon_fill!(@pub impl Viewport<$I>, <$U as Coord>::I2);
+    };
+    (@pub impl $Self:ident<$UI:ty>, $I2:ty) => {
+        impl $Self<$UI> {
+            /// Clips the polygon with the given vertices, filled according to the `rule`,
+            /// to this region.
+            ///
+            /// Returns a [`PolygonFill`] over the spans of the polygon inside this
+            /// clipping region, or [`None`] if the polygon lies fully outside.
+            #[inline]
+            pub const fn polygon_fill<'a>(
+                &self,
+                points: &'a [($UI, $UI)],
+                rule: FillRule,
+            ) -> Option<PolygonFill<'a, $UI>> {
+                let rows = (self.y_min() as $I2, self.y_max as $I2);
+                let cols = (self.x_min() as $I2, self.x_max as $I2);
+                let fill = PolygonFill::<$UI>::raw(points, rule, rows, cols);
+                if fill.is_empty() {
+                    return None;
+                }
+                Some(fill)
+            }
+        }
+    };
+}
+
+clip_polygon_fill!(u8 | i8);
+clip_polygon_fill!(u16 | i16);
+clip_polygon_fill!(u32 | i32);
+clip_polygon_fill!(u64 | i64);
+clip_polygon_fill!(usize | isize);
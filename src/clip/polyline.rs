@@ -0,0 +1,47 @@
+use crate::clip::{Clip, Viewport};
+use crate::polyline::Polyline;
+
+macro_rules! clip_polyline {
+    ($U:ty | $I:ty) => {
+        clip_polyline!(@pub impl Clip<$U>);
+        clip_polyline!(@pub impl Clip<$I>);
+        clip_polyline!(@pub impl Viewport<$U>);
+        clip_polyline!(@pub impl Viewport<$I>);
+    };
+    (@pub impl $Self:ident<$UI:ty>) => {
+        impl $Self<$UI> {
+            /// Clips the polyline connecting consecutive `points`, with the last point
+            /// connected back to the first one if `closed`, to this region.
+            ///
+            /// Each segment is clipped with [`Self::line_b`], so the vertices shared
+            /// by consecutive segments are yielded once, as in [`Polyline::new`].
+            ///
+            /// Returns a [`Polyline`] over the points of the polyline inside this
+            /// clipping region, or [`None`] if the polyline lies fully outside.
+            #[inline]
+            pub const fn polyline<'a>(
+                &self,
+                points: &'a [($UI, $UI)],
+                closed: bool,
+            ) -> Option<Polyline<'a, $UI>> {
+                let region = Viewport {
+                    x_min: self.x_min(),
+                    y_min: self.y_min(),
+                    x_max: self.x_max,
+                    y_max: self.y_max,
+                };
+                let polyline = Polyline::<$UI>::raw(points, closed, Some(region));
+                if polyline.is_empty() {
+                    return None;
+                }
+                Some(polyline)
+            }
+        }
+    };
+}
+
+clip_polyline!(u8 | i8);
+clip_polyline!(u16 | i16);
+clip_polyline!(u32 | i32);
+clip_polyline!(u64 | i64);
+clip_polyline!(usize | isize);
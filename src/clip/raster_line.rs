@@ -0,0 +1,27 @@
+use crate::clip::{Clip, Viewport};
+use crate::math::Coord;
+use crate::raster_line::RasterLine;
+
+impl<C: Coord> Clip<C> {
+    /// Clips the directed, half-open line segment `(x0, y0) -> (x1, y1)` to this region
+    /// with the rasterization algorithm of the line iterator `L`.
+    ///
+    /// Returns an `L` over the portion of the segment inside this clipping region,
+    /// or [`None`] if `L` does not support the segment or it lies fully outside.
+    #[inline]
+    pub fn clip_line<L: RasterLine<C>>(&self, x0: C, y0: C, x1: C, y1: C) -> Option<L> {
+        L::clip(self, x0, y0, x1, y1)
+    }
+}
+
+impl<C: Coord> Viewport<C> {
+    /// Clips the directed, half-open line segment `(x0, y0) -> (x1, y1)` to this region
+    /// with the rasterization algorithm of the line iterator `L`.
+    ///
+    /// Returns an `L` over the portion of the segment inside this clipping region,
+    /// or [`None`] if `L` does not support the segment or it lies fully outside.
+    #[inline]
+    pub fn clip_line<L: RasterLine<C>>(&self, x0: C, y0: C, x1: C, y1: C) -> Option<L> {
+        L::viewport(self, x0, y0, x1, y1)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::clip::{Clip, Viewport};
+use crate::macros::*;
+use crate::math::ops;
+
+macro_rules! clip_rect {
+    ($U:ty | $I:ty) => {
+        clip_rect!(@pub impl Clip<$U>);
+        clip_rect!(@pub impl Clip<$I>);
+        clip_rect!(@pub impl Viewport<$U>);
+        clip_rect!(@pub impl Viewport<$I>);
+
+        clip_rect!(@pub impl Clip<$I, proj $U>);
+        clip_rect!(@pub impl Viewport<$U, proj $U>);
+        clip_rect!(@pub impl Viewport<$I, proj $U>);
+    };
+    (@pub impl $Self:ident<$UI:ty>) => {
+        impl $Self<$UI> {
+            /// Clips the closed rectangle with opposite corners `(x0, y0)` and `(x1, y1)`
+            /// to this region.
+            ///
+            /// Returns a [`Viewport`] over the intersection of the rectangle and this
+            /// clipping region, or [`None`] if the rectangle lies fully outside.
+            ///
+            /// Use [`Rect`](crate::Rect) or [`RectFill`](crate::RectFill)
+            /// to iterate over the outline or spans of the intersection.
+            #[inline]
+            pub const fn rect(&self, x0: $UI, y0: $UI, x1: $UI, y1: $UI) -> Option<Viewport<$UI>> {
+                let (x0, x1) = if x0 <= x1 { (x0, x1) } else { (x1, x0) };
+                let (y0, y1) = if y0 <= y1 { (y0, y1) } else { (y1, y0) };
+                if x1 < self.x_min() || self.x_max < x0 || y1 < self.y_min() || self.y_max < y0 {
+                    return None;
+                }
+                Some(Viewport {
+                    x_min: ops::<$UI>::max(x0, self.x_min()),
+                    y_min: ops::<$UI>::max(y0, self.y_min()),
+                    x_max: ops::<$UI>::min(x1, self.x_max),
+                    y_max: ops::<$UI>::min(y1, self.y_max),
+                })
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty, proj $U:ty>) => {
+        impl $Self<$UI> {
+            /// Clips and projects the closed rectangle with opposite corners
+            /// `(x0, y0)` and `(x1, y1)` to this region.
+            ///
+            /// Returns a [`Viewport`] over the intersection of the rectangle and this
+            /// clipping region relative to the region, or [`None`] if the rectangle
+            /// lies fully outside.
+            #[inline]
+            pub const fn rect_proj(
+                &self,
+                x0: $UI,
+                y0: $UI,
+                x1: $UI,
+                y1: $UI,
+            ) -> Option<Viewport<$U>> {
+                let Viewport { x_min, y_min, x_max, y_max } = try_opt!(self.rect(x0, y0, x1, y1));
+                Some(Viewport {
+                    x_min: ops::<$UI>::wusub(x_min, self.x_min()),
+                    y_min: ops::<$UI>::wusub(y_min, self.y_min()),
+                    x_max: ops::<$UI>::wusub(x_max, self.x_min()),
+                    y_max: ops::<$UI>::wusub(y_max, self.y_min()),
+                })
+            }
+        }
+    };
+}
+
+clip_rect!(u8 | i8);
+clip_rect!(u16 | i16);
+clip_rect!(u32 | i32);
+clip_rect!(u64 | i64);
+clip_rect!(usize | isize);
@@ -0,0 +1,108 @@
+use crate::clip::{Clip, Viewport};
+use crate::macros::*;
+use crate::math::ops;
+
+macro_rules! clip_region {
+    ($U:ty | $I:ty) => {
+        clip_region!(@pub impl Clip<$U>);
+        clip_region!(@pub impl Clip<$I>);
+        clip_region!(@pub impl Viewport<$U>, $U, $I);
+        clip_region!(@pub impl Viewport<$I>, $U, $I);
+    };
+    (@pub impl Clip<$UI:ty>) => {
+        impl Clip<$UI> {
+            /// Returns the [`Viewport`] covering the same region as this [`Clip`].
+            #[inline]
+            pub const fn to_viewport(&self) -> Viewport<$UI> {
+                Viewport { x_min: 0, y_min: 0, x_max: self.x_max, y_max: self.y_max }
+            }
+        }
+    };
+    (@pub impl Viewport<$UI:ty>, $U:ty, $I:ty) => {
+        impl Viewport<$UI> {
+            /// Returns the [`Clip`] covering the same region as this [`Viewport`],
+            /// or [`None`] if its minimum corner is not zero.
+            #[inline]
+            pub const fn to_clip(&self) -> Option<Clip<$UI>> {
+                if self.x_min != 0 || self.y_min != 0 {
+                    return None;
+                }
+                Some(Clip { x_max: self.x_max, y_max: self.y_max })
+            }
+
+            /// Returns the intersection of this region and `other`,
+            /// or [`None`] if they are disjoint.
+            #[inline]
+            pub const fn intersect(&self, other: &Self) -> Option<Self> {
+                self.rect(other.x_min, other.y_min, other.x_max, other.y_max)
+            }
+
+            /// Returns the smallest region containing both this region and `other`.
+            #[inline]
+            pub const fn bounding_union(&self, other: &Self) -> Self {
+                Self {
+                    x_min: ops::<$UI>::min(self.x_min, other.x_min),
+                    y_min: ops::<$UI>::min(self.y_min, other.y_min),
+                    x_max: ops::<$UI>::max(self.x_max, other.x_max),
+                    y_max: ops::<$UI>::max(self.y_max, other.y_max),
+                }
+            }
+
+            /// Returns `true` if `other` lies fully inside this region.
+            #[inline]
+            pub const fn contains_viewport(&self, other: &Self) -> bool {
+                self.x_min <= other.x_min
+                    && self.y_min <= other.y_min
+                    && other.x_max <= self.x_max
+                    && other.y_max <= self.y_max
+            }
+
+            /// Returns this region moved by `(dx, dy)`,
+            /// or [`None`] if any of its corners would overflow.
+            #[inline]
+            pub const fn translate(&self, dx: $I, dy: $I) -> Option<Self> {
+                Some(Self {
+                    x_min: try_opt!(ops::<$UI>::chadd_i(self.x_min, dx)),
+                    y_min: try_opt!(ops::<$UI>::chadd_i(self.y_min, dy)),
+                    x_max: try_opt!(ops::<$UI>::chadd_i(self.x_max, dx)),
+                    y_max: try_opt!(ops::<$UI>::chadd_i(self.y_max, dy)),
+                })
+            }
+
+            /// Returns this region grown by the margin `mx` on the left and right,
+            /// and `my` on the top and bottom, or [`None`] if any of its corners would overflow.
+            #[inline]
+            pub const fn expand(&self, mx: $U, my: $U) -> Option<Self> {
+                Some(Self {
+                    x_min: try_opt!(ops::<$UI>::chsub_u(self.x_min, mx)),
+                    y_min: try_opt!(ops::<$UI>::chsub_u(self.y_min, my)),
+                    x_max: try_opt!(ops::<$UI>::chadd_u(self.x_max, mx)),
+                    y_max: try_opt!(ops::<$UI>::chadd_u(self.y_max, my)),
+                })
+            }
+
+            /// Returns this region shrunk by the margin `mx` on the left and right,
+            /// and `my` on the top and bottom, or [`None`] if nothing would remain.
+            #[inline]
+            pub const fn shrink(&self, mx: $U, my: $U) -> Option<Self> {
+                let w = ops::<$UI>::usub(self.x_max, self.x_min);
+                let h = ops::<$UI>::usub(self.y_max, self.y_min);
+                if w / 2 < mx || h / 2 < my {
+                    return None;
+                }
+                Some(Self {
+                    x_min: ops::<$UI>::add_u(self.x_min, mx),
+                    y_min: ops::<$UI>::add_u(self.y_min, my),
+                    x_max: ops::<$UI>::sub_u(self.x_max, mx),
+                    y_max: ops::<$UI>::sub_u(self.y_max, my),
+                })
+            }
+        }
+    };
+}
+
+clip_region!(u8 | i8);
+clip_region!(u16 | i16);
+clip_region!(u32 | i32);
+clip_region!(u64 | i64);
+clip_region!(usize | isize);
@@ -0,0 +1,105 @@
+use crate::clip::{Clip, Viewport};
+use crate::math::Coord;
+use crate::triangle::Triangle;
+
+macro_rules! clip_triangle {
+    ($U:ty | $I:ty) => {
+        clip_triangle!(@impl Clip<$U>, <$U as Coord>::I2);
+        clip_triangle!(@impl Clip<$I>, <$U as Coord>::I2);
+        clip_triangle!(@impl Viewport<$U>, <$U as Coord>::I2);
+        clip_triangle!(@impl Viewport<$I>, <$U as Coord>::I2);
+
+        clip_triangle!(@pub impl Clip<$U>, <$U as Coord>::I2);
+        clip_triangle!(@pub impl Clip<$I>, <$U as Coord>::I2);
+        clip_triangle!(@pub impl Viewport<$U>, <$U as Coord>::I2);
+        clip_triangle!(@pub impl Viewport<$I>, <$U as Coord>::I2);
+
+        clip_triangle!(@pub impl Clip<$I, proj $U>, <$U as Coord>::I2);
+        clip_triangle!(@pub impl Viewport<$U, proj $U>, <$U as Coord>::I2);
+        clip_triangle!(@pub impl Viewport<$I, proj $U>, <$U as Coord>::I2);
+    };
+    (@impl $Self:ident<$UI:ty>, $I2:ty) => {
+        impl $Self<$UI> {
+            /// Returns the vertices, rows and columns of this region relative to `(x, y)`.
+            #[inline]
+            const fn triangle_frame(
+                &self,
+                x: $I2,
+                y: $I2,
+                v: [($UI, $UI); 3],
+            ) -> ([($I2, $I2); 3], ($I2, $I2), ($I2, $I2)) {
+                let v = [
+                    (v[0].0 as $I2 - x, v[0].1 as $I2 - y),
+                    (v[1].0 as $I2 - x, v[1].1 as $I2 - y),
+                    (v[2].0 as $I2 - x, v[2].1 as $I2 - y),
+                ];
+                let rows = (self.y_min() as $I2 - y, self.y_max as $I2 - y);
+                let cols = (self.x_min() as $I2 - x, self.x_max as $I2 - x);
+                (v, rows, cols)
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty>, $I2:ty) => {
+        impl $Self<$UI> {
+            /// Clips the triangle with vertices `(x0, y0)`, `(x1, y1)` and `(x2, y2)`
+            /// to this region. See [`Triangle::new`].
+            ///
+            /// Returns a [`Triangle`] over the spans of the triangle inside this
+            /// clipping region, or [`None`] if either:
+            /// - the triangle lies fully outside,
+            /// - twice the area of the triangle does not fit into `C::I2`.
+            #[inline]
+            pub const fn triangle(
+                &self,
+                x0: $UI,
+                y0: $UI,
+                x1: $UI,
+                y1: $UI,
+                x2: $UI,
+                y2: $UI,
+            ) -> Option<Triangle<$UI>> {
+                let (v, rows, cols) = self.triangle_frame(0, 0, [(x0, y0), (x1, y1), (x2, y2)]);
+                let triangle = match Triangle::<$UI>::raw(v, rows, cols) {
+                    Some(triangle) if !triangle.is_empty() => triangle,
+                    _ => return None,
+                };
+                Some(triangle)
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty, proj $U:ty>, $I2:ty) => {
+        impl $Self<$UI> {
+            /// Clips and projects the triangle with vertices `(x0, y0)`, `(x1, y1)`
+            /// and `(x2, y2)` to this region. See [`Triangle::new`].
+            ///
+            /// Returns a [`Triangle`] over the spans of the triangle inside this
+            /// clipping region relative to the region, or [`None`] if either:
+            /// - the triangle lies fully outside,
+            /// - twice the area of the triangle does not fit into `C::I2`.
+            #[inline]
+            pub const fn triangle_proj(
+                &self,
+                x0: $UI,
+                y0: $UI,
+                x1: $UI,
+                y1: $UI,
+                x2: $UI,
+                y2: $UI,
+            ) -> Option<Triangle<$U>> {
+                let (x, y) = (self.x_min() as $I2, self.y_min() as $I2);
+                let (v, rows, cols) = self.triangle_frame(x, y, [(x0, y0), (x1, y1), (x2, y2)]);
+                let triangle = match Triangle::<$U>::raw(v, rows, cols) {
+                    Some(triangle) if !triangle.is_empty() => triangle,
+                    _ => return None,
+                };
+                Some(triangle)
+            }
+        }
+    };
+}
+
+clip_triangle!(u8 | i8);
+clip_triangle!(u16 | i16);
+clip_triangle!(u32 | i32);
+clip_triangle!(u64 | i64);
+clip_triangle!(usize | isize);
@@ -0,0 +1,189 @@
+use crate::macros::*;
+use crate::math::{Coord, ops};
+
+mod line_b3;
+mod point;
+
+/// A closed[^1] box-shaped clipping region in 3D space with a zero origin and a maximum corner.
+///
+/// Use [`Viewport3`] if the region needs an arbitrary position.
+///
+/// [^1]: `x_max`, `y_max` and `z_max` are inside the region.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Clip3<C: Coord> {
+    pub(crate) x_max: C,
+    pub(crate) y_max: C,
+    pub(crate) z_max: C,
+}
+
+/// A closed[^1] box-shaped clipping region in 3D space with a minimum and maximum corner.
+///
+/// Use [`Clip3`] if the region is always positioned at zero.
+///
+/// [^1]: `x_min`, `y_min`, `z_min`, `x_max`, `y_max` and `z_max` are inside the region.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Viewport3<C: Coord> {
+    pub(crate) x_min: C,
+    pub(crate) y_min: C,
+    pub(crate) z_min: C,
+    pub(crate) x_max: C,
+    pub(crate) y_max: C,
+    pub(crate) z_max: C,
+}
+
+macro_rules! clip3 {
+    ($U:ty|$I:ty) => {
+        clip3!(@impl Clip3<unsigned $U>, $U);
+        clip3!(@impl Clip3<signed $I>, $U);
+        clip3!(@impl Viewport3<$U>, $U);
+        clip3!(@impl Viewport3<$I>, $U);
+    };
+    (@impl Clip3<$signedness:ident $UI:ty>, $U:ty) => {
+        impl Clip3<$UI> {
+            /// Returns a [`Clip3`] with the given maximum
+            #[doc = if_unsigned!($signedness <
+                "corner."
+            > else <
+                "corner, or [`None`] if `x_max`, `y_max` or `z_max` is negative."
+            >)]
+            #[inline]
+            pub const fn from_max(
+                x_max: $UI,
+                y_max: $UI,
+                z_max: $UI,
+            ) -> if_unsigned!($signedness [Self] else [Option<Self>]) {
+                if_unsigned!($signedness {
+                    Self { x_max, y_max, z_max }
+                } else {
+                    if x_max < 0 || y_max < 0 || z_max < 0 {
+                        return None;
+                    }
+                    Some(Self { x_max, y_max, z_max })
+                })
+            }
+
+            /// Returns a [`Clip3`] with the given size, or [`None`] if
+            #[doc = if_unsigned!($signedness <
+                "`width`, `height` or `depth` is zero."
+            > else <
+                "either:\n\
+                - `width`, `height` or `depth` is zero,\n\
+                - `width - 1`, `height - 1` or `depth - 1` is greater than the largest possible coordinate."
+            >)]
+            #[inline]
+            pub const fn from_size(width: $U, height: $U, depth: $U) -> Option<Self> {
+                let (x_max, y_max, z_max) = if_unsigned!($signedness {
+                    if width == 0 || height == 0 || depth == 0 {
+                        return None;
+                    }
+                    (width - 1, height - 1, depth - 1)
+                } else {
+                    const MAX: $U = <$UI>::MAX as $U + 1;
+                    if width == 0 || height == 0 || depth == 0 {
+                        return None;
+                    }
+                    if MAX < width || MAX < height || MAX < depth {
+                        return None;
+                    }
+                    ((width - 1) as $UI, (height - 1) as $UI, (depth - 1) as $UI)
+                });
+                Some(Self { x_max, y_max, z_max })
+            }
+        }
+    };
+    (@impl Viewport3<$UI:ty>, $U:ty) => {
+        impl Viewport3<$UI> {
+            /// Returns a [`Viewport3`] with the given minimum and maximum corners,
+            /// or [`None`] if `x_max < x_min`, `y_max < y_min` or `z_max < z_min`.
+            #[inline]
+            pub const fn from_min_max(
+                x_min: $UI,
+                y_min: $UI,
+                z_min: $UI,
+                x_max: $UI,
+                y_max: $UI,
+                z_max: $UI,
+            ) -> Option<Self> {
+                if x_max < x_min || y_max < y_min || z_max < z_min {
+                    return None;
+                }
+                Some(Self { x_min, y_min, z_min, x_max, y_max, z_max })
+            }
+
+            /// Returns a [`Viewport3`] with the given minimum corner and size,
+            /// or [`None`] if either:
+            /// - `width`, `height` or `depth` is zero,
+            /// - `x_min + width`, `y_min + height` or `z_min + depth` overflows.
+            #[inline]
+            pub const fn from_min_size(
+                x_min: $UI,
+                y_min: $UI,
+                z_min: $UI,
+                width: $U,
+                height: $U,
+                depth: $U,
+            ) -> Option<Self> {
+                if width == 0 || height == 0 || depth == 0 {
+                    return None;
+                }
+                let x_max = try_opt!(ops::<$UI>::chadd_u(x_min, width - 1));
+                let y_max = try_opt!(ops::<$UI>::chadd_u(y_min, height - 1));
+                let z_max = try_opt!(ops::<$UI>::chadd_u(z_min, depth - 1));
+                Some(Self { x_min, y_min, z_min, x_max, y_max, z_max })
+            }
+        }
+    };
+    (@impl[$($generics:tt)+] MinMax for $Self:ident<$UI:ty> {
+        $self:ident, $x_min:expr, $y_min:expr, $z_min:expr
+    }) => {
+        impl<$($generics)*> $Self<$UI> {
+            /// Returns the minimum `x` coordinate of this clipping region.
+            #[inline]
+            pub const fn x_min(&$self) -> $UI {
+                $x_min
+            }
+
+            /// Returns the minimum `y` coordinate of this clipping region.
+            #[inline]
+            pub const fn y_min(&$self) -> $UI {
+                $y_min
+            }
+
+            /// Returns the minimum `z` coordinate of this clipping region.
+            #[inline]
+            pub const fn z_min(&$self) -> $UI {
+                $z_min
+            }
+
+            /// Returns the maximum `x` coordinate of this clipping region.
+            #[inline]
+            pub const fn x_max(&self) -> $UI {
+                self.x_max
+            }
+
+            /// Returns the maximum `y` coordinate of this clipping region.
+            #[inline]
+            pub const fn y_max(&self) -> $UI {
+                self.y_max
+            }
+
+            /// Returns the maximum `z` coordinate of this clipping region.
+            #[inline]
+            pub const fn z_max(&self) -> $UI {
+                self.z_max
+            }
+        }
+    };
+}
+
+clone!([C: Coord] Clip3<C>);
+clone!([C: Coord] Viewport3<C>);
+
+clip3!(@impl[C: Coord] MinMax for Clip3<C> { self, C::ZERO, C::ZERO, C::ZERO });
+clip3!(@impl[C: Coord] MinMax for Viewport3<C> { self, self.x_min, self.y_min, self.z_min });
+
+clip3!(u8 | i8);
+clip3!(u16 | i16);
+clip3!(u32 | i32);
+clip3!(u64 | i64);
+clip3!(usize | isize);
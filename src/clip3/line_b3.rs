@@ -0,0 +1,173 @@
+use crate::clip3::{Clip3, Viewport3};
+use crate::line_b3::LineB3;
+use crate::macros::*;
+use crate::math::{Coord, ops};
+
+macro_rules! clip3_line_b3 {
+    ($U:ty | $I:ty) => {
+        clip3_line_b3!(@impl Clip3<$U>, $U, <$U as Coord>::U2, <$U as Coord>::I2);
+        clip3_line_b3!(@impl Clip3<$I>, $U, <$U as Coord>::U2, <$U as Coord>::I2);
+        clip3_line_b3!(@impl Viewport3<$U>, $U, <$U as Coord>::U2, <$U as Coord>::I2);
+        clip3_line_b3!(@impl Viewport3<$I>, $U, <$U as Coord>::U2, <$U as Coord>::I2);
+
+        clip3_line_b3!(@pub impl Clip3<$U>);
+        clip3_line_b3!(@pub impl Clip3<$I>);
+        clip3_line_b3!(@pub impl Viewport3<$U>);
+        clip3_line_b3!(@pub impl Viewport3<$I>);
+
+        clip3_line_b3!(@pub impl Clip3<$I, proj $U>);
+        clip3_line_b3!(@pub impl Viewport3<$U, proj $U>);
+        clip3_line_b3!(@pub impl Viewport3<$I, proj $U>);
+    };
+    (@impl $Self:ident<$UI:ty>, $U:ty, $U2:ty, $I2:ty) => {
+        impl $Self<$UI> {
+            /// Returns the minimum and maximum coordinates of this region
+            /// along the axes `U`, `V` and `W` of a [`LineB3`] with the given major axis.
+            #[inline]
+            const fn uvw_min_max(&self, axis: u8) -> [($UI, $UI); 3] {
+                let x = (self.x_min(), self.x_max);
+                let y = (self.y_min(), self.y_max);
+                let z = (self.z_min(), self.z_max);
+                match axis {
+                    0 => [x, y, z],
+                    1 => [y, x, z],
+                    _ => [z, x, y],
+                }
+            }
+
+            /// Returns the closed range of offsets `[a, b]` along one axis from `c0`
+            /// in the direction `s` that lie inside `[min, max]` and do not exceed `d`.
+            #[inline]
+            const fn range_b3(c0: $UI, s: i8, (min, max): ($UI, $UI), d: $U) -> Option<($U, $U)> {
+                if 0 < s && max < c0 || s < 0 && c0 < min {
+                    return None;
+                }
+                let (near, far) = if 0 < s { (min, max) } else { (max, min) };
+                let a = if 0 < s && c0 < min || s < 0 && max < c0 {
+                    ops::<$UI>::wusub_s(near, c0, s)
+                } else {
+                    0
+                };
+                if d < a {
+                    return None;
+                }
+                let b = ops::<$UI>::wusub_s(far, c0, s);
+                Some((a, if d < b { d } else { b }))
+            }
+
+            /// Returns the range of steps `[k0, k1)` along the major axis
+            /// where the offset along a minor axis lies inside `[a, b]`.
+            ///
+            /// The offset after `k` steps is `(base + k * dv) / du`.
+            #[inline]
+            const fn steps_b3(du: $U, dv: $U, (a, b): ($U, $U)) -> ($U2, $U2) {
+                let (du, dv, a, b) = (du as $U2, dv as $U2, a as $U2, b as $U2);
+                let base = du / 2;
+                let k0 = if a == 0 { 0 } else { (a * du - base).div_ceil(dv) };
+                let k1 = if b == dv { du } else { ((b + 1) * du - base).div_ceil(dv) };
+                (k0, k1)
+            }
+
+            /// Returns the minor coordinate and the error term after `k` steps.
+            #[inline]
+            const fn minor_b3(v0: $UI, du: $U, dv: $U, sv: i8, k: $U2) -> ($UI, $I2) {
+                let (du, dv) = (du as $U2, dv as $U2);
+                let num = du / 2 + k * dv;
+                let v = ops::<$UI>::wadd_su(v0, (num / du) as $U, sv);
+                (v, (num % du) as $I2 - du as $I2 + dv as $I2)
+            }
+
+            #[inline]
+            const fn raw_line_b3(
+                &self,
+                x0: $UI,
+                y0: $UI,
+                z0: $UI,
+                x1: $UI,
+                y1: $UI,
+                z1: $UI,
+            ) -> Option<LineB3<$UI>> {
+                let line = LineB3::<$UI>::new(x0, y0, z0, x1, y1, z1);
+                if line.is_empty() {
+                    return None;
+                }
+                let LineB3 { u0, v0, w0, du, dv, dw, su, sv, sw, axis, .. } = line;
+                let [ru, rv, rw] = self.uvw_min_max(axis);
+                let (ua, ub) = try_opt!(Self::range_b3(u0, su, ru, du));
+                let va_vb = try_opt!(Self::range_b3(v0, sv, rv, dv));
+                let wa_wb = try_opt!(Self::range_b3(w0, sw, rw, dw));
+                let (kv0, kv1) = Self::steps_b3(du, dv, va_vb);
+                let (kw0, kw1) = Self::steps_b3(du, dw, wa_wb);
+                let (ku0, ku1) = (ua as $U2, if ub < du { ub as $U2 + 1 } else { du as $U2 });
+                let k0 = if ku0 < kv0 { kv0 } else { ku0 };
+                let k0 = if k0 < kw0 { kw0 } else { k0 };
+                let k1 = if kv1 < ku1 { kv1 } else { ku1 };
+                let k1 = if kw1 < k1 { kw1 } else { k1 };
+                if k1 <= k0 {
+                    return None;
+                }
+                let (v0, ev) = Self::minor_b3(v0, du, dv, sv, k0);
+                let (w0, ew) = Self::minor_b3(w0, du, dw, sw, k0);
+                let u1 = ops::<$UI>::wadd_su(u0, k1 as $U, su);
+                let u0 = ops::<$UI>::wadd_su(u0, k0 as $U, su);
+                Some(LineB3 { u0, v0, w0, du, dv, dw, ev, ew, u1, su, sv, sw, axis })
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty>) => {
+        impl $Self<$UI> {
+            /// Clips the directed, half-open line segment `(x0, y0, z0) -> (x1, y1, z1)`
+            /// to this region.
+            ///
+            /// Returns a [`LineB3`] over the portion of the segment inside this
+            /// clipping region, or [`None`] if the segment lies fully outside.
+            #[inline]
+            pub const fn line_b3(
+                &self,
+                x0: $UI,
+                y0: $UI,
+                z0: $UI,
+                x1: $UI,
+                y1: $UI,
+                z1: $UI,
+            ) -> Option<LineB3<$UI>> {
+                self.raw_line_b3(x0, y0, z0, x1, y1, z1)
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty, proj $U:ty>) => {
+        impl $Self<$UI> {
+            /// Clips and projects the directed, half-open line segment
+            /// `(x0, y0, z0) -> (x1, y1, z1)` to this region.
+            ///
+            /// Returns a [`LineB3`] over the portion of the segment inside this
+            /// clipping region relative to the region, or [`None`] if the segment
+            /// lies fully outside.
+            #[inline]
+            pub const fn line_b3_proj(
+                &self,
+                x0: $UI,
+                y0: $UI,
+                z0: $UI,
+                x1: $UI,
+                y1: $UI,
+                z1: $UI,
+            ) -> Option<LineB3<$U>> {
+                let LineB3 { u0, v0, w0, du, dv, dw, ev, ew, u1, su, sv, sw, axis } =
+                    try_opt!(self.raw_line_b3(x0, y0, z0, x1, y1, z1));
+                let [(u_min, _), (v_min, _), (w_min, _)] = self.uvw_min_max(axis);
+                let u0 = ops::<$UI>::wusub(u0, u_min);
+                let v0 = ops::<$UI>::wusub(v0, v_min);
+                let w0 = ops::<$UI>::wusub(w0, w_min);
+                let u1 = ops::<$UI>::wusub(u1, u_min);
+                Some(LineB3 { u0, v0, w0, du, dv, dw, ev, ew, u1, su, sv, sw, axis })
+            }
+        }
+    };
+}
+
+clip3_line_b3!(u8 | i8);
+clip3_line_b3!(u16 | i16);
+clip3_line_b3!(u32 | i32);
+clip3_line_b3!(u64 | i64);
+clip3_line_b3!(usize | isize);
@@ -0,0 +1,52 @@
+use crate::clip3::{Clip3, Viewport3};
+use crate::math::ops;
+
+macro_rules! clip3_point {
+    ($U:ty|$I:ty) => {
+        clip3_point!(@pub impl Clip3<$U>);
+        clip3_point!(@pub impl Clip3<$I>);
+        clip3_point!(@pub impl Viewport3<$U>);
+        clip3_point!(@pub impl Viewport3<$I>);
+
+        clip3_point!(@pub impl Clip3<$I, proj $U>);
+        clip3_point!(@pub impl Viewport3<$U, proj $U>);
+        clip3_point!(@pub impl Viewport3<$I, proj $U>);
+    };
+    (@pub impl $Self:ident<$UI:ty>) => {
+        impl $Self<$UI> {
+            /// Returns `true` if the point `(x, y, z)` is inside this region.
+            #[inline]
+            pub const fn point(&self, x: $UI, y: $UI, z: $UI) -> bool {
+                self.x_min() <= x
+                    && x <= self.x_max
+                    && self.y_min() <= y
+                    && y <= self.y_max
+                    && self.z_min() <= z
+                    && z <= self.z_max
+            }
+        }
+    };
+    (@pub impl $Self:ident<$UI:ty, proj $U:ty>) => {
+        impl $Self<$UI> {
+            /// Clips and projects the point `(x, y, z)` to this region.
+            ///
+            /// Returns the point relative to the region, or [`None`] if it lies outside.
+            #[inline]
+            pub const fn point_proj(&self, x: $UI, y: $UI, z: $UI) -> Option<($U, $U, $U)> {
+                if !self.point(x, y, z) {
+                    return None;
+                }
+                let x = ops::<$UI>::wusub(x, self.x_min());
+                let y = ops::<$UI>::wusub(y, self.y_min());
+                let z = ops::<$UI>::wusub(z, self.z_min());
+                Some((x, y, z))
+            }
+        }
+    };
+}
+
+clip3_point!(u8 | i8);
+clip3_point!(u16 | i16);
+clip3_point!(u32 | i32);
+clip3_point!(u64 | i64);
+clip3_point!(usize | isize);
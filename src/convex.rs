@@ -0,0 +1,515 @@
+use crate::clip::Viewport;
+use crate::line_b::LineB;
+use crate::macros::*;
+use crate::math::{Coord, ops};
+
+/// A closed convex polygonal clipping region.
+///
+/// The region is the intersection of the closed half-planes bounded by its edges,
+/// so the vertices may be given in either order. Collinear vertices are allowed,
+/// and a polygon with all its vertices on a line is the segment between them.
+///
+/// Use [`Clip`](crate::Clip) or [`Viewport`] if the region is an axis-aligned rectangle.
+///
+/// # Warning
+///
+/// Internally, this uses integers twice the size of the coordinate type.
+/// Keep this in mind when using `u64`, `i64`, `usize` and `isize` coordinates.
+/// If you do not need the whole range, prefer smaller coordinate types.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Convex<'a, C: Coord> {
+    pub(crate) points: &'a [(C, C)],
+    /// The sign of the turns between edges: `1`, `-1`, or `0` if all vertices are collinear.
+    pub(crate) turn: i8,
+    pub(crate) bounds: Viewport<C>,
+}
+
+/// An iterator over the visible runs of a [`LineB`] clipped to a [`Convex`] region.
+///
+/// Yields [`LineB`] iterators over the maximal runs of consecutive points of the unclipped
+/// segment that lie inside the region, in order along the segment. Together, they yield
+/// exactly the points of the unclipped segment inside the region.
+///
+/// The points of a segment running nearly parallel to an edge may alternate between
+/// both sides of the edge, which splits the segment into several runs.
+/// Otherwise, there is at most one.
+///
+/// # Performance
+///
+/// Each run takes `O(v log² n)` time for `v` vertices and `n` points, however long it is.
+/// Along each edge, the points lie on one side, then may alternate over a stretch found
+/// by bisection, where the points on either side are counted in closed form,
+/// then lie on the other side. A run may take a few times as long
+/// if the points alternate along more than one edge before it starts.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ConvexLineB<'a, C: Coord> {
+    pub(crate) region: Convex<'a, C>,
+    /// The remaining points after the current run.
+    pub(crate) line: LineB<C>,
+    pub(crate) run: LineB<C>,
+}
+
+/// The points of a [`LineB`], at `u0 + su * k` along its major axis
+/// and `v0 + sv * ((base + k * dv) / du)` along its minor axis for each step `k`.
+struct Steps<C: Coord> {
+    yx: bool,
+    u0: C,
+    v0: C,
+    su: i8,
+    sv: i8,
+    du: C::U2,
+    dv: C::U2,
+    base: C::U2,
+    len: C::U,
+}
+
+/// The closed half-plane `0 <= e.0 * (y - a.1) - e.1 * (x - a.0)` seen along [`Steps`],
+/// where the value changes by `da` per step and by `db` per minor step.
+struct HalfPlane<C: Coord> {
+    a: (C, C),
+    e: (C::I2, C::I2),
+    da: C::I2,
+    db: C::I2,
+}
+
+macro_rules! convex {
+    ($Cu:ty | $Ci:ty) => {
+        convex!($Cu, <$Cu as Coord>::U, <$Cu as Coord>::U2, <$Cu as Coord>::I2);
+        convex!($Ci, <$Ci as Coord>::U, <$Ci as Coord>::U2, <$Ci as Coord>::I2);
+    };
+    ($C:ty, $U:ty, $U2:ty, $I2:ty) => {
+        impl<'a> Convex<'a, $C> {
+            /// Returns a [`Convex`] region bounded by the polygon with the given vertices,
+            /// or [`None`] if there are no vertices or the polygon is not convex.
+            pub const fn new(points: &'a [($C, $C)]) -> Option<Self> {
+                let n = points.len();
+                if n == 0 {
+                    return None;
+                }
+                let (mut x_min, mut y_min) = points[0];
+                let (mut x_max, mut y_max) = points[0];
+                let mut turn = 0;
+                let mut spike = false;
+                // The last non-zero edge, and the sign of the last edge with a non-zero `dx`.
+                let (mut prev, mut sx) = ((0, 0), 0);
+                let mut i = n;
+                while 0 < i {
+                    i -= 1;
+                    let e = Self::edge(points, i);
+                    if prev.0 == 0 && prev.1 == 0 {
+                        prev = e;
+                    }
+                    if sx == 0 && e.0 != 0 {
+                        sx = if e.0 < 0 { -1 } else { 1 };
+                    }
+                }
+                let mut flips = 0;
+                while i < n {
+                    let (x, y) = points[i];
+                    x_min = if x < x_min { x } else { x_min };
+                    y_min = if y < y_min { y } else { y_min };
+                    x_max = if x_max < x { x } else { x_max };
+                    y_max = if y_max < y { y } else { y_max };
+                    let e = Self::edge(points, i);
+                    i += 1;
+                    if e.0 == 0 && e.1 == 0 {
+                        continue;
+                    }
+                    let cross = Self::cmp_mul(prev.0, e.1, prev.1, e.0);
+                    if cross == 0 {
+                        spike |= Self::cmp_mul(prev.0, e.0, -prev.1, e.1) < 0;
+                    } else if turn == 0 {
+                        turn = cross;
+                    } else if turn != cross {
+                        return None;
+                    }
+                    if e.0 != 0 {
+                        let s = if e.0 < 0 { -1 } else { 1 };
+                        flips += (s != sx) as u8;
+                        sx = s;
+                    }
+                    prev = e;
+                }
+                // A convex polygon winds around its interior once,
+                // so its edges change their direction along `X` at most twice.
+                if turn != 0 && spike || 2 < flips {
+                    return None;
+                }
+                let bounds = Viewport { x_min, y_min, x_max, y_max };
+                Some(Self { points, turn, bounds })
+            }
+
+            /// Returns the edge from vertex `i` to the next one.
+            #[inline]
+            const fn edge(points: &[($C, $C)], i: usize) -> ($I2, $I2) {
+                let (x0, y0) = points[i];
+                let (x1, y1) = points[(i + 1) % points.len()];
+                (x1 as $I2 - x0 as $I2, y1 as $I2 - y0 as $I2)
+            }
+
+            /// Returns whether `a * b` is negative, and its magnitude.
+            #[inline]
+            const fn smul(a: $I2, b: $I2) -> (bool, $U2) {
+                ((a < 0) != (b < 0) && a != 0 && b != 0, a.unsigned_abs() * b.unsigned_abs())
+            }
+
+            /// Returns the sign of `a * b - c * d`.
+            #[inline]
+            const fn cmp_mul(a: $I2, b: $I2, c: $I2, d: $I2) -> i8 {
+                let (p, q) = (Self::smul(a, b), Self::smul(c, d));
+                match (p.0, q.0) {
+                    (true, false) => -1,
+                    (false, true) => 1,
+                    (neg, _) => {
+                        let (p, q) = if neg { (q.1, p.1) } else { (p.1, q.1) };
+                        if p < q {
+                            -1
+                        } else if q < p {
+                            1
+                        } else {
+                            0
+                        }
+                    }
+                }
+            }
+
+            /// Returns the vertices of this region.
+            #[inline]
+            pub const fn points(&self) -> &'a [($C, $C)] {
+                self.points
+            }
+
+            /// Returns the smallest [`Viewport`] containing this region.
+            #[inline]
+            pub const fn bounds(&self) -> &Viewport<$C> {
+                &self.bounds
+            }
+
+            /// Returns `true` if the point `(x, y)` is inside this region.
+            #[inline]
+            pub const fn point(&self, x: $C, y: $C) -> bool {
+                if !self.bounds.point(x, y) {
+                    return false;
+                }
+                let mut i = 0;
+                while i < self.points.len() {
+                    let (ax, ay) = self.points[i];
+                    let e = Self::edge(self.points, i);
+                    let (px, py) = (x as $I2 - ax as $I2, y as $I2 - ay as $I2);
+                    let side = Self::cmp_mul(e.0, py, e.1, px);
+                    if side * self.turn < 0 || self.turn == 0 && side != 0 {
+                        return false;
+                    }
+                    i += 1;
+                }
+                true
+            }
+
+            /// Clips the directed, half-open line segment `(x0, y0) -> (x1, y1)` to this region.
+            ///
+            /// Returns a [`ConvexLineB`] over the runs of the segment inside this
+            /// clipping region, or [`None`] if the segment lies fully outside.
+            #[inline]
+            pub const fn line_b(
+                &self,
+                x0: $C,
+                y0: $C,
+                x1: $C,
+                y1: $C,
+            ) -> Option<ConvexLineB<'a, $C>> {
+                let line = try_opt!(self.bounds.line_b(x0, y0, x1, y1));
+                let run = LineB::<$C>::new(0, 0, 0, 0);
+                let mut runs = ConvexLineB { region: self.clone(), line, run };
+                runs.seek();
+                if runs.run.is_empty() {
+                    return None;
+                }
+                Some(runs)
+            }
+        }
+
+        impl<'a> ConvexLineB<'a, $C> {
+            /// Moves to the next run, if any.
+            #[inline]
+            const fn seek(&mut self) {
+                let steps = Steps::<$C>::new(&self.line);
+                let planes = 2 * self.region.points.len();
+                // Skip to the next point inside each half-plane in turn,
+                // until a full round leaves the start in place.
+                let (mut start, mut j, mut settled) = (0, 0, 0);
+                while settled < planes && start < steps.len {
+                    if let Some(h) = HalfPlane::<$C>::new(&self.region, &steps, j) {
+                        let next = h.skip(&steps, start, true);
+                        if start < next {
+                            (start, settled) = (next, 0);
+                        }
+                    }
+                    settled += 1;
+                    j = (j + 1) % planes;
+                }
+                let mut end = steps.len;
+                j = 0;
+                while start < end && j < planes {
+                    if let Some(h) = HalfPlane::<$C>::new(&self.region, &steps, j) {
+                        let next = h.skip(&steps, start, false);
+                        end = if next < end { next } else { end };
+                    }
+                    j += 1;
+                }
+                let (_, rest) = self.line.clone().split_at(start);
+                let (run, rest) = rest.split_at(end - start);
+                (self.run, self.line) = (run, rest);
+            }
+
+            /// Returns `true` if the iterator is empty.
+            #[inline]
+            pub const fn is_empty(&self) -> bool {
+                self.run.is_empty()
+            }
+
+            /// Returns the run at the start of the iterator.
+            /// This does not advance the iterator.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn head(&self) -> Option<LineB<$C>> {
+                if self.is_empty() {
+                    return None;
+                }
+                Some(self.run.clone())
+            }
+
+            /// Consumes and returns the run at the start of the iterator.
+            /// This advances the iterator forwards.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn pop_head(&mut self) -> Option<LineB<$C>> {
+                let head = try_opt!(self.head());
+                self.seek();
+                Some(head)
+            }
+        }
+
+        impl Steps<$C> {
+            #[inline]
+            const fn new(line: &LineB<$C>) -> Self {
+                let (yx, u0, v0, du, dv, err, su, sv, len) = match line {
+                    LineB::Bx(l) => (false, l.u0, l.v0, l.du, l.dv, l.err, l.su, l.sv, l.len()),
+                    LineB::By(l) => (true, l.u0, l.v0, l.du, l.dv, l.err, l.su, l.sv, l.len()),
+                };
+                if dv == 0 {
+                    return Self { yx, u0, v0, su, sv, du: 1, dv: 0, base: 0, len };
+                }
+                // The error term at the head lies in `[dv - du, dv)`.
+                let base = (err - dv as $I2 + du as $I2) as $U2;
+                Self { yx, u0, v0, su, sv, du: du as $U2, dv: dv as $U2, base, len }
+            }
+
+            /// Returns the point at step `k`, and the remainder of its minor offset.
+            #[inline]
+            const fn at(&self, k: $U) -> (($C, $C), $U2) {
+                let num = self.base + k as $U2 * self.dv;
+                let u = ops::<$C>::wadd_su(self.u0, k, self.su);
+                let v = ops::<$C>::wadd_su(self.v0, (num / self.du) as $U, self.sv);
+                (if self.yx { (v, u) } else { (u, v) }, num % self.du)
+            }
+        }
+
+        impl HalfPlane<$C> {
+            /// Returns the half-plane of edge `j / 2`, reversed if `j` is odd,
+            /// or [`None`] if it does not bound the region.
+            #[inline]
+            const fn new(region: &Convex<'_, $C>, steps: &Steps<$C>, j: usize) -> Option<Self> {
+                let (i, o) = (j / 2, if j % 2 == 0 { 1 } else { -1 });
+                let e = Convex::<$C>::edge(region.points, i);
+                if region.turn != 0 && region.turn != o || e.0 == 0 && e.1 == 0 {
+                    return None;
+                }
+                let e = (o as $I2 * e.0, o as $I2 * e.1);
+                let (su, sv) = (steps.su as $I2, steps.sv as $I2);
+                let (da, db) = if steps.yx { (e.0 * su, -e.1 * sv) } else { (-e.1 * su, e.0 * sv) };
+                Some(Self { a: region.points[i], e, da, db })
+            }
+
+            /// Returns the sign of the value at step `k`, the value itself if its
+            /// magnitude is less than `|db|`, and the remainder of the minor offset.
+            #[inline]
+            const fn value(&self, steps: &Steps<$C>, k: $U) -> (i8, Option<$I2>, $U2) {
+                let ((x, y), r) = steps.at(k);
+                let (px, py) = (x as $I2 - self.a.0 as $I2, y as $I2 - self.a.1 as $I2);
+                let sign = Convex::<$C>::cmp_mul(self.e.0, py, self.e.1, px);
+                let p = Convex::<$C>::smul(self.e.0, py);
+                let q = Convex::<$C>::smul(self.e.1, px);
+                let abs = match (p.0 == q.0, p.1 < q.1) {
+                    (true, true) => Some(q.1 - p.1),
+                    (true, false) => Some(p.1 - q.1),
+                    (false, _) => p.1.checked_add(q.1),
+                };
+                match abs {
+                    Some(abs) if abs < self.db.unsigned_abs() => {
+                        let value = if sign < 0 { -(abs as $I2) } else { abs as $I2 };
+                        (sign, Some(value), r)
+                    }
+                    _ => (sign, None, r),
+                }
+            }
+
+            /// Returns `true` if the value on the underlying real line, shifted by
+            /// the largest (`upper`) or smallest possible rounding, is non-negative at step `k`.
+            ///
+            /// Unlike the value at the points, these are linear in `k`.
+            #[inline]
+            const fn bound(&self, steps: &Steps<$C>, k: $U, upper: bool) -> bool {
+                let (sign, value, r) = self.value(steps, k);
+                let Some(value) = value else {
+                    return 0 <= sign;
+                };
+                let r = r as $I2;
+                let t = if (0 <= self.db) == upper { r } else { r - steps.du as $I2 + 1 };
+                0 <= Convex::<$C>::cmp_mul(steps.du as $I2, value, -self.db, t)
+            }
+
+            /// Returns the first step in `[lo, hi)` where [`Self::bound`] is `val`,
+            /// given that it changes at most once there, or `hi` if there is none.
+            #[inline]
+            const fn find(
+                &self,
+                steps: &Steps<$C>,
+                mut lo: $U,
+                mut hi: $U,
+                upper: bool,
+                val: bool,
+            ) -> $U {
+                while lo < hi {
+                    let mid = lo + (hi - lo) / 2;
+                    if self.bound(steps, mid, upper) == val {
+                        hi = mid;
+                    } else {
+                        lo = mid + 1;
+                    }
+                }
+                lo
+            }
+
+            /// Like [`Self::find`] in `[k, len)`, but gallops from `k`
+            /// to take time logarithmic in the distance to the result.
+            #[inline]
+            const fn reach(&self, steps: &Steps<$C>, k: $U, upper: bool, val: bool) -> $U {
+                let (mut lo, mut gap): ($U, $U) = (k, 1);
+                while lo < steps.len {
+                    let hi = if steps.len - lo <= gap { steps.len } else { lo + gap };
+                    if self.bound(steps, hi - 1, upper) == val {
+                        return self.find(steps, lo, hi, upper, val);
+                    }
+                    (lo, gap) = (hi, gap.saturating_mul(2));
+                }
+                steps.len
+            }
+
+            /// Returns the number of points inside among the `n` steps from a step
+            /// with the given `value` and remainder `r`, given that the points may
+            /// alternate at all of them, that is, the bounds of [`Self::bound`] differ.
+            ///
+            /// There, a point is inside if and only if its minor offset from `z`
+            /// is at least (at most, if `db < 0`) the offset at which the value crosses zero,
+            /// and these differ by at most one, so the count is a sum of floors.
+            #[inline]
+            const fn count(&self, steps: &Steps<$C>, (value, r): ($I2, $U2), n: $U) -> $U2 {
+                let n = n as $U2;
+                let minor = ops::<$C>::floor_sum(n, steps.du, steps.dv, r);
+                let m = self.db.unsigned_abs() as $I2;
+                let (qa, ra) = (self.da.div_euclid(m), self.da.rem_euclid(m));
+                let (qb, rb) = (value.div_euclid(m), value.rem_euclid(m));
+                let cross = ops::<$C>::floor_sum(n, m as $U2, ra as $U2, rb as $U2)
+                    .wrapping_add(ops::<$C>::pairs(n).wrapping_mul(qa as $U2))
+                    .wrapping_add(n.wrapping_mul(qb as $U2));
+                let minor = if 0 < self.db { minor } else { minor.wrapping_neg() };
+                n.wrapping_add(minor).wrapping_add(cross)
+            }
+
+            /// Returns `true` if the steps `[k, t)`, which lie in the stretch from `z` where
+            /// the points may alternate, hold a point inside if `inside` and outside otherwise.
+            #[inline]
+            const fn found(
+                &self,
+                steps: &Steps<$C>,
+                (z, value, r): ($U, $I2, $U2),
+                k: $U,
+                t: $U,
+                inside: bool,
+            ) -> bool {
+                let n = self.count(steps, (value, r), t - z);
+                let n = n.wrapping_sub(self.count(steps, (value, r), k - z));
+                0 < if inside { n } else { (t - k) as $U2 - n }
+            }
+
+            /// Returns the first step in `[k, len)` whose point is inside if `inside`
+            /// and outside otherwise, or `len` if there is none.
+            ///
+            /// The points are on one side of the half-plane up to the stretch where
+            /// they may alternate, and on the other side after it. Within it, they are counted.
+            const fn skip(&self, steps: &Steps<$C>, mut k: $U, inside: bool) -> $U {
+                let len = steps.len;
+                let rising =
+                    0 <= Convex::<$C>::cmp_mul(self.da, steps.du as $I2, -self.db, steps.dv as $I2);
+                if k < len && self.bound(steps, k, rising) != rising {
+                    if rising != inside {
+                        return k;
+                    }
+                    k = self.reach(steps, k, rising, rising);
+                }
+                if k < len && self.bound(steps, k, !rising) != rising {
+                    let (_, Some(value), r) = self.value(steps, k) else { unreachable!() };
+                    let zone = (k, value, r);
+                    // Gallop, then bisect, to the first step `t` such that the steps `[k, t)`
+                    // hold a wanted point, which is the one at `t - 1`, unless the stretch ends.
+                    let (mut lo, mut gap): ($U, $U) = (k, 1);
+                    loop {
+                        let mut hi = if len - lo <= gap { len } else { lo + gap };
+                        let ends = self.bound(steps, hi - 1, !rising) == rising;
+                        if ends {
+                            hi = self.find(steps, lo, hi, !rising, rising);
+                        }
+                        if self.found(steps, zone, k, hi, inside) {
+                            while lo + 1 < hi {
+                                let mid = lo + (hi - lo) / 2;
+                                if self.found(steps, zone, k, mid, inside) {
+                                    hi = mid;
+                                } else {
+                                    lo = mid;
+                                }
+                            }
+                            return hi - 1;
+                        }
+                        if ends || hi == len {
+                            k = hi;
+                            break;
+                        }
+                        (lo, gap) = (hi, gap.saturating_mul(2));
+                    }
+                }
+                if k < len && rising == inside { k } else { len }
+            }
+        }
+
+        impl Iterator for ConvexLineB<'_, $C> {
+            type Item = LineB<$C>;
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.pop_head()
+            }
+        }
+
+        impl core::iter::FusedIterator for ConvexLineB<'_, $C> {}
+    };
+}
+
+clone!(['a, C: Coord] Convex<'a, C>, fields = [bounds]);
+clone!(['a, C: Coord] ConvexLineB<'a, C>, fields = [region, line, run]);
+
+convex!(u8 | i8);
+convex!(u16 | i16);
+convex!(u32 | i32);
+convex!(u64 | i64);
+convex!(usize | isize);
@@ -0,0 +1,153 @@
+use crate::circle::Circle;
+use crate::macros::*;
+use crate::math::Coord;
+
+/// An iterator over the horizontal spans of a filled disc.
+///
+/// Yields `(y, x_start, x_end)`, where `x_start <= x_end` are both inside the span.
+/// Rows are yielded in increasing order of `y`, each at most once.
+///
+/// Each span runs between the outermost points of the [`Circle`] with the same
+/// center and radius in its row, so the disc covers the circle and its interior.
+///
+/// # Warning
+///
+/// Internally, this uses integers twice the size of the coordinate type.
+/// Keep this in mind when using `u64`, `i64`, `usize` and `isize` coordinates.
+/// If you do not need the whole range, prefer smaller coordinate types.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Disc<C: Coord> {
+    pub(crate) xc: C::I2,
+    pub(crate) yc: C::I2,
+    pub(crate) r: C::U,
+    /// The diagonal row of the first octant of the circle.
+    pub(crate) m: C::U,
+    pub(crate) r0: C::I2,
+    pub(crate) r1: C::I2,
+    pub(crate) c0: C::I2,
+    pub(crate) c1: C::I2,
+}
+
+macro_rules! disc {
+    ($Cu:ty | $Ci:ty) => {
+        disc!($Cu, <$Cu as Coord>::U, <$Cu as Coord>::U2, <$Cu as Coord>::I2);
+        disc!($Ci, <$Ci as Coord>::U, <$Ci as Coord>::U2, <$Ci as Coord>::I2);
+    };
+    ($C:ty, $U:ty, $U2:ty, $I2:ty) => {
+        impl Disc<$C> {
+            /// Returns a [`Disc`] over the disc centered at `(xc, yc)` with radius `r`.
+            ///
+            /// Parts of the disc that do not fit into the coordinate type are skipped.
+            #[inline]
+            pub const fn new(xc: $C, yc: $C, r: $U) -> Self {
+                let (xc, yc) = (xc as $I2, yc as $I2);
+                let (min, max) = (<$C>::MIN as $I2, <$C>::MAX as $I2);
+                let rows = (min - yc, max - yc);
+                let cols = (min - xc, max - xc);
+                Self::raw(xc, yc, r, rows, cols)
+            }
+
+            /// Returns a [`Disc`] whose rows and columns relative to `(xc, yc)`
+            /// are restricted to the closed ranges `rows` and `cols`.
+            #[inline]
+            pub(crate) const fn raw(
+                xc: $I2,
+                yc: $I2,
+                r: $U,
+                rows: ($I2, $I2),
+                cols: ($I2, $I2),
+            ) -> Self {
+                let (m, _) = Circle::<$C>::diagonal(r);
+                let mut disc = Self { xc, yc, r, m, r0: 1, r1: 0, c0: cols.0, c1: cols.1 };
+                // The disc is symmetric about the diagonal, so the rows reaching
+                // the column `need` are the ones within the half-width of row `need`.
+                let need = if cols.0 < -cols.1 { -cols.1 } else { cols.0 };
+                if (r as $I2) < need {
+                    return disc;
+                }
+                let t = if need <= 0 { r as $I2 } else { disc.half_width(need) };
+                disc.r0 = if rows.0 < -t { -t } else { rows.0 };
+                disc.r1 = if t < rows.1 { t } else { rows.1 };
+                disc
+            }
+
+            /// Returns the half-width of row `|t| <= r` relative to the center.
+            #[inline]
+            const fn half_width(&self, t: $I2) -> $I2 {
+                let t = t.unsigned_abs() as $U;
+                if t <= self.m {
+                    return Circle::<$C>::x_at(self.r, t) as $I2;
+                }
+                let (r, t) = (self.r as $U2, t as $U2);
+                Circle::<$C>::ceil_sqrt(r * r - t * t + t) as $I2 - 1
+            }
+
+            /// Returns `true` if the iterator is empty.
+            #[inline]
+            pub const fn is_empty(&self) -> bool {
+                self.r1 < self.r0
+            }
+
+            /// Returns the remaining length of this iterator.
+            #[inline]
+            pub const fn len(&self) -> $U2 {
+                if self.is_empty() {
+                    return 0;
+                }
+                (self.r1 - self.r0) as $U2 + 1
+            }
+
+            /// Returns the span at the start of the iterator.
+            /// This does not advance the iterator.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn head(&self) -> Option<($C, $C, $C)> {
+                if self.is_empty() {
+                    return None;
+                }
+                let w = self.half_width(self.r0);
+                let a = if -w < self.c0 { self.c0 } else { -w };
+                let b = if self.c1 < w { self.c1 } else { w };
+                let y = (self.yc + self.r0) as $C;
+                Some((y, (self.xc + a) as $C, (self.xc + b) as $C))
+            }
+
+            /// Consumes and returns the span at the start of the iterator.
+            /// This advances the iterator forwards.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn pop_head(&mut self) -> Option<($C, $C, $C)> {
+                let head = try_opt!(self.head());
+                self.r0 += 1;
+                Some(head)
+            }
+        }
+
+        impl Iterator for Disc<$C> {
+            type Item = ($C, $C, $C);
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.pop_head()
+            }
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match usize::try_from(self.len()) {
+                    Ok(len) => (len, Some(len)),
+                    Err(_) => (usize::MAX, None),
+                }
+            }
+        }
+
+        impl core::iter::FusedIterator for Disc<$C> {}
+    };
+}
+
+clone!([C: Coord] Disc<C>);
+
+disc!(u8 | i8);
+disc!(u16 | i16);
+disc!(u32 | i32);
+disc!(u64 | i64);
+disc!(usize | isize);
@@ -0,0 +1,525 @@
+use crate::macros::*;
+use crate::math::Coord;
+
+/// An iterator over the rasterized points of an axis-aligned ellipse or an elliptical arc.
+///
+/// The ellipse with radii `a` along `X` and `b` along `Y` is the boundary of the
+/// [`EllipseFill`](crate::EllipseFill) with the same center and radii: in the first quadrant,
+/// a filled point `(x, y)` lies on the ellipse if `(x + 1, y)` or `(x, y + 1)` is not filled.
+/// A point is filled if it is covered by rounding the exact ellipse to the nearest point
+/// in its row or in its column, as the midpoint ellipse algorithm does.
+///
+/// Quadrants are numbered from `0` to `3`, starting at the positive `X` axis
+/// and turning towards the positive `Y` axis. Points are yielded quadrant by quadrant
+/// in this direction, each point exactly once. Points on the boundary between
+/// two quadrants belong to the even one, and the center of a degenerate ellipse
+/// belongs to quadrant `0`.
+///
+/// # Warning
+///
+/// Internally, this uses integers twice the size of the coordinate type,
+/// and products four times the size of the coordinate type to round the ellipse.
+/// Keep this in mind when using `u64`, `i64`, `usize` and `isize` coordinates.
+/// If you do not need the whole range, prefer smaller coordinate types.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Ellipse<C: Coord> {
+    pub(crate) xc: C::I2,
+    pub(crate) yc: C::I2,
+    pub(crate) a: C::U,
+    pub(crate) b: C::U,
+    pub(crate) x: C::I2,
+    pub(crate) y: C::I2,
+    pub(crate) x_end: C::I2,
+    pub(crate) y_end: C::I2,
+    /// The closed range of columns of the current quadrant within the bounds.
+    pub(crate) c0: C::I2,
+    pub(crate) c1: C::I2,
+    /// The last filled columns of rows `y` and `y + 1`, clamped to `c0 - 1..=c1`.
+    pub(crate) w0: C::I2,
+    pub(crate) w1: C::I2,
+    /// The midpoint decision variable `b² x² + a² y² - a² b²` as `(hi, lo)` at the end
+    /// of row `y + 1` in even quadrants, and of row `y` in odd quadrants.
+    pub(crate) g: (C::I2, C::U2),
+    pub(crate) q: u8,
+    pub(crate) q_end: u8,
+    pub(crate) x_min: C::I2,
+    pub(crate) x_max: C::I2,
+    pub(crate) y_min: C::I2,
+    pub(crate) y_max: C::I2,
+}
+
+/// Returns the product `(hi, lo)` of `a` and `b`.
+#[inline]
+const fn mul_u256(a: u128, b: u128) -> (u128, u128) {
+    let (a1, a0) = (a >> 64, a as u64 as u128);
+    let (b1, b0) = (b >> 64, b as u64 as u128);
+    let (p00, p01, p10, p11) = (a0 * b0, a0 * b1, a1 * b0, a1 * b1);
+    let mid = (p00 >> 64) + (p01 as u64 as u128) + (p10 as u64 as u128);
+    let lo = (mid << 64) | (p00 as u64 as u128);
+    let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+/// Returns `(p * m - c) / d` rounded down, or [`None`] if `p * m < c`,
+/// provided that the quotient fits into `u128`.
+#[inline]
+const fn muldiv_u256(p: u128, m: u128, c: u128, d: u128) -> Option<u128> {
+    let (hi, lo) = mul_u256(p, m);
+    let (lo, borrow) = lo.overflowing_sub(c);
+    if hi == 0 && borrow {
+        return None;
+    }
+    // The quotient fits, so `hi < d`, and long division never overflows.
+    let (mut q, mut r) = (0, hi - borrow as u128);
+    let mut i = 128;
+    while 0 < i {
+        i -= 1;
+        let carry = r >> 127 == 1;
+        r = (r << 1) | ((lo >> i) & 1);
+        q <<= 1;
+        if carry || d <= r {
+            r = r.wrapping_sub(d);
+            q |= 1;
+        }
+    }
+    Some(q)
+}
+
+macro_rules! ellipse {
+    ($Cu:ty | $Ci:ty, $W:tt) => {
+        ellipse!($Cu, <$Cu as Coord>::U, <$Cu as Coord>::U2, <$Cu as Coord>::I2, $W);
+        ellipse!($Ci, <$Ci as Coord>::U, <$Ci as Coord>::U2, <$Ci as Coord>::I2, $W);
+    };
+    (@wmul $U2:ty, $I2:ty, u256) => {
+        /// Returns the product of `p` and `q` as a signed integer
+        /// four times the size of the coordinate type, in wrapping arithmetic.
+        #[inline]
+        const fn wmul(p: $U2, q: $U2) -> ($I2, $U2) {
+            let (hi, lo) = mul_u256(p as u128, q as u128);
+            (hi as $I2, lo as $U2)
+        }
+    };
+    (@wmul $U2:ty, $I2:ty, $W:ty) => {
+        /// Returns the product of `p` and `q` as a signed integer
+        /// four times the size of the coordinate type, in wrapping arithmetic.
+        #[inline]
+        const fn wmul(p: $U2, q: $U2) -> ($I2, $U2) {
+            let t = p as $W * q as $W;
+            ((t >> <$U2>::BITS) as $I2, t as $U2)
+        }
+    };
+    (@muldiv $U2:ty, u256) => {
+        /// Returns `(p * m - c) / d` rounded down, or [`None`] if `p * m < c`,
+        /// provided that the quotient fits into the double-width type.
+        #[inline]
+        const fn muldiv(p: $U2, m: $U2, c: $U2, d: $U2) -> Option<$U2> {
+            match muldiv_u256(p as u128, m as u128, c as u128, d as u128) {
+                Some(q) => Some(q as $U2),
+                None => None,
+            }
+        }
+    };
+    (@muldiv $U2:ty, $W:ty) => {
+        /// Returns `(p * m - c) / d` rounded down, or [`None`] if `p * m < c`,
+        /// provided that the quotient fits into the double-width type.
+        #[inline]
+        const fn muldiv(p: $U2, m: $U2, c: $U2, d: $U2) -> Option<$U2> {
+            let t = p as $W * m as $W;
+            if t < c as $W {
+                return None;
+            }
+            Some(((t - c as $W) / d as $W) as $U2)
+        }
+    };
+    ($C:ty, $U:ty, $U2:ty, $I2:ty, $W:tt) => {
+        impl Ellipse<$C> {
+            ellipse!(@muldiv $U2, $W);
+            ellipse!(@wmul $U2, $I2, $W);
+
+            /// Returns `p + q` in wrapping arithmetic.
+            #[inline]
+            const fn wadd((ph, pl): ($I2, $U2), (qh, ql): ($I2, $U2)) -> ($I2, $U2) {
+                let (lo, carry) = pl.overflowing_add(ql);
+                (ph.wrapping_add(qh).wrapping_add(carry as $I2), lo)
+            }
+
+            /// Returns `p - q` in wrapping arithmetic.
+            #[inline]
+            const fn wsub((ph, pl): ($I2, $U2), (qh, ql): ($I2, $U2)) -> ($I2, $U2) {
+                let (lo, borrow) = pl.overflowing_sub(ql);
+                (ph.wrapping_sub(qh).wrapping_sub(borrow as $I2), lo)
+            }
+
+            /// Returns an [`Ellipse`] over the ellipse centered at `(xc, yc)`
+            /// with radii `a` along `X` and `b` along `Y`.
+            ///
+            /// Points that do not fit into the coordinate type are skipped.
+            #[inline]
+            pub const fn new(xc: $C, yc: $C, a: $U, b: $U) -> Self {
+                Self::arc(xc, yc, a, b, 0, 0)
+            }
+
+            /// Returns an [`Ellipse`] over the arc of the ellipse centered at `(xc, yc)`
+            /// with radii `a` along `X` and `b` along `Y`, covering the quadrants
+            /// from `start` up to, but not including, `end`.
+            ///
+            /// Quadrants are taken modulo `4`. If `start` and `end` are equal,
+            /// this covers the whole ellipse.
+            ///
+            /// Points that do not fit into the coordinate type are skipped.
+            #[inline]
+            pub const fn arc(xc: $C, yc: $C, a: $U, b: $U, start: u8, end: u8) -> Self {
+                let (xc, yc) = (xc as $I2, yc as $I2);
+                let (min, max) = (<$C>::MIN as $I2, <$C>::MAX as $I2);
+                let bounds = (min - xc, max - xc, min - yc, max - yc);
+                Self::raw(xc, yc, a, b, start, end, bounds)
+            }
+
+            /// Returns an [`Ellipse`] whose points relative to the center `(xc, yc)`
+            /// are restricted to the closed ranges `bounds = (x_min, x_max, y_min, y_max)`.
+            #[inline]
+            pub(crate) const fn raw(
+                xc: $I2,
+                yc: $I2,
+                a: $U,
+                b: $U,
+                start: u8,
+                end: u8,
+                (x_min, x_max, y_min, y_max): ($I2, $I2, $I2, $I2),
+            ) -> Self {
+                let (start, end) = (start % 4, end % 4);
+                let q_end = if start < end { end } else { end + 4 };
+                let mut ellipse = Self {
+                    xc,
+                    yc,
+                    a,
+                    b,
+                    x: 0,
+                    y: 0,
+                    x_end: 0,
+                    y_end: 0,
+                    c0: 0,
+                    c1: 0,
+                    w0: 0,
+                    w1: 0,
+                    g: (0, 0),
+                    q: start,
+                    q_end,
+                    x_min,
+                    x_max,
+                    y_min,
+                    y_max,
+                };
+                ellipse.seek(start);
+                ellipse
+            }
+
+            /// Returns the last filled column of row `0 <= y`,
+            /// or `-1` if the row is past the ellipse.
+            #[inline]
+            pub(crate) const fn half_width(a: $U, b: $U, y: $I2) -> $I2 {
+                if (b as $I2) < y {
+                    return -1;
+                }
+                if b == 0 {
+                    return a as $I2;
+                }
+                let (a2, b2) = (a as $U2 * a as $U2, b as $U2 * b as $U2);
+                let s = b2 - y as $U2 * y as $U2;
+                // Rounding the row: (2x - 1)² b² <= 4 a² (b² - y²),
+                // so x <= (sqrt(4q + 1) + 1) / 2 with q = (a² (b² - y²) - b² / 4) / b².
+                let x_row = match Self::muldiv(a2, s, b2.div_ceil(4), b2) {
+                    Some(q) => {
+                        let r = q.isqrt();
+                        if r * r + r <= q { r + 1 } else { r }
+                    }
+                    None => 0,
+                };
+                // Rounding the column: (2y - 1)² a² <= 4 b² (a² - x²),
+                // so x² <= (a² (b² - y² + y) - a² / 4) / b².
+                let x_col = match Self::muldiv(a2, s + y as $U2, a2.div_ceil(4), b2) {
+                    Some(q) => q.isqrt(),
+                    None => 0,
+                };
+                (if x_row < x_col { x_col } else { x_row }) as $I2
+            }
+
+            /// Returns the first column of row `0 <= y` that lies on the ellipse.
+            #[inline]
+            const fn edge(&self, y: $I2) -> $I2 {
+                let w = Self::half_width(self.a, self.b, y);
+                let v = Self::half_width(self.a, self.b, y + 1) + 1;
+                if v < w { v } else { w }
+            }
+
+            /// Returns the midpoint decision variable `b² x² + a² y² - a² b²` as `(hi, lo)`
+            /// for `|x| <= a` and `0 <= y <= b`.
+            #[inline]
+            const fn decision(a: $U, b: $U, x: $I2, y: $I2) -> ($I2, $U2) {
+                let (a2, b2) = (a as $U2 * a as $U2, b as $U2 * b as $U2);
+                let (x, y) = (x.unsigned_abs(), y.unsigned_abs());
+                let g = Self::wadd(Self::wmul(b2, x * x), Self::wmul(a2, y * y));
+                Self::wsub(g, Self::wmul(a2, b2))
+            }
+
+            /// Returns `true` if the point `(x, y)` with `0 <= x` and `0 <= y <= b`
+            /// is filled, given the decision variable `g` at that point.
+            #[inline]
+            const fn filled(a: $U, b: $U, x: $I2, y: $I2, g: ($I2, $U2)) -> bool {
+                let (a2, b2) = (a as $U2 * a as $U2, b as $U2 * b as $U2);
+                // Rounding the row: (2x - 1)² b² <= 4 a² (b² - y²), so g + b² / 4 <= b² x.
+                let row = Self::wadd(g, (0, b2.div_ceil(4)));
+                let (row, _) = Self::wsub(Self::wmul(b2, x as $U2), row);
+                // Rounding the column: (2y - 1)² a² <= 4 b² (a² - x²), so g + a² / 4 <= a² y.
+                let col = Self::wadd(g, (0, a2.div_ceil(4)));
+                let (col, _) = Self::wsub(Self::wmul(a2, y as $U2), col);
+                0 <= row || 0 <= col
+            }
+
+            /// Returns the last filled column `w` of a row clamped to `c0 - 1..=c1`.
+            #[inline]
+            const fn clamp(&self, w: $I2) -> $I2 {
+                if w < self.c0 {
+                    self.c0 - 1
+                } else if self.c1 < w {
+                    self.c1
+                } else {
+                    w
+                }
+            }
+
+            /// Returns `(nx, ny)`, such that the point `(x, y)` of the first quadrant
+            /// maps to `(nx * x, ny * y)` in quadrant `q`.
+            #[inline]
+            const fn transform(q: u8) -> (bool, bool) {
+                match q % 4 {
+                    0 => (false, false),
+                    1 => (true, false),
+                    2 => (true, true),
+                    _ => (false, true),
+                }
+            }
+
+            /// Returns the closed ranges `(r0, r1, c0, c1)` of non-empty rows
+            /// and of columns of quadrant `q` within the bounds.
+            #[inline]
+            const fn run(&self, q: u8) -> Option<($I2, $I2, $I2, $I2)> {
+                let (nx, ny) = Self::transform(q);
+                let (c0, c1) =
+                    if nx { (-self.x_max, -self.x_min) } else { (self.x_min, self.x_max) };
+                let (r0, r1) =
+                    if ny { (-self.y_max, -self.y_min) } else { (self.y_min, self.y_max) };
+                // Axes belong to the even quadrants, and the center to quadrant 0.
+                let (x0, y0) = match q % 4 {
+                    1 | 3 => (1, 1),
+                    2 if self.a == 0 => (0, 1),
+                    2 if self.b == 0 => (1, 0),
+                    _ => (0, 0),
+                };
+                let c0 = if c0 < x0 { x0 } else { c0 };
+                let c1 = if (self.a as $I2) < c1 { self.a as $I2 } else { c1 };
+                let r0 = if r0 < y0 { y0 } else { r0 };
+                let r1 = if (self.b as $I2) < r1 { self.b as $I2 } else { r1 };
+                if c1 < c0 || r1 < r0 {
+                    return None;
+                }
+                // Both ends of the rows only move left going up,
+                // so the rows reaching the columns are contiguous.
+                let (mut lo, mut hi) = (r0, r1 + 1);
+                while lo < hi {
+                    let mid = lo + (hi - lo) / 2;
+                    if self.edge(mid) <= c1 { hi = mid } else { lo = mid + 1 }
+                }
+                let r0 = lo;
+                let (mut lo, mut hi) = (r0 - 1, r1);
+                while lo < hi {
+                    let mid = hi - (hi - lo) / 2;
+                    if c0 <= Self::half_width(self.a, self.b, mid) {
+                        lo = mid
+                    } else {
+                        hi = mid - 1
+                    }
+                }
+                let r1 = lo;
+                if r1 < r0 {
+                    return None;
+                }
+                Some((r0, r1, c0, c1))
+            }
+
+            /// Moves to the start of the current row.
+            #[inline]
+            const fn enter_row(&mut self) {
+                // Rows within the columns end past `c0` and start before `c1`,
+                // so the clamped ends of the row and of the row above give its span.
+                let lo = if self.w0 <= self.w1 { self.w0 } else { self.w1 + 1 };
+                let (lo, hi) = (if lo < self.c0 { self.c0 } else { lo }, self.w0);
+                (self.x, self.x_end) = if self.q % 2 == 0 { (hi, lo) } else { (lo, hi) };
+            }
+
+            /// Moves to the next row of the current quadrant.
+            ///
+            /// The end of the row ahead is stepped with the decision variable,
+            /// which takes time linear in the number of columns it moves by.
+            #[inline]
+            const fn next_row(&mut self) {
+                let (a, b) = (self.a, self.b);
+                let (a2, b2) = (a as $U2 * a as $U2, b as $U2 * b as $U2);
+                if self.q % 2 == 0 {
+                    (self.y, self.w0) = (self.y + 1, self.w1);
+                    let y = self.y + 1;
+                    if (b as $I2) < y {
+                        self.w1 = self.c0 - 1;
+                    } else {
+                        // Moving up to row `y` adds a² (2y - 1),
+                        // and moving left from column `x` subtracts b² (2x - 1).
+                        // Column `0` of the row is filled, so `x` stays positive.
+                        self.g = Self::wadd(self.g, Self::wmul(a2, (2 * y - 1) as $U2));
+                        while self.c0 <= self.w1 && !Self::filled(a, b, self.w1, y, self.g) {
+                            let dg = Self::wmul(b2, (2 * self.w1 - 1) as $U2);
+                            (self.g, self.w1) = (Self::wsub(self.g, dg), self.w1 - 1);
+                        }
+                    }
+                } else {
+                    (self.y, self.w1) = (self.y - 1, self.w0);
+                    let y = self.y;
+                    // Moving down to row `y` subtracts a² (2y + 1),
+                    // and moving right from column `x` adds b² (2x + 1).
+                    self.g = Self::wsub(self.g, Self::wmul(a2, (2 * y + 1) as $U2));
+                    while self.w0 < self.c1 {
+                        let g = Self::wadd(self.g, Self::wmul(b2, (2 * self.w0 + 1) as $U2));
+                        if !Self::filled(a, b, self.w0 + 1, y, g) {
+                            break;
+                        }
+                        (self.g, self.w0) = (g, self.w0 + 1);
+                    }
+                }
+                self.enter_row();
+            }
+
+            /// Moves to the first non-empty run, starting with quadrant `q`.
+            #[inline]
+            const fn seek(&mut self, mut q: u8) {
+                while q < self.q_end {
+                    if let Some((r0, r1, c0, c1)) = self.run(q) {
+                        (self.y, self.y_end) = if q % 2 == 0 { (r0, r1) } else { (r1, r0) };
+                        (self.c0, self.c1, self.q) = (c0, c1, q);
+                        let (a, b, y) = (self.a, self.b, self.y);
+                        self.w0 = self.clamp(Self::half_width(a, b, y));
+                        self.w1 = self.clamp(Self::half_width(a, b, y + 1));
+                        let (x, y) = if q % 2 == 0 { (self.w1, y + 1) } else { (self.w0, y) };
+                        // Rows past the ellipse are not stepped.
+                        self.g = if y <= b as $I2 { Self::decision(a, b, x, y) } else { (0, 0) };
+                        self.enter_row();
+                        return;
+                    }
+                    q += 1;
+                }
+                self.q = q;
+            }
+
+            /// Returns `true` if the iterator is empty.
+            #[inline]
+            pub const fn is_empty(&self) -> bool {
+                self.q_end <= self.q
+            }
+
+            /// Returns lower and upper bounds on the remaining length of this iterator.
+            ///
+            /// Each remaining row has a point, and the rows of a quadrant form a staircase
+            /// in which consecutive rows share at most one column.
+            #[inline]
+            const fn len_bounds(&self) -> ($U2, $U2) {
+                if self.is_empty() {
+                    return (0, 0);
+                }
+                let row = (if self.x < self.x_end { self.x_end - self.x } else { self.x - self.x_end })
+                    as $U2
+                    + 1;
+                let (rows, cols) = if self.q % 2 == 0 {
+                    (self.y_end - self.y, self.x_end - self.c0 + 1)
+                } else {
+                    (self.y - self.y_end, self.c1 - self.x_end + 1)
+                };
+                let (mut lo, mut hi) = (row + rows as $U2, row);
+                if 0 < rows {
+                    hi += rows as $U2 + cols as $U2 - 1;
+                }
+                let mut q = self.q + 1;
+                while q < self.q_end {
+                    if let Some((r0, r1, c0, c1)) = self.run(q) {
+                        lo += (r1 - r0) as $U2 + 1;
+                        hi += (r1 - r0) as $U2 + (c1 - c0) as $U2 + 1;
+                    }
+                    q += 1;
+                }
+                (lo, hi)
+            }
+
+            /// Returns the point at the start of the iterator.
+            /// This does not advance the iterator.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn head(&self) -> Option<($C, $C)> {
+                if self.is_empty() {
+                    return None;
+                }
+                let (nx, ny) = Self::transform(self.q);
+                let x = if nx { self.xc - self.x } else { self.xc + self.x };
+                let y = if ny { self.yc - self.y } else { self.yc + self.y };
+                Some((x as $C, y as $C))
+            }
+
+            /// Consumes and returns the point at the start of the iterator.
+            /// This advances the iterator forwards.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn pop_head(&mut self) -> Option<($C, $C)> {
+                let head = try_opt!(self.head());
+                let step = if self.q % 2 == 0 { 1 } else { -1 };
+                if self.x != self.x_end {
+                    self.x -= step;
+                } else if self.y != self.y_end {
+                    self.next_row();
+                } else {
+                    self.seek(self.q + 1);
+                }
+                Some(head)
+            }
+        }
+
+        impl Iterator for Ellipse<$C> {
+            type Item = ($C, $C);
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.pop_head()
+            }
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let (lo, hi) = self.len_bounds();
+                let lo = match usize::try_from(lo) {
+                    Ok(lo) => lo,
+                    Err(_) => usize::MAX,
+                };
+                (lo, usize::try_from(hi).ok())
+            }
+        }
+
+        impl core::iter::FusedIterator for Ellipse<$C> {}
+    };
+}
+
+clone!([C: Coord] Ellipse<C>);
+
+ellipse!(u8 | i8, u64);
+ellipse!(u16 | i16, u64);
+ellipse!(u32 | i32, u128);
+ellipse!(u64 | i64, u256);
+#[cfg(target_pointer_width = "64")]
+ellipse!(usize | isize, u256);
+#[cfg(target_pointer_width = "32")]
+ellipse!(usize | isize, u128);
+#[cfg(target_pointer_width = "16")]
+ellipse!(usize | isize, u64);
@@ -0,0 +1,153 @@
+use crate::ellipse::Ellipse;
+use crate::macros::*;
+use crate::math::Coord;
+
+/// An iterator over the horizontal spans of a filled axis-aligned ellipse.
+///
+/// Yields `(y, x_start, x_end)`, where `x_start <= x_end` are both inside the span.
+/// Rows are yielded in increasing order of `y`, each at most once.
+///
+/// The boundary of the filled ellipse is the [`Ellipse`] with the same center and radii.
+///
+/// # Warning
+///
+/// Internally, this uses integers twice the size of the coordinate type,
+/// and products four times the size of the coordinate type to round the ellipse.
+/// Keep this in mind when using `u64`, `i64`, `usize` and `isize` coordinates.
+/// If you do not need the whole range, prefer smaller coordinate types.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct EllipseFill<C: Coord> {
+    pub(crate) xc: C::I2,
+    pub(crate) yc: C::I2,
+    pub(crate) a: C::U,
+    pub(crate) b: C::U,
+    pub(crate) r0: C::I2,
+    pub(crate) r1: C::I2,
+    pub(crate) c0: C::I2,
+    pub(crate) c1: C::I2,
+}
+
+macro_rules! ellipse_fill {
+    ($Cu:ty | $Ci:ty) => {
+        ellipse_fill!($Cu, <$Cu as Coord>::U, <$Cu as Coord>::U2, <$Cu as Coord>::I2);
+        ellipse_fill!($Ci, <$Ci as Coord>::U, <$Ci as Coord>::U2, <$Ci as Coord>::I2);
+    };
+    ($C:ty, $U:ty, $U2:ty, $I2:ty) => {
+        impl EllipseFill<$C> {
+            /// Returns an [`EllipseFill`] over the filled ellipse centered at `(xc, yc)`
+            /// with radii `a` along `X` and `b` along `Y`.
+            ///
+            /// Parts of the ellipse that do not fit into the coordinate type are skipped.
+            #[inline]
+            pub const fn new(xc: $C, yc: $C, a: $U, b: $U) -> Self {
+                let (xc, yc) = (xc as $I2, yc as $I2);
+                let (min, max) = (<$C>::MIN as $I2, <$C>::MAX as $I2);
+                let rows = (min - yc, max - yc);
+                let cols = (min - xc, max - xc);
+                Self::raw(xc, yc, a, b, rows, cols)
+            }
+
+            /// Returns an [`EllipseFill`] whose rows and columns relative to `(xc, yc)`
+            /// are restricted to the closed ranges `rows` and `cols`.
+            #[inline]
+            pub(crate) const fn raw(
+                xc: $I2,
+                yc: $I2,
+                a: $U,
+                b: $U,
+                rows: ($I2, $I2),
+                cols: ($I2, $I2),
+            ) -> Self {
+                let mut fill = Self { xc, yc, a, b, r0: 1, r1: 0, c0: cols.0, c1: cols.1 };
+                // The ellipse is symmetric about the center, so the rows reaching
+                // the column `need` are the ones at most as far as the last such row.
+                let need = if cols.0 < -cols.1 { -cols.1 } else { cols.0 };
+                if (a as $I2) < need {
+                    return fill;
+                }
+                let (mut lo, mut hi) = (0, b as $I2);
+                while lo < hi {
+                    let mid = hi - (hi - lo) / 2;
+                    if need <= fill.half_width(mid) { lo = mid } else { hi = mid - 1 }
+                }
+                fill.r0 = if rows.0 < -lo { -lo } else { rows.0 };
+                fill.r1 = if lo < rows.1 { lo } else { rows.1 };
+                fill
+            }
+
+            /// Returns the half-width of row `|t| <= b` relative to the center.
+            #[inline]
+            const fn half_width(&self, t: $I2) -> $I2 {
+                let t = if t < 0 { -t } else { t };
+                Ellipse::<$C>::half_width(self.a, self.b, t)
+            }
+
+            /// Returns `true` if the iterator is empty.
+            #[inline]
+            pub const fn is_empty(&self) -> bool {
+                self.r1 < self.r0
+            }
+
+            /// Returns the remaining length of this iterator.
+            #[inline]
+            pub const fn len(&self) -> $U2 {
+                if self.is_empty() {
+                    return 0;
+                }
+                (self.r1 - self.r0) as $U2 + 1
+            }
+
+            /// Returns the span at the start of the iterator.
+            /// This does not advance the iterator.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn head(&self) -> Option<($C, $C, $C)> {
+                if self.is_empty() {
+                    return None;
+                }
+                let w = self.half_width(self.r0);
+                let a = if -w < self.c0 { self.c0 } else { -w };
+                let b = if self.c1 < w { self.c1 } else { w };
+                let y = (self.yc + self.r0) as $C;
+                Some((y, (self.xc + a) as $C, (self.xc + b) as $C))
+            }
+
+            /// Consumes and returns the span at the start of the iterator.
+            /// This advances the iterator forwards.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn pop_head(&mut self) -> Option<($C, $C, $C)> {
+                let head = try_opt!(self.head());
+                self.r0 += 1;
+                Some(head)
+            }
+        }
+
+        impl Iterator for EllipseFill<$C> {
+            type Item = ($C, $C, $C);
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.pop_head()
+            }
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match usize::try_from(self.len()) {
+                    Ok(len) => (len, Some(len)),
+                    Err(_) => (usize::MAX, None),
+                }
+            }
+        }
+
+        impl core::iter::FusedIterator for EllipseFill<$C> {}
+    };
+}
+
+clone!([C: Coord] EllipseFill<C>);
+
+ellipse_fill!(u8 | i8);
+ellipse_fill!(u16 | i16);
+ellipse_fill!(u32 | i32);
+ellipse_fill!(u64 | i64);
+ellipse_fill!(usize | isize);
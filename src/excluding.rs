@@ -0,0 +1,168 @@
+use crate::clip::Viewport;
+use crate::line_a::LineA;
+use crate::line_b::LineB;
+use crate::line_d::LineD;
+use crate::macros::*;
+use crate::math::{Coord, ops};
+
+/// An iterator over the visible pieces of a line segment clipped to a region
+/// with rectangular holes.
+///
+/// Yields line iterators `L` over the maximal runs of consecutive points of the unclipped
+/// segment that lie inside the region and outside all holes, in order along the segment.
+/// Each piece is an exactly clipped run of the unclipped segment.
+///
+/// # Performance
+///
+/// Each piece takes time quadratic in the number of holes in the worst case.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Excluding<'a, C: Coord, L> {
+    pub(crate) holes: &'a [Viewport<C>],
+    /// The endpoints of the unclipped segment.
+    pub(crate) x0: C,
+    pub(crate) y0: C,
+    pub(crate) x1: C,
+    pub(crate) y1: C,
+    /// The segment clipped to the region, starting at index `k0` of the unclipped segment.
+    pub(crate) line: L,
+    pub(crate) k0: C::U,
+    /// The index of the first point after the current piece.
+    pub(crate) k: C::U,
+    pub(crate) piece: L,
+}
+
+macro_rules! excluding {
+    ($Cu:ty | $Ci:ty) => {
+        excluding!(LineB, line_b, $Cu, <$Cu as Coord>::U);
+        excluding!(LineB, line_b, $Ci, <$Ci as Coord>::U);
+        excluding!(LineD, line_d, $Cu, <$Cu as Coord>::U);
+        excluding!(LineD, line_d, $Ci, <$Ci as Coord>::U);
+        excluding!(LineA, line_a, $Cu, <$Cu as Coord>::U);
+        excluding!(LineA, line_a, $Ci, <$Ci as Coord>::U);
+    };
+    ($Line:ident, $line:ident, $C:ty, $U:ty) => {
+        impl<'a> Excluding<'a, $C, $Line<$C>> {
+            /// Returns an [`Excluding`] over the pieces of `line` outside the `holes`,
+            /// where `line` is a clipped run of the segment `(x0, y0) -> (x1, y1)`.
+            #[inline]
+            pub(crate) const fn raw(
+                line: $Line<$C>,
+                holes: &'a [Viewport<$C>],
+                x0: $C,
+                y0: $C,
+                x1: $C,
+                y1: $C,
+            ) -> Self {
+                let piece = line.clone();
+                let mut pieces = Self { holes, x0, y0, x1, y1, line, k0: 0, k: 0, piece };
+                if let Some((x, y)) = pieces.line.head() {
+                    pieces.k0 = pieces.index(x, y);
+                    pieces.k = pieces.k0;
+                }
+                pieces.seek();
+                pieces
+            }
+
+            /// Returns the index of the point `(x, y)` of the unclipped segment.
+            #[inline]
+            const fn index(&self, x: $C, y: $C) -> $U {
+                let (dx, _) = ops::<$C>::susub(x, self.x0);
+                let (dy, _) = ops::<$C>::susub(y, self.y0);
+                if dx < dy { dy } else { dx }
+            }
+
+            /// Returns the half-open range of indices of the unclipped segment inside hole `i`.
+            #[inline]
+            const fn hole(&self, i: usize) -> Option<($U, $U)> {
+                let (x0, y0, x1, y1) = (self.x0, self.y0, self.x1, self.y1);
+                let run = try_opt!(self.holes[i].$line(x0, y0, x1, y1));
+                let (x, y) = try_opt!(run.head());
+                let a = self.index(x, y);
+                Some((a, a + run.len()))
+            }
+
+            /// Moves to the next piece starting at index `k` or later, if any.
+            #[inline]
+            const fn seek(&mut self) {
+                let end = self.k0 + self.line.len();
+                let mut moved = true;
+                while moved {
+                    moved = false;
+                    let mut i = 0;
+                    while i < self.holes.len() {
+                        if let Some((a, b)) = self.hole(i) {
+                            if a <= self.k && self.k < b {
+                                self.k = b;
+                                moved = true;
+                            }
+                        }
+                        i += 1;
+                    }
+                }
+                let start = if end < self.k { end } else { self.k };
+                let mut stop = end;
+                let mut i = 0;
+                while i < self.holes.len() {
+                    if let Some((a, _)) = self.hole(i) {
+                        if start < a && a < stop {
+                            stop = a;
+                        }
+                    }
+                    i += 1;
+                }
+                let (_, rest) = self.line.clone().split_at(start - self.k0);
+                let (piece, _) = rest.split_at(stop - start);
+                (self.piece, self.k) = (piece, stop);
+            }
+
+            /// Returns `true` if the iterator is empty.
+            #[inline]
+            pub const fn is_empty(&self) -> bool {
+                self.piece.is_empty()
+            }
+
+            /// Returns the piece at the start of the iterator.
+            /// This does not advance the iterator.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn head(&self) -> Option<$Line<$C>> {
+                if self.is_empty() {
+                    return None;
+                }
+                Some(self.piece.clone())
+            }
+
+            /// Consumes and returns the piece at the start of the iterator.
+            /// This advances the iterator forwards.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn pop_head(&mut self) -> Option<$Line<$C>> {
+                let head = try_opt!(self.head());
+                self.seek();
+                Some(head)
+            }
+        }
+
+        impl Iterator for Excluding<'_, $C, $Line<$C>> {
+            type Item = $Line<$C>;
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.pop_head()
+            }
+        }
+
+        impl core::iter::FusedIterator for Excluding<'_, $C, $Line<$C>> {}
+    };
+}
+
+clone!(['a, C: Coord] Excluding<'a, C, LineA<C>>, fields = [line, piece]);
+clone!(['a, C: Coord] Excluding<'a, C, LineB<C>>, fields = [line, piece]);
+clone!(['a, C: Coord] Excluding<'a, C, LineD<C>>, fields = [line, piece]);
+
+excluding!(u8 | i8);
+excluding!(u16 | i16);
+excluding!(u32 | i32);
+excluding!(u64 | i64);
+excluding!(usize | isize);
@@ -7,11 +7,36 @@
 //! - **Axis-aligned**: [`LineA`] (dynamic axis), [`LineAu`] (static: [`LineAx`] or [`LineAy`]).
 //! - **Diagonal**: [`LineD`] and [`LineD2`] (fast double-ended iteration).
 //! - [**Bresenham**][bres]: [`LineB`], [`LineBu`] ("slow" [`LineBx`] and "fast" [`LineBy`]).
+//! - **Sub-pixel Bresenham**: [`LineBq`] (pixel centers along segments between fixed-point positions).
+//! - [**Xiaolin Wu**][wu] (anti-aliased): [`LineW`], [`LineWu`] ([`LineWx`] and [`LineWy`]).
+//! - **Thick**: [`LineThick`] (horizontal spans of a stroke with a [`Cap`]).
+//! - **Supercover**: [`LineS`] (all touched cells), [`LineS4`] (4-connected), [`LineSu`].
+//! - **3D Bresenham**: [`LineB3`] (clipped to a [`Clip3`] or [`Viewport3`] box).
+//! - [**Amanatides–Woo**][aw] (grid traversal): [`LineR`] (rays between fixed-point positions).
+//! - **Polyline**: [`Polyline`] (connected [`LineB`] segments, each shared vertex yielded once).
+//! - **Floating-point**: `LineF` (pixel centers along `f64` segments, with the `float` feature).
+//!
+//! It also provides a [**midpoint**][mid] [`Circle`] iterator over circles and octant-bounded arcs,
+//! a [`Disc`] iterator over the horizontal spans of filled discs, and their elliptical
+//! counterparts: [`Ellipse`] over axis-aligned ellipses and quadrant-bounded arcs,
+//! and [`EllipseFill`]. Polygons made of [`LineB`] edges are filled by [`PolygonFill`],
+//! and triangles by [`Triangle`], which also yields barycentric weights for interpolation.
+//! Axis-aligned rectangles are covered by [`Rect`] (outline) and [`RectFill`] (spans).
+//! Quadratic and cubic Bézier curves are traced by [`QuadBezier`] and [`CubicBezier`]
+//! as 8-connected paths, which join without repeating the shared endpoint.
 //!
 //! Line segments can be [clipped](Clip::<i8>::line_b) to one of the two kinds of a closed
 //! rectangular region: a [`Clip`] with a zero origin, or a [`Viewport`] with an arbitrary origin.
 //! Additionally, segments can be clipped and [projected](Clip::<i8>::line_b_proj) to the region,
 //! yielding local coordinates. This is used for indexing into a grid backed by the clipping region.
+//! Segments can also be clipped to a [`Convex`] polygon, yielding the exact runs of points inside.
+//! Rectangular holes can be [excluded](Clip::<i8>::line_b_excluding) from a region, yielding
+//! the visible pieces of a segment, e.g. to skip the points under opaque panels.
+//! Nested regions are built with [`Viewport::intersect`], [`Viewport::translate`],
+//! [`Viewport::expand`] and [`Viewport::shrink`], which never overflow.
+//! Code generic over the algorithm takes a [`RasterLine`], which [`Clip::clip_line`]
+//! and [`Viewport::clip_line`] construct for [`LineA`], [`LineB`] and [`LineD`] alike,
+//! and code generic over the coordinate type takes a [`Coord`].
 //!
 //! ## Features
 //!
@@ -137,6 +162,9 @@
 //!
 //! [clip]: https://en.wikipedia.org/wiki/Line_clipping
 //! [bres]: https://en.wikipedia.org/wiki/Bresenham%27s_line_algorithm
+//! [wu]: https://en.wikipedia.org/wiki/Xiaolin_Wu%27s_line_algorithm
+//! [aw]: http://www.cse.yorku.ca/~amana/research/grid.pdf
+//! [mid]: https://en.wikipedia.org/wiki/Midpoint_circle_algorithm
 //! [spy]: https://doi.org/10.1016/0097-8493(87)90061-6
 //! [dorr]: https://doi.org/10.1016/0097-8493(90)90067-8
 //! [kuzmin]: https://doi.org/10.1111/1467-8659.1450275
@@ -150,14 +178,59 @@
 )]
 #![deny(missing_docs)]
 
+mod bezier;
+mod circle;
 mod clip;
+mod clip3;
+mod convex;
+mod disc;
+mod ellipse;
+mod ellipse_fill;
+mod excluding;
 mod line_a;
 mod line_b;
+mod line_b3;
+mod line_bq;
 mod line_d;
+#[cfg(feature = "float")]
+mod line_f;
+mod line_r;
+mod line_s;
+mod line_thick;
+mod line_w;
 mod macros;
 mod math;
+mod polygon_fill;
+mod polyline;
+mod raster_line;
+mod rect;
+mod rect_fill;
+mod triangle;
 
-pub use clip::{Clip, Viewport};
+pub use bezier::{Bezier, CubicBezier, QuadBezier};
+pub use circle::Circle;
+pub use clip::{Clip, Viewport, Visibility};
+pub use clip3::{Clip3, Viewport3};
+pub use convex::{Convex, ConvexLineB};
+pub use disc::Disc;
+pub use ellipse::Ellipse;
+pub use ellipse_fill::EllipseFill;
+pub use excluding::Excluding;
 pub use line_a::{LineA, LineAu, LineAx, LineAy};
 pub use line_b::{LineB, LineBu, LineBx, LineBy};
+pub use line_b3::LineB3;
+pub use line_bq::LineBq;
 pub use line_d::{LineD, LineD2};
+#[cfg(feature = "float")]
+pub use line_f::LineF;
+pub use line_r::LineR;
+pub use line_s::{LineS, LineS4, LineSu};
+pub use line_thick::{Cap, LineThick};
+pub use line_w::{LineW, LineWu, LineWx, LineWy};
+pub use math::{Coord, Num};
+pub use polygon_fill::{FillRule, PolygonFill};
+pub use polyline::Polyline;
+pub use raster_line::RasterLine;
+pub use rect::Rect;
+pub use rect_fill::RectFill;
+pub use triangle::Triangle;
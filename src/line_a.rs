@@ -106,12 +106,17 @@ macro_rules! line_au {
                     let (xt, yt) = try_opt!(self.tail());
                     self.u1 = if YX { yt } else { xt };
                     Some((xt, yt))
+                },
+                fn advance(n) = {
+                    self.u0 = ops::<$C>::wadd_su(self.u0, n, self.su);
+                },
+                fn truncate(m) = {
+                    self.u1 = ops::<$C>::wadd_su(self.u0, m, self.su);
                 }
             );
         }
 
-        iter_fwd!(LineAu<const YX, $C>$(, exact = [$($ptr_size),+])?);
-        iter_rev!(LineAu<const YX, $C>);
+        iter_line!(LineAu<const YX: bool, $C>$(, exact = [$($ptr_size),+])?);
     };
 }
 
@@ -200,26 +205,30 @@ macro_rules! line_a {
                 fn pop_tail = match self {
                     Self::Ax(line) => line.pop_tail(),
                     Self::Ay(line) => line.pop_tail(),
+                },
+                fn advance(n) = match self {
+                    Self::Ax(line) => line.advance_unchecked(n),
+                    Self::Ay(line) => line.advance_unchecked(n),
+                },
+                fn truncate(m) = match self {
+                    Self::Ax(line) => line.truncate_unchecked(m),
+                    Self::Ay(line) => line.truncate_unchecked(m),
                 }
             );
         }
 
-        iter_fwd!(
+        iter_line!(
             LineA<$C>,
-            fn fold(self, accum, f) = match self {
-                Self::Ax(line) => line.fold(accum, f),
-                Self::Ay(line) => line.fold(accum, f),
+            fn fold(line, accum, f) = match line {
+                LineA::Ax(line) => line.fold(accum, f),
+                LineA::Ay(line) => line.fold(accum, f),
+            },
+            fn rfold(line, accum, f) = match line {
+                LineA::Ax(line) => line.rfold(accum, f),
+                LineA::Ay(line) => line.rfold(accum, f),
             }$(,
             exact = [$($ptr_size),+])?
         );
-
-        iter_rev!(
-            LineA<$C>,
-            fn rfold(self, accum, f) = match self {
-                Self::Ax(line) => line.rfold(accum, f),
-                Self::Ay(line) => line.rfold(accum, f),
-            }
-        );
     };
 }
 
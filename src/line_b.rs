@@ -125,14 +125,52 @@ macro_rules! line_bu {
                     self.u0 = ops::<$C>::wadd_i(self.u0, self.su as $I);
                     self.err += self.dv as $I2;
                     Some((x0, y0))
+                },
+                fn tail = {
+                    if self.is_empty() {
+                        return None;
+                    }
+                    let ut = ops::<$C>::wsub_i(self.u1, self.su as $I);
+                    let (vt, _) = self.v_err_at(self.len() - 1);
+                    let (xt, yt) = if YX { (vt, ut) } else { (ut, vt) };
+                    Some((xt, yt))
+                },
+                fn pop_tail = {
+                    let (xt, yt) = try_opt!(self.tail());
+                    self.u1 = if YX { yt } else { xt };
+                    Some((xt, yt))
+                },
+                fn advance(n) = {
+                    (self.v0, self.err) = self.v_err_at(n);
+                    self.u0 = ops::<$C>::wadd_su(self.u0, n, self.su);
+                },
+                fn truncate(m) = {
+                    self.u1 = ops::<$C>::wadd_su(self.u0, m, self.su);
                 }
             );
+
+            /// Returns the minor coordinate and the error term after `n` steps.
+            ///
+            /// The error term at the head always lies in `[dv - du, dv)`,
+            /// which lets us compute the number of minor steps taken
+            /// in the first `n` steps with a single division.
+            #[inline]
+            const fn v_err_at(&self, n: $U) -> ($C, $I2) {
+                if self.dv == 0 {
+                    return (self.v0, self.err);
+                }
+                let (du, dv) = (self.du as $U2, self.dv as $U2);
+                let base = (self.err - self.dv as $I2 + self.du as $I2) as $U2;
+                let num = base + n as $U2 * dv;
+                let (dvn, rem) = (num / du, num % du);
+                debug_assert!(dvn <= <$U>::MAX as $U2);
+                let v = ops::<$C>::wadd_su(self.v0, dvn as $U, self.sv);
+                let err = rem as $I2 - du as $I2 + dv as $I2;
+                (v, err)
+            }
         }
 
-        iter_fwd!(
-            LineBu<const YX, $C>$(,
-            exact = [$($ptr_size),+])?
-        );
+        iter_line!(LineBu<const YX: bool, $C>$(, exact = [$($ptr_size),+])?);
     };
 }
 
@@ -229,15 +267,35 @@ macro_rules! line_b {
                 fn pop_head = match self {
                     Self::Bx(line) => line.pop_head(),
                     Self::By(line) => line.pop_head(),
+                },
+                fn tail = match self {
+                    Self::Bx(line) => line.tail(),
+                    Self::By(line) => line.tail(),
+                },
+                fn pop_tail = match self {
+                    Self::Bx(line) => line.pop_tail(),
+                    Self::By(line) => line.pop_tail(),
+                },
+                fn advance(n) = match self {
+                    Self::Bx(line) => line.advance_unchecked(n),
+                    Self::By(line) => line.advance_unchecked(n),
+                },
+                fn truncate(m) = match self {
+                    Self::Bx(line) => line.truncate_unchecked(m),
+                    Self::By(line) => line.truncate_unchecked(m),
                 }
             );
         }
 
-        iter_fwd!(
+        iter_line!(
             LineB<$C>,
-            fn fold(self, accum, f) = match self {
-                Self::Bx(line) => line.fold(accum, f),
-                Self::By(line) => line.fold(accum, f),
+            fn fold(line, accum, f) = match line {
+                LineB::Bx(line) => line.fold(accum, f),
+                LineB::By(line) => line.fold(accum, f),
+            },
+            fn rfold(line, accum, f) = match line {
+                LineB::Bx(line) => line.rfold(accum, f),
+                LineB::By(line) => line.rfold(accum, f),
             }$(,
             exact = [$($ptr_size),+])?
         );
@@ -0,0 +1,173 @@
+use crate::macros::*;
+use crate::math::{Coord, ops};
+
+/// An iterator over the rasterized points of a directed, half-open line segment
+/// in 3D space. B stands for Bresenham.
+///
+/// The major axis `U` is the one with the largest extent, preferring `X` over `Y` over `Z`.
+/// Each minor axis `V` and `W` is stepped with its own error accumulator,
+/// the same way as the minor axis of [`LineB`](crate::LineB), so the projection
+/// of this segment onto the plane of `U` and either minor axis matches
+/// the corresponding 2D segment.
+///
+/// # Warning
+///
+/// Internally, this uses error accumulators twice the size of the coordinate type.
+/// Keep this in mind when using `u64`, `i64`, `usize` and `isize` coordinates.
+/// If you do not need the whole range, prefer smaller coordinate types.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct LineB3<C: Coord> {
+    pub(crate) u0: C,
+    pub(crate) v0: C,
+    pub(crate) w0: C,
+    pub(crate) du: C::U,
+    pub(crate) dv: C::U,
+    pub(crate) dw: C::U,
+    pub(crate) ev: C::I2,
+    pub(crate) ew: C::I2,
+    pub(crate) u1: C,
+    pub(crate) su: i8,
+    pub(crate) sv: i8,
+    pub(crate) sw: i8,
+    /// The major axis: `0` for `X` (`V = Y`, `W = Z`),
+    /// `1` for `Y` (`V = X`, `W = Z`), `2` for `Z` (`V = X`, `W = Y`).
+    pub(crate) axis: u8,
+}
+
+macro_rules! line_b3 {
+    (
+        $Cu:ty|$Ci:ty$(,
+        exact = [$($ptr_size:literal),+])?
+    ) => {
+        line_b3!(
+            $Cu,
+            <$Cu as Coord>::U,
+            <$Cu as Coord>::I,
+            <$Cu as Coord>::I2$(,
+            exact = [$($ptr_size),+])?
+        );
+        line_b3!(
+            $Ci,
+            <$Ci as Coord>::U,
+            <$Ci as Coord>::I,
+            <$Ci as Coord>::I2$(,
+            exact = [$($ptr_size),+])?
+        );
+    };
+    (
+        $C:ty,
+        $U:ty,
+        $I:ty,
+        $I2:ty$(,
+        exact = [$($ptr_size:literal),+])?
+    ) => {
+        impl LineB3<$C> {
+            /// Returns a [`LineB3`] over a directed, half-open line segment
+            /// `(x0, y0, z0) -> (x1, y1, z1)`.
+            #[inline]
+            pub const fn new(x0: $C, y0: $C, z0: $C, x1: $C, y1: $C, z1: $C) -> Self {
+                let (dx, sx) = ops::<$C>::susub(x1, x0);
+                let (dy, sy) = ops::<$C>::susub(y1, y0);
+                let (dz, sz) = ops::<$C>::susub(z1, z0);
+                let axis = Self::axis(dx, dy, dz);
+                let ((u0, u1, du, su), (v0, dv, sv), (w0, dw, sw)) = match axis {
+                    0 => ((x0, x1, dx, sx), (y0, dy, sy), (z0, dz, sz)),
+                    1 => ((y0, y1, dy, sy), (x0, dx, sx), (z0, dz, sz)),
+                    _ => ((z0, z1, dz, sz), (x0, dx, sx), (y0, dy, sy)),
+                };
+                let ev = dv as $I2 - du.div_ceil(2) as $I2;
+                let ew = dw as $I2 - du.div_ceil(2) as $I2;
+                Self { u0, v0, w0, du, dv, dw, ev, ew, u1, su, sv, sw, axis }
+            }
+
+            /// Returns the major axis for the given extents.
+            #[inline]
+            pub(crate) const fn axis(dx: $U, dy: $U, dz: $U) -> u8 {
+                if dy <= dx && dz <= dx {
+                    0
+                } else if dz <= dy {
+                    1
+                } else {
+                    2
+                }
+            }
+
+            /// Returns `true` if the iterator is empty.
+            #[inline]
+            pub const fn is_empty(&self) -> bool {
+                self.u0 == self.u1
+            }
+
+            /// Returns the remaining length of this iterator.
+            #[inline]
+            pub const fn len(&self) -> $U {
+                ops::<$C>::wusub_s(self.u1, self.u0, self.su)
+            }
+
+            /// Returns the point at the start of the iterator.
+            /// This does not advance the iterator.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn head(&self) -> Option<($C, $C, $C)> {
+                if self.is_empty() {
+                    return None;
+                }
+                let (u, v, w) = (self.u0, self.v0, self.w0);
+                Some(match self.axis {
+                    0 => (u, v, w),
+                    1 => (v, u, w),
+                    _ => (v, w, u),
+                })
+            }
+
+            /// Consumes and returns the point at the start of the iterator.
+            /// This advances the iterator forwards.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn pop_head(&mut self) -> Option<($C, $C, $C)> {
+                let head = try_opt!(self.head());
+                if 0 <= self.ev {
+                    self.v0 = ops::<$C>::wadd_i(self.v0, self.sv as $I);
+                    self.ev -= self.du as $I2;
+                }
+                if 0 <= self.ew {
+                    self.w0 = ops::<$C>::wadd_i(self.w0, self.sw as $I);
+                    self.ew -= self.du as $I2;
+                }
+                self.u0 = ops::<$C>::wadd_i(self.u0, self.su as $I);
+                self.ev += self.dv as $I2;
+                self.ew += self.dw as $I2;
+                Some(head)
+            }
+        }
+
+        impl Iterator for LineB3<$C> {
+            type Item = ($C, $C, $C);
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.pop_head()
+            }
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match usize::try_from(self.len()) {
+                    Ok(len) => (len, Some(len)),
+                    Err(_) => (usize::MAX, None),
+                }
+            }
+        }
+
+        $(#[cfg(any($(target_pointer_width = $ptr_size),+))]
+        impl ExactSizeIterator for LineB3<$C> {})?
+        impl core::iter::FusedIterator for LineB3<$C> {}
+    };
+}
+
+clone!([C: Coord] LineB3<C>);
+
+line_b3!(u8 | i8, exact = ["16", "32", "64"]);
+line_b3!(u16 | i16, exact = ["16", "32", "64"]);
+line_b3!(u32 | i32, exact = ["32", "64"]);
+line_b3!(u64 | i64, exact = ["64"]);
+line_b3!(usize | isize, exact = ["16", "32", "64"]);
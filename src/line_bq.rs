@@ -0,0 +1,249 @@
+use crate::macros::*;
+use crate::math::{Coord, ops};
+
+/// An iterator over the rasterized points of a directed, half-open line segment
+/// between two fixed-point positions. Q stands for the Q notation of fixed-point numbers.
+///
+/// Endpoints are fixed-point numbers with `F` fractional bits: the position `p`
+/// stands for `p / 2^F`, and points are pixel centers at integer positions.
+/// Along the major axis `U`, which is `X` unless the segment is longer along `Y`,
+/// this yields one point for each pixel center `u` in the half-open range from `u0` to `u1`,
+/// that is `u0 <= u < u1` if the segment goes forward, or `u1 < u <= u0` otherwise.
+/// The other coordinate is the exact position of the segment at `u`, rounded to the nearest
+/// integer, with ties rounding towards `v1`. The error term of the Bresenham algorithm
+/// starts from the sub-pixel offset of the first point, so moving an endpoint by a fraction
+/// of a pixel moves the rasterized segment smoothly.
+///
+/// Points are yielded in pixel coordinates. With `F = 0`, this yields the same points
+/// as [`LineB`](crate::LineB), and for other `F`, the same points as `LineF` does
+/// for the same positions as floating-point numbers.
+///
+/// # Warning
+///
+/// Internally, this uses integers twice the size of the coordinate type.
+/// Keep this in mind when using `u64`, `i64`, `usize` and `isize` coordinates.
+/// If you do not need the whole range, prefer smaller coordinate types.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct LineBq<const F: u32, C: Coord> {
+    pub(crate) x: C,
+    pub(crate) y: C,
+    /// The error term, which is less than `lim`, and its increment per step along `U`.
+    pub(crate) err: C::U2,
+    pub(crate) lim: C::U2,
+    pub(crate) inc: C::U2,
+    pub(crate) len: C::U,
+    pub(crate) sx: i8,
+    pub(crate) sy: i8,
+    /// Whether `Y` is the major axis.
+    pub(crate) yx: bool,
+}
+
+macro_rules! line_bq {
+    ($Cu:ty | $Ci:ty$(, exact = [$($ptr_size:literal),+])?) => {
+        line_bq!(
+            $Cu,
+            <$Cu as Coord>::U,
+            <$Cu as Coord>::I,
+            <$Cu as Coord>::U2,
+            <$Cu as Coord>::I2$(,
+            exact = [$($ptr_size),+])?
+        );
+        line_bq!(
+            $Ci,
+            <$Ci as Coord>::U,
+            <$Ci as Coord>::I,
+            <$Ci as Coord>::U2,
+            <$Ci as Coord>::I2$(,
+            exact = [$($ptr_size),+])?
+        );
+    };
+    ($C:ty, $U:ty, $I:ty, $U2:ty, $I2:ty$(, exact = [$($ptr_size:literal),+])?) => {
+        impl<const F: u32> LineBq<F, $C> {
+            const ONE: $I2 = {
+                assert!(F < <$C>::BITS, "too many fractional bits");
+                1 << F
+            };
+
+            /// Returns a [`LineBq`] over the directed, half-open line segment
+            /// `(x0, y0) -> (x1, y1)` between fixed-point positions.
+            ///
+            /// # Panics
+            ///
+            /// At compile time, if `F` is not less than the number of bits in the coordinate type.
+            #[inline]
+            pub const fn new(x0: $C, y0: $C, x1: $C, y1: $C) -> Self {
+                let (min, max) = (<$C>::MIN as $I2, <$C>::MAX as $I2);
+                Self::raw(x0, y0, x1, y1, (min, max), (min, max))
+            }
+
+            /// Returns a [`LineBq`] over the points of the segment
+            /// inside the closed ranges `rows` and `cols` of pixels.
+            #[inline]
+            pub(crate) const fn raw(
+                x0: $C,
+                y0: $C,
+                x1: $C,
+                y1: $C,
+                rows: ($I2, $I2),
+                cols: ($I2, $I2),
+            ) -> Self {
+                let (x0, y0, x1, y1) = (x0 as $I2, y0 as $I2, x1 as $I2, y1 as $I2);
+                let yx = (x1 - x0).unsigned_abs() < (y1 - y0).unsigned_abs();
+                let (u0, v0, u1, v1, (c0, c1), (r0, r1)) =
+                    if yx { (y0, x0, y1, x1, rows, cols) } else { (x0, y0, x1, y1, cols, rows) };
+                let (su, sv): (i8, i8) =
+                    (if u1 < u0 { -1 } else { 1 }, if v1 < v0 { -1 } else { 1 });
+                let (du, dv) = ((u1 - u0).unsigned_abs(), (v1 - v0).unsigned_abs());
+                let (lim, inc) = (du << (F + 1), dv << (F + 1));
+                let (sx, sy) = if yx { (sv, su) } else { (su, sv) };
+                let mut line = Self { x: 0, y: 0, err: 0, lim, inc, len: 0, sx, sy, yx };
+                // The first and the last pixel center along `U`, in the direction of `U`.
+                let (a, b) =
+                    if 0 < su { (-(-u0 >> F), -(-u1 >> F) - 1) } else { (u0 >> F, (u1 >> F) + 1) };
+                let (a, b) = if 0 < su {
+                    (if a < c0 { c0 } else { a }, if c1 < b { c1 } else { b })
+                } else {
+                    (if c1 < a { c1 } else { a }, if b < c0 { c0 } else { b })
+                };
+                if du == 0 || 0 < su && b < a || su < 0 && a < b {
+                    return line;
+                }
+                let n = (if 0 < su { b - a } else { a - b }) as $U2 + 1;
+                // Rows are mirrored if `V` goes backward, so that they increase along the segment.
+                let v0 = if 0 < sv { v0 } else { -v0 };
+                let (r0, r1) = if 0 < sv { (r0, r1) } else { (-r1, -r0) };
+                let (mut lo, mut hi) = (0, n);
+                while lo < hi {
+                    let mid = lo + (hi - lo) / 2;
+                    let (v, _) = Self::row_at(Self::at(a, su, mid), u0, su, v0, du, dv);
+                    if v < r0 { lo = mid + 1 } else { hi = mid }
+                }
+                let start = lo;
+                let mut hi = n;
+                while lo < hi {
+                    let mid = lo + (hi - lo) / 2;
+                    let (v, _) = Self::row_at(Self::at(a, su, mid), u0, su, v0, du, dv);
+                    if r1 < v { hi = mid } else { lo = mid + 1 }
+                }
+                if lo == start {
+                    return line;
+                }
+                let u = Self::at(a, su, start);
+                let (v, err) = Self::row_at(u, u0, su, v0, du, dv);
+                let v = if 0 < sv { v } else { -v };
+                (line.x, line.y) = if yx { (v as $C, u as $C) } else { (u as $C, v as $C) };
+                (line.err, line.len) = (err, (lo - start) as $U);
+                line
+            }
+
+            /// Returns the pixel center `i` steps after `a` in the direction `su`.
+            #[inline]
+            const fn at(a: $I2, su: i8, i: $U2) -> $I2 {
+                if 0 < su { a + i as $I2 } else { a - i as $I2 }
+            }
+
+            /// Returns the row and the error term at the pixel center `u`
+            /// of the segment from `u0` in the direction `su` with the slope `dv / du`,
+            /// where the rows and `v0` increase along the segment.
+            ///
+            /// The row is the floor of `h = (2 * v(u) + 2^F) / 2^(F + 1)`, where `v(u)` is
+            /// the exact position at `u`, and the error term is the fractional part
+            /// of `h` scaled by `lim = du * 2^(F + 1)`.
+            #[inline]
+            const fn row_at(u: $I2, u0: $I2, su: i8, v0: $I2, du: $U2, dv: $U2) -> ($I2, $U2) {
+                let t = (if 0 < su { (u << F) - u0 } else { u0 - (u << F) }) as $U2;
+                let (q, r) = (t * dv / du, t * dv % du);
+                let h = 2 * (v0 + q as $I2) + Self::ONE;
+                let row = h >> (F + 1);
+                let hr = (h - (row << (F + 1))) as $U2;
+                let (x, lim) = (hr * du, du << (F + 1));
+                if lim - 2 * r <= x { (row + 1, x - (lim - 2 * r)) } else { (row, x + 2 * r) }
+            }
+
+            iter_methods!(
+                C = $C,
+                U = $U,
+                self = self,
+                fn is_empty = self.len == 0,
+                fn len = self.len,
+                fn head = {
+                    if self.is_empty() {
+                        return None;
+                    }
+                    Some((self.x, self.y))
+                },
+                fn pop_head = {
+                    let head = try_opt!(self.head());
+                    self.len -= 1;
+                    let step = self.lim - self.inc;
+                    let minor = step <= self.err;
+                    self.err = if minor { self.err - step } else { self.err + self.inc };
+                    if self.yx {
+                        self.y = ops::<$C>::wadd_i(self.y, self.sy as $I);
+                        if minor {
+                            self.x = ops::<$C>::wadd_i(self.x, self.sx as $I);
+                        }
+                    } else {
+                        self.x = ops::<$C>::wadd_i(self.x, self.sx as $I);
+                        if minor {
+                            self.y = ops::<$C>::wadd_i(self.y, self.sy as $I);
+                        }
+                    }
+                    Some(head)
+                },
+                fn tail = {
+                    if self.is_empty() {
+                        return None;
+                    }
+                    let mut line = self.clone();
+                    line.advance_unchecked(self.len - 1);
+                    line.head()
+                },
+                fn pop_tail = {
+                    let tail = try_opt!(self.tail());
+                    self.len -= 1;
+                    Some(tail)
+                },
+                fn advance(n) = {
+                    let (dv, err) = self.minor_err_at(n);
+                    (self.err, self.len) = (err, self.len - n);
+                    if self.yx {
+                        self.y = ops::<$C>::wadd_su(self.y, n, self.sy);
+                        self.x = ops::<$C>::wadd_su(self.x, dv, self.sx);
+                    } else {
+                        self.x = ops::<$C>::wadd_su(self.x, n, self.sx);
+                        self.y = ops::<$C>::wadd_su(self.y, dv, self.sy);
+                    }
+                },
+                fn truncate(m) = {
+                    self.len = m;
+                }
+            );
+
+            /// Returns the number of minor steps and the error term after `n` steps.
+            ///
+            /// Like [`Self::row_at`], this splits `n * inc` into whole multiples of `lim`
+            /// and a remainder, so that the error term does not overflow.
+            #[inline]
+            const fn minor_err_at(&self, n: $U) -> ($U, $U2) {
+                if n == 0 {
+                    return (0, self.err);
+                }
+                let (du, dv) = (self.lim >> (F + 1), self.inc >> (F + 1));
+                let t = n as $U2 * dv;
+                let (q, r) = ((t / du) as $U, (t % du) << (F + 1));
+                if self.lim - r <= self.err { (q + 1, self.err - (self.lim - r)) } else { (q, self.err + r) }
+            }
+        }
+
+        iter_line!(LineBq<const F: u32, $C>$(, exact = [$($ptr_size),+])?);
+    };
+}
+
+clone!([const F: u32, C: Coord] LineBq<F, C>);
+
+line_bq!(u8 | i8);
+line_bq!(u16 | i16, exact = ["16", "32", "64"]);
+line_bq!(u32 | i32, exact = ["32", "64"]);
+line_bq!(u64 | i64, exact = ["64"]);
+line_bq!(usize | isize);
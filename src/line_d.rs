@@ -90,12 +90,18 @@ macro_rules! line_d {
                     let (xt, yt) = try_opt!(self.tail());
                     self.x1 = xt;
                     Some((xt, yt))
+                },
+                fn advance(n) = {
+                    self.x0 = ops::<$C>::wadd_su(self.x0, n, self.sx);
+                    self.y0 = ops::<$C>::wadd_su(self.y0, n, self.sy);
+                },
+                fn truncate(m) = {
+                    self.x1 = ops::<$C>::wadd_su(self.x0, m, self.sx);
                 }
             );
         }
 
-        iter_fwd!(LineD<$C>$(, exact = [$($ptr_size),+])?);
-        iter_rev!(LineD<$C>);
+        iter_line!(LineD<$C>$(, exact = [$($ptr_size),+])?);
     };
 }
 
@@ -196,12 +202,19 @@ macro_rules! line_d2 {
                     self.x1 = xt;
                     self.y1 = yt;
                     Some((xt, yt))
+                },
+                fn advance(n) = {
+                    self.x0 = ops::<$C>::wadd_su(self.x0, n, self.sx);
+                    self.y0 = ops::<$C>::wadd_su(self.y0, n, self.sy);
+                },
+                fn truncate(m) = {
+                    self.x1 = ops::<$C>::wadd_su(self.x0, m, self.sx);
+                    self.y1 = ops::<$C>::wadd_su(self.y0, m, self.sy);
                 }
             );
         }
 
-        iter_fwd!(LineD2<$C>$(, exact = [$($ptr_size),+])?);
-        iter_rev!(LineD2<$C>);
+        iter_line!(LineD2<$C>$(, exact = [$($ptr_size),+])?);
     };
 }
 
@@ -0,0 +1,458 @@
+use crate::macros::*;
+use crate::math::Coord;
+
+/// An iterator over the rasterized points of a directed, half-open line segment
+/// between two floating-point positions. F stands for float.
+///
+/// Points have integer coordinates at the pixel centers. Along the major axis `U`,
+/// which is `X` unless the segment is longer along `Y`, this yields one point for each
+/// pixel center `u` in the half-open range from `u0` to `u1`, that is `u0 <= u < u1`
+/// if the segment goes forward, or `u1 < u <= u0` otherwise. The other coordinate is
+/// the position of the segment at `u`, rounded to the nearest integer, with ties rounding
+/// towards `v1`. It is computed as `v0 + (u - u0) * (v1 - v0) / (u1 - u0)` with `f64`
+/// arithmetic, except near ties, where it is computed exactly if the endpoints become
+/// integers of at most 61 bits when scaled by a common power of two of at most `2^60`.
+/// This covers all integer and fixed-point endpoints within the coordinate type,
+/// so for integer endpoints, this yields the same points as [`LineB`](crate::LineB).
+///
+/// Endpoints far outside the coordinate type are fine: first, the segment is cut
+/// to the square `|x|, |y| <= 2^33`, which lies beyond all supported coordinate types,
+/// moving the endpoints outside of it along the segment. Then, it is clipped to the domain
+/// or to the clipping region before any position is converted to an integer.
+/// The cut endpoints are computed from exact products, so even for a segment from far
+/// beyond the square, they are off its original endpoints' line by only a rounding error
+/// relative to the square.
+///
+/// # Warning
+///
+/// This is only available with the `float` feature,
+/// and for coordinate types of up to 32 bits, which `f64` represents exactly.
+#[derive(Clone, PartialEq, Debug)]
+pub struct LineF<C: Coord> {
+    /// The start and the direction of the segment, with `U` as the first axis.
+    pub(crate) u0: f64,
+    pub(crate) v0: f64,
+    pub(crate) du: f64,
+    pub(crate) dv: f64,
+    /// Whether `Y` is the major axis.
+    pub(crate) yx: bool,
+    /// The current pixel center along `U`.
+    pub(crate) u: C::I2,
+    pub(crate) su: i8,
+    pub(crate) len: C::U2,
+    /// The origin subtracted from the yielded points.
+    pub(crate) origin: (C::I2, C::I2),
+    /// The endpoints as integers, if they are exact as such.
+    pub(crate) exact: Option<Exact>,
+}
+
+/// The endpoints of a segment scaled by `2^s` to integers, with `U` as the first axis
+/// and `0 < du`, for computing its minor coordinate exactly.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) struct Exact {
+    s: u32,
+    u0: i128,
+    v0: i128,
+    du: i128,
+    dv: i128,
+    /// Whether `v1 < v0`, so that ties round down.
+    down: bool,
+}
+
+impl Exact {
+    /// Returns the endpoints scaled by the smallest power of two `2^s` with `1 <= s <= 60`
+    /// that makes them integers, or [`None`] if there is none, if any of them
+    /// does not fit into 61 bits then, or if the segment is parallel to `V`.
+    #[inline]
+    const fn new(u0: f64, v0: f64, u1: f64, v1: f64) -> Option<Self> {
+        let p = [dyadic(u0), dyadic(v0), dyadic(u1), dyadic(v1)];
+        let mut s = 1;
+        let mut i = 0;
+        while i < 4 {
+            if p[i].0 != 0 && s < -p[i].1 {
+                s = -p[i].1;
+            }
+            i += 1;
+        }
+        if 60 < s {
+            return None;
+        }
+        let mut w = [0; 4];
+        let mut i = 0;
+        while i < 4 {
+            let (m, e) = p[i];
+            // The shift is not negative, and `m` has at most 53 bits.
+            let shift = (e + s) as u32;
+            if m != 0 && 61 < shift + (64 - m.unsigned_abs().leading_zeros()) {
+                return None;
+            }
+            w[i] = if m == 0 { 0 } else { (m as i128) << shift };
+            i += 1;
+        }
+        let (du, dv) = (w[2] - w[0], w[3] - w[1]);
+        if du == 0 {
+            return None;
+        }
+        let down = dv < 0;
+        let (du, dv) = if du < 0 { (-du, -dv) } else { (du, dv) };
+        Some(Self { s: s as u32, u0: w[0], v0: w[1], du, dv, down })
+    }
+
+    /// Returns the minor coordinate at the pixel center `u`, rounded to the nearest
+    /// integer, with ties rounding towards `v1`.
+    ///
+    /// The pixel center lies between the endpoints, so that no product overflows.
+    #[inline]
+    const fn minor(&self, u: i128) -> i128 {
+        // This is 2^s * du times the minor coordinate.
+        let n = self.v0 * self.du + ((u << self.s) - self.u0) * self.dv;
+        let (q, r) = (n.div_euclid(self.du), n.rem_euclid(self.du));
+        let h = q + (1 << (self.s - 1));
+        let v = h >> self.s;
+        if r == 0 && h & ((1 << self.s) - 1) == 0 && self.down { v - 1 } else { v }
+    }
+}
+
+/// Returns `(m, e)` with `v = m * 2^e` and `m` odd, or `(0, 0)` if `v` is zero.
+#[inline]
+const fn dyadic(v: f64) -> (i64, i32) {
+    let bits = v.to_bits();
+    let exp = ((bits >> 52) & 0x7ff) as i32;
+    let frac = (bits & ((1 << 52) - 1)) as i64;
+    let (m, e) = if exp == 0 { (frac, -1074) } else { (frac | 1 << 52, exp - 1075) };
+    if m == 0 {
+        return (0, 0);
+    }
+    let tz = m.trailing_zeros();
+    (if v < 0.0 { -(m >> tz) } else { m >> tz }, e + tz as i32)
+}
+
+/// Returns the largest integer not greater than `v`.
+#[inline]
+const fn floor(v: f64) -> f64 {
+    // All floats of at least this magnitude are integers.
+    const BIG: f64 = 4503599627370496.0;
+    if !(-BIG < v && v < BIG) {
+        return v;
+    }
+    let t = v as i64 as f64;
+    if v < t { t - 1.0 } else { t }
+}
+
+/// Returns the smallest integer not less than `v`.
+#[inline]
+const fn ceil(v: f64) -> f64 {
+    -floor(-v)
+}
+
+/// Returns the magnitude of `v`.
+#[inline]
+const fn abs(v: f64) -> f64 {
+    if v < 0.0 { -v } else { v }
+}
+
+/// Returns `v` split into two halves of its significand, by Veltkamp's algorithm.
+#[inline]
+const fn split(v: f64) -> (f64, f64) {
+    let c = 134217729.0 * v;
+    let hi = c - (c - v);
+    (hi, v - hi)
+}
+
+/// Returns the rounded product `a * b` and its rounding error, by Dekker's algorithm.
+#[inline]
+const fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    let ((ah, al), (bh, bl)) = (split(a), split(b));
+    (p, ((ah * bh - p) + ah * bl + al * bh) + al * bl)
+}
+
+/// Returns the segment `(x0, y0) -> (x1, y1)` cut to the square `|x|, |y| <= 2^33`,
+/// or [`None`] if it lies fully outside.
+///
+/// Endpoints inside the square are kept as is. The others are placed on the segment
+/// relative to where it crosses the minor axis, computed from exact products,
+/// so that their error is relative to the square rather than to the original endpoints.
+#[inline]
+const fn cut(x0: f64, y0: f64, x1: f64, y1: f64) -> Option<(f64, f64, f64, f64)> {
+    const SIDE: f64 = 8589934592.0;
+    let inside0 = abs(x0) <= SIDE && abs(y0) <= SIDE;
+    let inside1 = abs(x1) <= SIDE && abs(y1) <= SIDE;
+    if inside0 && inside1 {
+        return Some((x0, y0, x1, y1));
+    }
+    let (hx, hy) = (x1 * 0.5 - x0 * 0.5, y1 * 0.5 - y0 * 0.5);
+    let yx = abs(hx) < abs(hy);
+    let (u0, v0, u1, v1, hu, hv) =
+        if yx { (y0, x0, y1, x1, hy, hx) } else { (x0, y0, x1, y1, hx, hy) };
+    if hu == 0.0 {
+        return None;
+    }
+    // Scaling huge positions by a power of two keeps the products finite,
+    // and is exact for all but negligibly small positions.
+    let max = abs(u0);
+    let max = if max < abs(v0) { abs(v0) } else { max };
+    let max = if max < abs(u1) { abs(u1) } else { max };
+    let max = if max < abs(v1) { abs(v1) } else { max };
+    let k = if max < f64::from_bits(1523 << 52) { 1.0 } else { f64::from_bits(423 << 52) };
+    // The segment crosses `u = 0` at `v = c`, that is `(u1 * v0 - u0 * v1) / (u1 - u0)`.
+    let (p, e) = two_product(u1 * k, v0 * k);
+    let (q, f) = two_product(u0 * k, v1 * k);
+    let c = ((p - q) + (e - f)) / (hu * k) * 0.5 / k;
+    // Unless an endpoint is inside, rounding must not make the cut segment empty.
+    let touches = inside0 || inside1;
+    if !touches && abs(c) > 2.0 * SIDE {
+        return None;
+    }
+    // Each boundary is the constraint `lo <= u <= hi` on the major coordinate.
+    let m = hv / hu;
+    let (mut lo, mut hi) = if u0 < u1 { (u0, u1) } else { (u1, u0) };
+    lo = if lo < -SIDE { -SIDE } else { lo };
+    hi = if SIDE < hi { SIDE } else { hi };
+    if m != 0.0 {
+        let (a, b) = ((-SIDE - c) / m, (SIDE - c) / m);
+        let (a, b) = if a < b { (a, b) } else { (b, a) };
+        lo = if lo < a { a } else { lo };
+        hi = if b < hi { b } else { hi };
+    } else if !touches && SIDE < abs(c) {
+        return None;
+    }
+    if !touches && hi < lo {
+        return None;
+    }
+    let (us, ue) = if u0 < u1 { (lo, hi) } else { (hi, lo) };
+    let (vs, ve) = (c + us * m, c + ue * m);
+    let (xs, ys) = if yx { (vs, us) } else { (us, vs) };
+    let (xe, ye) = if yx { (ve, ue) } else { (ue, ve) };
+    let (x0, y0) = if inside0 { (x0, y0) } else { (xs, ys) };
+    let (x1, y1) = if inside1 { (x1, y1) } else { (xe, ye) };
+    Some((x0, y0, x1, y1))
+}
+
+macro_rules! line_f {
+    ($Cu:ty | $Ci:ty) => {
+        line_f!($Cu, <$Cu as Coord>::U2, <$Cu as Coord>::I2);
+        line_f!($Ci, <$Ci as Coord>::U2, <$Ci as Coord>::I2);
+    };
+    ($C:ty, $U2:ty, $I2:ty) => {
+        impl LineF<$C> {
+            /// Returns a [`LineF`] over the directed, half-open line segment
+            /// `(x0, y0) -> (x1, y1)` between floating-point positions,
+            /// or [`None`] if any of them is not finite.
+            ///
+            /// Points that do not fit into the coordinate type are skipped.
+            /// Use [`f64::from`] for `f32` positions, which converts them exactly.
+            #[inline]
+            pub const fn new(x0: f64, y0: f64, x1: f64, y1: f64) -> Option<Self> {
+                let (min, max) = (<$C>::MIN as $I2, <$C>::MAX as $I2);
+                Self::raw(x0, y0, x1, y1, (min, max), (min, max), (0, 0))
+            }
+
+            /// Returns a [`LineF`] over the points of the segment inside the closed ranges
+            /// `rows` and `cols`, relative to `origin`, or [`None`] if any position is not finite.
+            #[inline]
+            pub(crate) const fn raw(
+                x0: f64,
+                y0: f64,
+                x1: f64,
+                y1: f64,
+                rows: ($I2, $I2),
+                cols: ($I2, $I2),
+                origin: ($I2, $I2),
+            ) -> Option<Self> {
+                if !(x0.is_finite() && y0.is_finite() && x1.is_finite() && y1.is_finite()) {
+                    return None;
+                }
+                let mut line = Self {
+                    u0: 0.0,
+                    v0: 0.0,
+                    du: 0.0,
+                    dv: 0.0,
+                    yx: false,
+                    u: 0,
+                    su: 1,
+                    len: 0,
+                    origin,
+                    exact: None,
+                };
+                let Some((x0, y0, x1, y1)) = cut(x0, y0, x1, y1) else {
+                    return Some(line);
+                };
+                let (dx, dy) = (x1 - x0, y1 - y0);
+                let yx = (if dx < 0.0 { -dx } else { dx }) < (if dy < 0.0 { -dy } else { dy });
+                let (u0, v0, u1, du, dv, (c0, c1), rows) = if yx {
+                    (y0, x0, y1, dy, dx, rows, cols)
+                } else {
+                    (x0, y0, x1, dx, dy, cols, rows)
+                };
+                let (su, a, b) = if 0.0 < du {
+                    (1, Self::clamp(ceil(u0), c0, c1), Self::clamp(ceil(u1) - 1.0, c0, c1))
+                } else {
+                    (-1, Self::clamp(floor(u0), c0, c1), Self::clamp(floor(u1) + 1.0, c0, c1))
+                };
+                (line.u0, line.v0, line.du, line.dv, line.yx, line.su) = (u0, v0, du, dv, yx, su);
+                line.exact = Exact::new(u0, v0, u1, if yx { x1 } else { y1 });
+                let (a, b) = if su < 0 { (b, a) } else { (a, b) };
+                let a = if a < c0 { c0 } else { a };
+                let b = if c1 < b { c1 } else { b };
+                if b < a {
+                    return Some(line);
+                }
+                let n = (b - a) as $U2 + 1;
+                line.u = if su < 0 { b } else { a };
+                // The rounded minor coordinate is monotonic along the segment,
+                // so the points within the rows are contiguous.
+                let (r0, r1) = (rows.0 as f64, rows.1 as f64);
+                let up = 0.0 <= dv;
+                let (mut lo, mut hi) = (0, n);
+                while lo < hi {
+                    let mid = lo + (hi - lo) / 2;
+                    let v = line.minor(line.at(mid));
+                    if if up { v < r0 } else { r1 < v } { lo = mid + 1 } else { hi = mid }
+                }
+                let start = lo;
+                let mut hi = n;
+                while lo < hi {
+                    let mid = lo + (hi - lo) / 2;
+                    let v = line.minor(line.at(mid));
+                    if if up { r1 < v } else { v < r0 } { hi = mid } else { lo = mid + 1 }
+                }
+                (line.u, line.len) = (line.at(start), lo - start);
+                Some(line)
+            }
+
+            /// Converts the integer `v` to the coordinate type, clamped to just outside
+            /// the closed range `c0..=c1`, which keeps the conversion exact.
+            #[inline]
+            const fn clamp(v: f64, c0: $I2, c1: $I2) -> $I2 {
+                let (lo, hi) = (c0 as f64 - 1.0, c1 as f64 + 1.0);
+                (if v < lo {
+                    lo
+                } else if hi < v {
+                    hi
+                } else {
+                    v
+                }) as $I2
+            }
+
+            /// Returns the pixel center `i` steps after the current one along `U`.
+            #[inline]
+            const fn at(&self, i: $U2) -> $I2 {
+                if self.su < 0 { self.u - i as $I2 } else { self.u + i as $I2 }
+            }
+
+            /// Returns the rounded coordinate along `V` at the pixel center `u`.
+            #[inline]
+            const fn minor(&self, u: $I2) -> f64 {
+                let v = self.v0 + (u as f64 - self.u0) * self.dv / self.du;
+                let r = floor(v);
+                let f = v - r;
+                // Positions are below 2^36, so the rounding error of `v` is below 2^-14,
+                // and only near a tie may it round to the wrong side.
+                if abs(f - 0.5) <= 1.0 / 256.0 {
+                    if let Some(exact) = self.exact {
+                        return exact.minor(u as i128) as f64;
+                    }
+                }
+                if 0.5 < f || f == 0.5 && 0.0 <= self.dv { r + 1.0 } else { r }
+            }
+
+            /// Returns the point at the pixel center `u`.
+            #[inline]
+            const fn point(&self, u: $I2) -> ($C, $C) {
+                let v = self.minor(u) as $I2;
+                let (x, y) = if self.yx { (v, u) } else { (u, v) };
+                ((x - self.origin.0) as $C, (y - self.origin.1) as $C)
+            }
+
+            /// Returns `true` if the iterator is empty.
+            #[inline]
+            pub const fn is_empty(&self) -> bool {
+                self.len == 0
+            }
+
+            /// Returns the remaining length of this iterator.
+            #[inline]
+            pub const fn len(&self) -> $U2 {
+                self.len
+            }
+
+            /// Returns the point at the start of the iterator.
+            /// This does not advance the iterator.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn head(&self) -> Option<($C, $C)> {
+                if self.is_empty() {
+                    return None;
+                }
+                Some(self.point(self.u))
+            }
+
+            /// Consumes and returns the point at the start of the iterator.
+            /// This advances the iterator forwards.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn pop_head(&mut self) -> Option<($C, $C)> {
+                let head = try_opt!(self.head());
+                self.u = self.at(1);
+                self.len -= 1;
+                Some(head)
+            }
+
+            /// Returns the point at the end of the iterator.
+            /// This does not advance the iterator.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn tail(&self) -> Option<($C, $C)> {
+                if self.is_empty() {
+                    return None;
+                }
+                Some(self.point(self.at(self.len - 1)))
+            }
+
+            /// Consumes and returns the point at the end of the iterator.
+            /// This advances the iterator backwards.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn pop_tail(&mut self) -> Option<($C, $C)> {
+                let tail = try_opt!(self.tail());
+                self.len -= 1;
+                Some(tail)
+            }
+        }
+
+        impl Iterator for LineF<$C> {
+            type Item = ($C, $C);
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.pop_head()
+            }
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match usize::try_from(self.len()) {
+                    Ok(len) => (len, Some(len)),
+                    Err(_) => (usize::MAX, None),
+                }
+            }
+        }
+
+        impl DoubleEndedIterator for LineF<$C> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.pop_tail()
+            }
+        }
+
+        impl core::iter::FusedIterator for LineF<$C> {}
+    };
+}
+
+clone!([C: Coord] LineF<C>);
+
+line_f!(u8 | i8);
+line_f!(u16 | i16);
+line_f!(u32 | i32);
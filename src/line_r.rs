@@ -0,0 +1,179 @@
+use crate::macros::*;
+use crate::math::{Coord, ops};
+
+/// An iterator over the grid cells traversed by a directed, half-open ray segment
+/// between two fixed-point positions. R stands for ray (Amanatides–Woo).
+///
+/// Endpoints are fixed-point numbers with `F` fractional bits: the cell of
+/// a position `p` is `p >> F`, and its fractional part is the low `F` bits.
+/// Cells are yielded in cell coordinates, starting with the cell of the start position,
+/// followed by one cell per boundary crossed before reaching the end position.
+/// Boundaries crossed exactly at the end position are not included.
+///
+/// When the ray passes exactly through a corner, this steps along `X` first.
+///
+/// # Warning
+///
+/// Internally, this uses an error accumulator twice the size of the coordinate type.
+/// Keep this in mind when using `u64`, `i64`, `usize` and `isize` coordinates.
+/// If you do not need the whole range, prefer smaller coordinate types.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct LineR<const F: u32, C: Coord> {
+    pub(crate) x0: C,
+    pub(crate) y0: C,
+    pub(crate) dx: C::U,
+    pub(crate) dy: C::U,
+    pub(crate) err: C::I2,
+    pub(crate) len: C::U2,
+    pub(crate) sx: i8,
+    pub(crate) sy: i8,
+}
+
+macro_rules! line_r {
+    (
+        $Cu:ty|$Ci:ty$(,
+        exact = [$($ptr_size:literal),+])?
+    ) => {
+        line_r!(
+            $Cu,
+            <$Cu as Coord>::U,
+            <$Cu as Coord>::I,
+            <$Cu as Coord>::U2,
+            <$Cu as Coord>::I2$(,
+            exact = [$($ptr_size),+])?
+        );
+        line_r!(
+            $Ci,
+            <$Ci as Coord>::U,
+            <$Ci as Coord>::I,
+            <$Ci as Coord>::U2,
+            <$Ci as Coord>::I2$(,
+            exact = [$($ptr_size),+])?
+        );
+    };
+    (
+        $C:ty,
+        $U:ty,
+        $I:ty,
+        $U2:ty,
+        $I2:ty$(,
+        exact = [$($ptr_size:literal),+])?
+    ) => {
+        impl<const F: u32> LineR<F, $C> {
+            const ONE: $U = {
+                assert!(F < <$U>::BITS, "too many fractional bits");
+                1 << F
+            };
+
+            /// Returns a [`LineR`] over the directed, half-open ray segment
+            /// `(x0, y0) -> (x1, y1)` between fixed-point positions.
+            ///
+            /// # Panics
+            ///
+            /// At compile time, if `F` is not less than the number of bits in the coordinate type.
+            #[inline]
+            pub const fn new(x0: $C, y0: $C, x1: $C, y1: $C) -> Self {
+                let (cx, dx, sx, rx, nx) = Self::axis(x0, x1);
+                let (cy, dy, sy, ry, ny) = Self::axis(y0, y1);
+                let len = if dx == 0 && dy == 0 { 0 } else { nx as $U2 + ny as $U2 + 1 };
+                let err = Self::err_at(dx, dy, rx, ry, 0, 0);
+                Self { x0: cx, y0: cy, dx, dy, err, len, sx, sy }
+            }
+
+            /// Returns the cell of `p0`, the distance and direction to `p1`,
+            /// the distance to the first boundary, and the number of boundaries crossed.
+            #[inline]
+            pub(crate) const fn axis(p0: $C, p1: $C) -> ($C, $U, i8, $U, $U) {
+                let (d, s) = ops::<$C>::susub(p1, p0);
+                let c = p0 >> F;
+                let f = p0 as $U & (Self::ONE - 1);
+                let r = if 0 < s { Self::ONE - f } else { f };
+                let n = if r < d { (d - r).div_ceil(Self::ONE) } else { 0 };
+                (c, d, s, r, n)
+            }
+
+            /// Returns the error after `kx` and `ky` crossings, given the distances
+            /// `rx` and `ry` to the first boundaries.
+            ///
+            /// The error is the difference between the times of the next crossings along
+            /// `X` and `Y`, scaled by `dx * dy`. It is less than `dx << F` and `dy << F`
+            /// in absolute value, so the wrapping operations yield the exact result.
+            #[inline]
+            pub(crate) const fn err_at(dx: $U, dy: $U, rx: $U, ry: $U, kx: $U, ky: $U) -> $I2 {
+                let (dx, dy) = (dx as $I2, dy as $I2);
+                let ex = (rx as $I2).wrapping_add((kx as $I2).wrapping_shl(F)).wrapping_mul(dy);
+                let ey = (ry as $I2).wrapping_add((ky as $I2).wrapping_shl(F)).wrapping_mul(dx);
+                ex.wrapping_sub(ey)
+            }
+
+            /// Returns `true` if the iterator is empty.
+            #[inline]
+            pub const fn is_empty(&self) -> bool {
+                self.len == 0
+            }
+
+            /// Returns the remaining length of this iterator.
+            #[inline]
+            pub const fn len(&self) -> $U2 {
+                self.len
+            }
+
+            /// Returns the cell at the start of the iterator.
+            /// This does not advance the iterator.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn head(&self) -> Option<($C, $C)> {
+                if self.is_empty() {
+                    return None;
+                }
+                Some((self.x0, self.y0))
+            }
+
+            /// Consumes and returns the cell at the start of the iterator.
+            /// This advances the iterator forwards.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn pop_head(&mut self) -> Option<($C, $C)> {
+                let (x0, y0) = try_opt!(self.head());
+                self.len -= 1;
+                if self.err <= 0 {
+                    self.x0 = ops::<$C>::wadd_i(self.x0, self.sx as $I);
+                    self.err += (self.dy as $I2) << F;
+                } else {
+                    self.y0 = ops::<$C>::wadd_i(self.y0, self.sy as $I);
+                    self.err -= (self.dx as $I2) << F;
+                }
+                Some((x0, y0))
+            }
+        }
+
+        impl<const F: u32> Iterator for LineR<F, $C> {
+            type Item = ($C, $C);
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.pop_head()
+            }
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match usize::try_from(self.len) {
+                    Ok(len) => (len, Some(len)),
+                    Err(_) => (usize::MAX, None),
+                }
+            }
+        }
+
+        $(#[cfg(any($(target_pointer_width = $ptr_size),+))]
+        impl<const F: u32> ExactSizeIterator for LineR<F, $C> {})?
+        impl<const F: u32> core::iter::FusedIterator for LineR<F, $C> {}
+    };
+}
+
+clone!([const F: u32, C: Coord] LineR<F, C>);
+
+line_r!(u8 | i8, exact = ["16", "32", "64"]);
+line_r!(u16 | i16, exact = ["32", "64"]);
+line_r!(u32 | i32, exact = ["64"]);
+line_r!(u64 | i64);
+line_r!(usize | isize);
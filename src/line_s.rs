@@ -0,0 +1,236 @@
+use crate::macros::*;
+use crate::math::{Coord, ops};
+
+/// An iterator over the grid cells traversed by a directed, half-open line segment
+/// between the centers of two cells. S stands for supercover.
+///
+/// `ALL` determines how corners are handled, where the segment passes exactly through
+/// a point shared by four cells:
+/// * `false`: [`LineS4`] yields a 4-connected path, stepping along `X` first.
+/// * `true`: [`LineS`] yields every touched cell, including both cells at the corner.
+///
+/// # Warning
+///
+/// Internally, this uses an error accumulator twice the size of the coordinate type.
+/// Keep this in mind when using `u64`, `i64`, `usize` and `isize` coordinates.
+/// If you do not need the whole range, prefer smaller coordinate types.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct LineSu<const ALL: bool, C: Coord> {
+    pub(crate) x0: C,
+    pub(crate) y0: C,
+    pub(crate) dx: C::U,
+    pub(crate) dy: C::U,
+    pub(crate) err: C::I2,
+    pub(crate) len: C::U2,
+    pub(crate) sx: i8,
+    pub(crate) sy: i8,
+    /// `1` if at the first cell of a corner, `2` if at the second.
+    pub(crate) phase: u8,
+    /// The first cell of the last corner is skipped, as it lies outside the clipping region.
+    pub(crate) skip: bool,
+}
+
+/// An iterator over the 4-connected grid cells traversed by a directed, half-open
+/// line segment between the centers of two cells. S stands for supercover.
+///
+/// When the segment passes exactly through a corner, this steps along `X` first.
+///
+/// # Warning
+///
+/// Internally, this uses an error accumulator twice the size of the coordinate type.
+/// Keep this in mind when using `u64`, `i64`, `usize` and `isize` coordinates.
+/// If you do not need the whole range, prefer smaller coordinate types.
+pub type LineS4<C> = LineSu<false, C>;
+
+/// An iterator over all grid cells touched by a directed, half-open line segment
+/// between the centers of two cells. S stands for supercover.
+///
+/// When the segment passes exactly through a corner, this yields both cells
+/// sharing that corner, first the one along `X`, then the one along `Y`.
+///
+/// # Warning
+///
+/// Internally, this uses an error accumulator twice the size of the coordinate type.
+/// Keep this in mind when using `u64`, `i64`, `usize` and `isize` coordinates.
+/// If you do not need the whole range, prefer smaller coordinate types.
+pub type LineS<C> = LineSu<true, C>;
+
+impl<const ALL: bool, C: Coord> core::fmt::Debug for LineSu<ALL, C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let name = if ALL { "LineS" } else { "LineS4" };
+        f.debug_struct(name)
+            .field("x0", &self.x0)
+            .field("y0", &self.y0)
+            .field("dx", &self.dx)
+            .field("dy", &self.dy)
+            .field("err", &self.err)
+            .field("len", &self.len)
+            .field("sx", &self.sx)
+            .field("sy", &self.sy)
+            .field("phase", &self.phase)
+            .field("skip", &self.skip)
+            .finish()
+    }
+}
+
+macro_rules! line_s {
+    (
+        $Cu:ty|$Ci:ty$(,
+        exact = [$($ptr_size:literal),+])?
+    ) => {
+        line_s!(
+            $Cu,
+            <$Cu as Coord>::U,
+            <$Cu as Coord>::I,
+            <$Cu as Coord>::U2,
+            <$Cu as Coord>::I2$(,
+            exact = [$($ptr_size),+])?
+        );
+        line_s!(
+            $Ci,
+            <$Ci as Coord>::U,
+            <$Ci as Coord>::I,
+            <$Ci as Coord>::U2,
+            <$Ci as Coord>::I2$(,
+            exact = [$($ptr_size),+])?
+        );
+    };
+    (
+        $C:ty,
+        $U:ty,
+        $I:ty,
+        $U2:ty,
+        $I2:ty$(,
+        exact = [$($ptr_size:literal),+])?
+    ) => {
+        impl<const ALL: bool> LineSu<ALL, $C> {
+            /// Returns a [`LineSu`] over the directed, half-open line segment
+            /// `(x0, y0) -> (x1, y1)`.
+            #[inline]
+            pub const fn new(x0: $C, y0: $C, x1: $C, y1: $C) -> Self {
+                let (dx, sx) = ops::<$C>::susub(x1, x0);
+                let (dy, sy) = ops::<$C>::susub(y1, y0);
+                let err = dy as $I2 - dx as $I2;
+                let mut len = dx as $U2 + dy as $U2;
+                if ALL {
+                    len += Self::corners(dx, dy) as $U2;
+                }
+                Self { x0, y0, dx, dy, err, len, sx, sy, phase: 0, skip: false }
+            }
+
+            /// Returns the number of corners the segment `(0, 0) -> (dx, dy)` passes through.
+            ///
+            /// Corners are only crossed if `dx / gcd` and `dy / gcd` are both odd,
+            /// in which case there are `gcd` of them.
+            #[inline]
+            pub(crate) const fn corners(dx: $U, dy: $U) -> $U {
+                if dx == 0 || dy == 0 || dx.trailing_zeros() != dy.trailing_zeros() {
+                    return 0;
+                }
+                Self::gcd(dx, dy)
+            }
+
+            #[inline]
+            pub(crate) const fn gcd(mut a: $U, mut b: $U) -> $U {
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                a
+            }
+
+            /// Returns `true` if the iterator is empty.
+            #[inline]
+            pub const fn is_empty(&self) -> bool {
+                self.len == 0
+            }
+
+            /// Returns the remaining length of this iterator.
+            ///
+            /// This can exceed the range of the unsigned coordinate type.
+            #[inline]
+            pub const fn len(&self) -> $U2 {
+                self.len
+            }
+
+            /// Returns the point at the start of the iterator.
+            /// This does not advance the iterator.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn head(&self) -> Option<($C, $C)> {
+                if self.is_empty() {
+                    return None;
+                }
+                Some((self.x0, self.y0))
+            }
+
+            /// Consumes and returns the point at the start of the iterator.
+            /// This advances the iterator forwards.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn pop_head(&mut self) -> Option<($C, $C)> {
+                let (x0, y0) = try_opt!(self.head());
+                self.len -= 1;
+                let dx2 = 2 * self.dx as $I2;
+                let dy2 = 2 * self.dy as $I2;
+                let (sx, sy) = (self.sx as $I, self.sy as $I);
+                match self.phase {
+                    1 => {
+                        self.x0 = ops::<$C>::wsub_i(self.x0, sx);
+                        self.y0 = ops::<$C>::wadd_i(self.y0, sy);
+                        self.phase = 2;
+                    }
+                    2 => {
+                        self.x0 = ops::<$C>::wadd_i(self.x0, sx);
+                        self.err += dy2 - dx2;
+                        self.phase = 0;
+                    }
+                    _ => {
+                        if self.err < 0 || self.err == 0 && !ALL {
+                            self.x0 = ops::<$C>::wadd_i(self.x0, sx);
+                            self.err += dy2;
+                        } else if 0 < self.err {
+                            self.y0 = ops::<$C>::wadd_i(self.y0, sy);
+                            self.err -= dx2;
+                        } else if self.skip && self.len == 1 {
+                            self.y0 = ops::<$C>::wadd_i(self.y0, sy);
+                            self.phase = 2;
+                        } else {
+                            self.x0 = ops::<$C>::wadd_i(self.x0, sx);
+                            self.phase = 1;
+                        }
+                    }
+                }
+                Some((x0, y0))
+            }
+        }
+
+        impl<const ALL: bool> Iterator for LineSu<ALL, $C> {
+            type Item = ($C, $C);
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.pop_head()
+            }
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match usize::try_from(self.len) {
+                    Ok(len) => (len, Some(len)),
+                    Err(_) => (usize::MAX, None),
+                }
+            }
+        }
+
+        $(#[cfg(any($(target_pointer_width = $ptr_size),+))]
+        impl<const ALL: bool> ExactSizeIterator for LineSu<ALL, $C> {})?
+        impl<const ALL: bool> core::iter::FusedIterator for LineSu<ALL, $C> {}
+    };
+}
+
+clone!([const ALL: bool, C: Coord] LineSu<ALL, C>);
+
+line_s!(u8 | i8, exact = ["16", "32", "64"]);
+line_s!(u16 | i16, exact = ["32", "64"]);
+line_s!(u32 | i32, exact = ["64"]);
+line_s!(u64 | i64);
+line_s!(usize | isize);
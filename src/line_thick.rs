@@ -0,0 +1,532 @@
+use crate::macros::*;
+use crate::math::{Coord, ops};
+
+/// The shape of the ends of a [`LineThick`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Cap {
+    /// The stroke ends exactly at the endpoints.
+    #[default]
+    Butt,
+    /// The stroke is extended past the endpoints by half of its width.
+    Square,
+    /// The stroke is extended past the endpoints by discs with a diameter of its width.
+    ///
+    /// The body of a sloped stroke is thinner than its width perpendicular to its direction,
+    /// down to `width / √2` at 45°, so these discs stick out past the sides of the body.
+    Round,
+}
+
+/// An iterator over the horizontal spans of a thick, closed line segment.
+///
+/// Yields `(y, x_start, x_end)`, where `x_start <= x_end` are both inside the span.
+/// Rows are yielded in increasing order of `y`, each at most once.
+///
+/// The stroke is made of the points of the [Bresenham][crate::LineB] segment,
+/// each widened along the minor axis to a run of `width` points,
+/// with the extra point of an even run placed on the positive side.
+/// With a width of `1` and [`Cap::Butt`], this covers the same points
+/// as [`LineB`](crate::LineB) over the closed segment.
+///
+/// Since the width is measured along the minor axis, the stroke is only
+/// `width * du / √(du² + dv²)` thick perpendicular to its direction,
+/// where `du` and `dv` are the extents of the segment along its major and minor axes,
+/// down to `width / √2` at 45°. The discs of [`Cap::Round`] keep the full `width`
+/// as their diameter, so they are wider than the body of a sloped stroke.
+///
+/// # Warning
+///
+/// Internally, this uses integers twice the size of the coordinate type.
+/// Keep this in mind when using `u64`, `i64`, `usize` and `isize` coordinates.
+/// If you do not need the whole range, prefer smaller coordinate types.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct LineThick<C: Coord> {
+    pub(crate) x0: C::I2,
+    pub(crate) y0: C::I2,
+    pub(crate) du: C::U,
+    pub(crate) dv: C::U,
+    pub(crate) width: C::U,
+    pub(crate) su: i8,
+    pub(crate) sv: i8,
+    pub(crate) yx: bool,
+    pub(crate) cap: Cap,
+    pub(crate) r0: C::I2,
+    pub(crate) r1: C::I2,
+    pub(crate) c0: C::I2,
+    pub(crate) c1: C::I2,
+}
+
+macro_rules! line_thick {
+    ($Cu:ty | $Ci:ty) => {
+        line_thick!($Cu, <$Cu as Coord>::U, <$Cu as Coord>::U2, <$Cu as Coord>::I2);
+        line_thick!($Ci, <$Ci as Coord>::U, <$Ci as Coord>::U2, <$Ci as Coord>::I2);
+    };
+    ($C:ty, $U:ty, $U2:ty, $I2:ty) => {
+        impl LineThick<$C> {
+            /// Returns a [`LineThick`] over the closed line segment `(x0, y0) -> (x1, y1)`
+            /// with the given `width` and `cap`, or [`None`] if `width` is zero.
+            ///
+            /// Parts of the stroke that do not fit into the coordinate type are skipped.
+            #[inline]
+            pub const fn new(
+                x0: $C,
+                y0: $C,
+                x1: $C,
+                y1: $C,
+                width: $U,
+                cap: Cap,
+            ) -> Option<Self> {
+                let (dx, sx) = ops::<$C>::susub(x1, x0);
+                let (dy, sy) = ops::<$C>::susub(y1, y0);
+                let (x0, y0) = (x0 as $I2, y0 as $I2);
+                let (min, max) = (<$C>::MIN as $I2, <$C>::MAX as $I2);
+                let rows = (min - y0, max - y0);
+                let cols = (min - x0, max - x0);
+                Self::raw(x0, y0, dx, dy, sx, sy, width, cap, rows, cols)
+            }
+
+            /// Returns a [`LineThick`] whose rows and columns relative to `(x0, y0)`
+            /// are restricted to the closed ranges `rows` and `cols`,
+            /// or [`None`] if no points are left.
+            #[inline]
+            #[allow(clippy::too_many_arguments)]
+            pub(crate) const fn raw(
+                x0: $I2,
+                y0: $I2,
+                dx: $U,
+                dy: $U,
+                sx: i8,
+                sy: i8,
+                width: $U,
+                cap: Cap,
+                rows: ($I2, $I2),
+                cols: ($I2, $I2),
+            ) -> Option<Self> {
+                if width == 0 {
+                    return None;
+                }
+                let yx = dx < dy;
+                let (du, dv, su, sv) = if yx { (dy, dx, sy, sx) } else { (dx, dy, sx, sy) };
+                let mut line = Self {
+                    x0,
+                    y0,
+                    du,
+                    dv,
+                    width,
+                    su,
+                    sv,
+                    yx,
+                    cap,
+                    r0: 0,
+                    r1: 0,
+                    c0: cols.0,
+                    c1: cols.1,
+                };
+                let (r0, r1) = line.rows();
+                let (v0, v1) = try_opt!(line.visible_rows());
+                let r0 = if r0 < v0 { v0 } else { r0 };
+                let r1 = if v1 < r1 { v1 } else { r1 };
+                line.r0 = if r0 < rows.0 { rows.0 } else { r0 };
+                line.r1 = if rows.1 < r1 { rows.1 } else { r1 };
+                if line.r1 < line.r0 {
+                    return None;
+                }
+                Some(line)
+            }
+
+            /// Returns `true` if the iterator is empty.
+            #[inline]
+            pub const fn is_empty(&self) -> bool {
+                self.r1 < self.r0
+            }
+
+            /// Returns the span at the start of the iterator.
+            /// This does not advance the iterator.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn head(&self) -> Option<($C, $C, $C)> {
+                let mut line = self.clone();
+                line.pop_head()
+            }
+
+            /// Consumes and returns the span at the start of the iterator.
+            /// This advances the iterator forwards.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn pop_head(&mut self) -> Option<($C, $C, $C)> {
+                while !self.is_empty() {
+                    let r = self.r0;
+                    self.r0 += 1;
+                    if let Some((a, b)) = self.clip_span(r) {
+                        let y = (self.y0 + r) as $C;
+                        return Some((y, (self.x0 + a) as $C, (self.x0 + b) as $C));
+                    }
+                }
+                None
+            }
+
+            #[inline]
+            const fn lo(&self) -> $I2 {
+                ((self.width - 1) / 2) as $I2
+            }
+
+            #[inline]
+            const fn hi(&self) -> $I2 {
+                (self.width / 2) as $I2
+            }
+
+            /// Returns `1` if the center of each run is offset by half a point, otherwise `0`.
+            #[inline]
+            const fn delta(&self) -> $I2 {
+                self.hi() - self.lo()
+            }
+
+            /// Returns the half-extent of a cap along one axis, in doubled units,
+            /// given the offset `t` from the center along the other axis in doubled units.
+            #[inline]
+            const fn disc(&self, t: $I2) -> Option<$U2> {
+                let t = t.unsigned_abs();
+                if self.width as $U2 < t {
+                    return None;
+                }
+                let w = self.width as $U2;
+                let rhs = w * w - 1;
+                let t2 = t * t;
+                if rhs < t2 {
+                    return None;
+                }
+                Some((rhs - t2).isqrt())
+            }
+
+            /// Returns how far the caps extend past the endpoints along the major axis.
+            #[inline]
+            const fn ext(&self) -> $I2 {
+                match self.cap {
+                    Cap::Butt => 0,
+                    Cap::Square => self.hi(),
+                    Cap::Round => match self.disc(self.delta()) {
+                        Some(s) => (s / 2) as $I2,
+                        None => 0,
+                    },
+                }
+            }
+
+            /// Returns the Bresenham offset along the minor axis at step `0 <= k <= du`.
+            #[inline]
+            const fn q(&self, k: $I2) -> $I2 {
+                if self.du == 0 {
+                    return 0;
+                }
+                let h = (self.du / 2) as $U2;
+                ((k as $U2 * self.dv as $U2 + h) / self.du as $U2) as $I2
+            }
+
+            /// Returns the first step `0 <= k <= du` with `t <= q(k)`, given `0 <= t <= dv`.
+            #[inline]
+            const fn k_min(&self, t: $I2) -> $I2 {
+                if t == 0 {
+                    return 0;
+                }
+                let h = (self.du / 2) as $U2;
+                ((t as $U2 * self.du as $U2 - h).div_ceil(self.dv as $U2)) as $I2
+            }
+
+            /// Returns the last step `0 <= k <= du` with `q(k) <= t`, given `0 <= t <= dv`.
+            #[inline]
+            const fn k_max(&self, t: $I2) -> $I2 {
+                if t == self.dv as $I2 {
+                    return self.du as $I2;
+                }
+                let h = (self.du / 2) as $U2;
+                (((t + 1) as $U2 * self.du as $U2 - h).div_ceil(self.dv as $U2)) as $I2 - 1
+            }
+
+            /// Returns the largest `|t|` with `need <= disc(t)`, or [`None`] if there is none.
+            #[inline]
+            const fn disc_reach(&self, need: $I2) -> Option<$I2> {
+                let need = if need < 0 { 0 } else { need.unsigned_abs() };
+                let w = self.width as $U2;
+                if w <= need {
+                    return None;
+                }
+                Some((w * w - 1 - need * need).isqrt() as $I2)
+            }
+
+            #[inline]
+            const fn signed(s: i8, v: $I2) -> $I2 {
+                if s < 0 { -v } else { v }
+            }
+
+            /// Returns the closed range of rows relative to `y0`.
+            #[inline]
+            const fn rows(&self) -> ($I2, $I2) {
+                let du = self.du as $I2;
+                let dv = Self::signed(self.sv, self.dv as $I2);
+                if self.yx {
+                    let e = self.ext();
+                    let (a, b) = (Self::signed(self.su, -e), Self::signed(self.su, du + e));
+                    if a <= b { (a, b) } else { (b, a) }
+                } else {
+                    let (a, b) = if 0 <= dv { (0, dv) } else { (dv, 0) };
+                    (a - self.lo(), b + self.hi())
+                }
+            }
+
+            /// Returns the closed range of rows relative to `y0` whose spans reach
+            /// the columns `[c0, c1]`, or [`None`] if there are none.
+            ///
+            /// This joins the rows of the body and of each cap, which are computed
+            /// in closed form from the columns, so clipping never steps through rows.
+            #[inline]
+            const fn visible_rows(&self) -> Option<($I2, $I2)> {
+                let (lo, hi, delta) = (self.lo(), self.hi(), self.delta());
+                let (du, dv) = (self.du as $I2, self.dv as $I2);
+                let (c0, c1) = (self.c0, self.c1);
+                let u1 = Self::signed(self.su, du);
+                let v1 = Self::signed(self.sv, dv);
+                let mut rows = None;
+                if self.yx {
+                    // The run of step `k` reaches the columns if `sv * q(k)` is in `[c0 - hi, c1 + lo]`.
+                    let (t0, t1) =
+                        if 0 < self.sv { (c0 - hi, c1 + lo) } else { (-(c1 + lo), -(c0 - hi)) };
+                    let t0 = if t0 < 0 { 0 } else { t0 };
+                    let t1 = if dv < t1 { dv } else { t1 };
+                    if t0 <= t1 {
+                        let (k0, k1) = (self.k_min(t0), self.k_max(t1));
+                        rows = Self::hull(rows, Self::signed(self.su, k0), Self::signed(self.su, k1));
+                    }
+                    match self.cap {
+                        Cap::Butt => {}
+                        Cap::Square => {
+                            let e = self.hi();
+                            if 0 < e && -lo <= c1 && c0 <= hi {
+                                let (a, b) = (Self::signed(self.su, -e), Self::signed(self.su, -1));
+                                rows = Self::hull(rows, a, b);
+                            }
+                            if 0 < e && v1 - lo <= c1 && c0 <= v1 + hi {
+                                let (a, b) = (du + 1, du + e);
+                                rows = Self::hull(rows, Self::signed(self.su, a), Self::signed(self.su, b));
+                            }
+                        }
+                        Cap::Round => {
+                            // The span of a cap reaches the columns if its doubled half-extent does.
+                            let need = Self::need_y(delta, c0, c1);
+                            if let Some(t) = self.disc_reach(need) {
+                                rows = Self::hull(rows, -(t / 2), t / 2);
+                            }
+                            let need = Self::need_y(delta, c0 - v1, c1 - v1);
+                            if let Some(t) = self.disc_reach(need) {
+                                rows = Self::hull(rows, u1 - t / 2, u1 + t / 2);
+                            }
+                        }
+                    }
+                } else {
+                    // The steps inside the columns cover the offsets `q(ka)..=q(kb)`.
+                    let (k0, k1) = if 0 < self.su { (c0, c1) } else { (-c1, -c0) };
+                    let ka = if k0 < 0 { 0 } else { k0 };
+                    let kb = if du < k1 { du } else { k1 };
+                    if ka <= kb {
+                        let (qa, qb) = (self.q(ka), self.q(kb));
+                        rows = if 0 < self.sv {
+                            Self::hull(rows, qa - lo, qb + hi)
+                        } else {
+                            Self::hull(rows, -qb - lo, hi - qa)
+                        };
+                    }
+                    match self.cap {
+                        Cap::Butt => {}
+                        Cap::Square => {
+                            let e = self.hi();
+                            let (a, b) = (Self::signed(self.su, -e), Self::signed(self.su, -1));
+                            let (a, b) = if a <= b { (a, b) } else { (b, a) };
+                            if 0 < e && a <= c1 && c0 <= b {
+                                rows = Self::hull(rows, -lo, hi);
+                            }
+                            let (a, b) = (Self::signed(self.su, du + 1), Self::signed(self.su, du + e));
+                            let (a, b) = if a <= b { (a, b) } else { (b, a) };
+                            if 0 < e && a <= c1 && c0 <= b {
+                                rows = Self::hull(rows, v1 - lo, v1 + hi);
+                            }
+                        }
+                        Cap::Round => {
+                            // The half-extent `disc(2 * r - delta) / 2` must reach the columns,
+                            // so `|2 * r - delta| <= t`.
+                            if let Some(t) = self.disc_reach(2 * Self::need_x(c0, c1)) {
+                                let (a, b) = (-(t - delta).div_euclid(2), (t + delta).div_euclid(2));
+                                if a <= b {
+                                    rows = Self::hull(rows, a, b);
+                                }
+                            }
+                            if let Some(t) = self.disc_reach(2 * Self::need_x(c0 - u1, c1 - u1)) {
+                                let (a, b) = (-(t - delta).div_euclid(2), (t + delta).div_euclid(2));
+                                if a <= b {
+                                    rows = Self::hull(rows, v1 + a, v1 + b);
+                                }
+                            }
+                        }
+                    }
+                }
+                rows
+            }
+
+            /// Returns the distance from column `0` to the columns `[c0, c1]`.
+            #[inline]
+            const fn need_x(c0: $I2, c1: $I2) -> $I2 {
+                if 0 < c0 {
+                    c0
+                } else if c1 < 0 {
+                    -c1
+                } else {
+                    0
+                }
+            }
+
+            /// Returns the least doubled half-extent `s` of a round cap centered at column `0`
+            /// in a "fast" slope whose span `[-((s - delta) / 2), (s + delta) / 2]`
+            /// reaches the columns `[c0, c1]`.
+            #[inline]
+            const fn need_y(delta: $I2, c0: $I2, c1: $I2) -> $I2 {
+                let mut need = delta;
+                if need < delta - 2 * c1 {
+                    need = delta - 2 * c1;
+                }
+                if need < 2 * c0 - delta {
+                    need = 2 * c0 - delta;
+                }
+                need
+            }
+
+            #[inline]
+            const fn hull(span: Option<($I2, $I2)>, a: $I2, b: $I2) -> Option<($I2, $I2)> {
+                let (a, b) = if a <= b { (a, b) } else { (b, a) };
+                match span {
+                    None => Some((a, b)),
+                    Some((c, d)) => Some((if a < c { a } else { c }, if d < b { b } else { d })),
+                }
+            }
+
+            /// Returns the span of row `r` relative to `(x0, y0)` for a "fast" slope.
+            #[inline]
+            const fn span_y(&self, r: $I2) -> Option<($I2, $I2)> {
+                let (lo, hi, delta) = (self.lo(), self.hi(), self.delta());
+                let du = self.du as $I2;
+                let c1 = Self::signed(self.sv, self.dv as $I2);
+                let k = Self::signed(self.su, r);
+                let mut span = None;
+                if 0 <= k && k <= du {
+                    let c = Self::signed(self.sv, self.q(k));
+                    span = Self::hull(span, c - lo, c + hi);
+                }
+                match self.cap {
+                    Cap::Butt => {}
+                    Cap::Square => {
+                        let e = self.hi();
+                        if -e <= k && k < 0 {
+                            span = Self::hull(span, -lo, hi);
+                        }
+                        if du < k && k <= du + e {
+                            span = Self::hull(span, c1 - lo, c1 + hi);
+                        }
+                    }
+                    Cap::Round => {
+                        if let Some(s) = self.disc(2 * k) {
+                            let s = s as $I2;
+                            if delta <= s {
+                                span = Self::hull(span, -((s - delta) / 2), (s + delta) / 2);
+                            }
+                        }
+                        if let Some(s) = self.disc(2 * (k - du)) {
+                            let s = s as $I2;
+                            if delta <= s {
+                                span = Self::hull(span, c1 - (s - delta) / 2, c1 + (s + delta) / 2);
+                            }
+                        }
+                    }
+                }
+                span
+            }
+
+            /// Returns the span of row `r` relative to `(x0, y0)` for a "slow" slope.
+            #[inline]
+            const fn span_x(&self, r: $I2) -> Option<($I2, $I2)> {
+                let (lo, hi, delta) = (self.lo(), self.hi(), self.delta());
+                let (du, dv) = (self.du as $I2, self.dv as $I2);
+                let c1 = Self::signed(self.sv, dv);
+                let mut span = None;
+                let (a, b) = if 0 < self.sv { (r - hi, r + lo) } else { (-r - lo, -r + hi) };
+                let a = if a < 0 { 0 } else { a };
+                let b = if dv < b { dv } else { b };
+                if a <= b {
+                    let (k0, k1) = (self.k_min(a), self.k_max(b));
+                    span = Self::hull(span, Self::signed(self.su, k0), Self::signed(self.su, k1));
+                }
+                match self.cap {
+                    Cap::Butt => {}
+                    Cap::Square => {
+                        let e = self.hi();
+                        if 0 < e && -lo <= r && r <= hi {
+                            span = Self::hull(span, Self::signed(self.su, -e), Self::signed(self.su, -1));
+                        }
+                        if 0 < e && -lo <= r - c1 && r - c1 <= hi {
+                            let (k0, k1) = (du + 1, du + e);
+                            span = Self::hull(span, Self::signed(self.su, k0), Self::signed(self.su, k1));
+                        }
+                    }
+                    Cap::Round => {
+                        if let Some(s) = self.disc(2 * r - delta) {
+                            let s = (s / 2) as $I2;
+                            span = Self::hull(span, -s, s);
+                        }
+                        if let Some(s) = self.disc(2 * (r - c1) - delta) {
+                            let s = (s / 2) as $I2;
+                            let c = Self::signed(self.su, du);
+                            span = Self::hull(span, c - s, c + s);
+                        }
+                    }
+                }
+                span
+            }
+
+            /// Returns the span of row `r` relative to `(x0, y0)`,
+            /// restricted to the columns `[c0, c1]`.
+            #[inline]
+            const fn clip_span(&self, r: $I2) -> Option<($I2, $I2)> {
+                let span = if self.yx { self.span_y(r) } else { self.span_x(r) };
+                let (a, b) = try_opt!(span);
+                let a = if a < self.c0 { self.c0 } else { a };
+                let b = if self.c1 < b { self.c1 } else { b };
+                if b < a {
+                    return None;
+                }
+                Some((a, b))
+            }
+        }
+
+        impl Iterator for LineThick<$C> {
+            type Item = ($C, $C, $C);
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.pop_head()
+            }
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.is_empty() {
+                    return (0, Some(0));
+                }
+                let rows = (self.r1 - self.r0) as $U2 + 1;
+                (1, usize::try_from(rows).ok())
+            }
+        }
+
+        impl core::iter::FusedIterator for LineThick<$C> {}
+    };
+}
+
+clone!([C: Coord] LineThick<C>);
+
+line_thick!(u8 | i8);
+line_thick!(u16 | i16);
+line_thick!(u32 | i32);
+line_thick!(u64 | i64);
+line_thick!(usize | isize);
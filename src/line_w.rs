@@ -0,0 +1,350 @@
+use crate::macros::*;
+use crate::math::{Coord, ops};
+
+/// An iterator over the anti-aliased points of a directed, half-open line segment
+/// with a "slow" slope relative to the major axis `U`. W stands for Xiaolin Wu.
+///
+/// Yields `(x, y, coverage)`, where `coverage` is the intensity of the point
+/// scaled to `0..=255`. Each step along the major axis yields up to two points
+/// straddling the ideal segment, whose coverages sum up to `255`.
+/// Points with zero coverage are skipped.
+///
+/// `YX` determines the major axis:
+/// * `false`: [`LineWx`] for segments with a "slow" slope relative to `X` (`dy <= dx`).
+/// * `true`: [`LineWy`] for segments with a "fast" slope relative to `X` (`dx < dy`).
+///
+/// # Warning
+///
+/// Internally, the coverage is computed in an integer twice the size of the coordinate type.
+/// Keep this in mind when using `u64`, `i64`, `usize` and `isize` coordinates.
+/// If you do not need the whole range, prefer smaller coordinate types.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct LineWu<const YX: bool, C: Coord> {
+    pub(crate) u0: C,
+    pub(crate) v0: C,
+    pub(crate) du: C::U,
+    pub(crate) dv: C::U,
+    pub(crate) rem: C::U,
+    pub(crate) u1: C,
+    pub(crate) v_min: C,
+    pub(crate) v_max: C,
+    pub(crate) su: i8,
+    pub(crate) sv: i8,
+    pub(crate) second: bool,
+}
+
+/// An iterator over the anti-aliased points of a directed, half-open line segment
+/// with a "slow" slope relative to axis `X` (`dy <= dx`). W stands for Xiaolin Wu.
+///
+/// # Warning
+///
+/// Internally, the coverage is computed in an integer twice the size of the coordinate type.
+/// Keep this in mind when using `u64`, `i64`, `usize` and `isize` coordinates.
+/// If you do not need the whole range, prefer smaller coordinate types.
+pub type LineWx<C> = LineWu<false, C>;
+
+/// An iterator over the anti-aliased points of a directed, half-open line segment
+/// with a "fast" slope relative to axis `X` (`dx < dy`). W stands for Xiaolin Wu.
+///
+/// # Warning
+///
+/// Internally, the coverage is computed in an integer twice the size of the coordinate type.
+/// Keep this in mind when using `u64`, `i64`, `usize` and `isize` coordinates.
+/// If you do not need the whole range, prefer smaller coordinate types.
+pub type LineWy<C> = LineWu<true, C>;
+
+impl<const YX: bool, C: Coord> core::fmt::Debug for LineWu<YX, C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let name = if YX { "LineWy" } else { "LineWx" };
+        let u0 = if YX { "y0" } else { "x0" };
+        let v0 = if YX { "x0" } else { "y0" };
+        let du = if YX { "dy" } else { "dx" };
+        let dv = if YX { "dx" } else { "dy" };
+        let u1 = if YX { "y1" } else { "x1" };
+        let v_min = if YX { "x_min" } else { "y_min" };
+        let v_max = if YX { "x_max" } else { "y_max" };
+        let su = if YX { "sy" } else { "sx" };
+        let sv = if YX { "sx" } else { "sy" };
+        f.debug_struct(name)
+            .field(u0, &self.u0)
+            .field(v0, &self.v0)
+            .field(du, &self.du)
+            .field(dv, &self.dv)
+            .field("rem", &self.rem)
+            .field(u1, &self.u1)
+            .field(v_min, &self.v_min)
+            .field(v_max, &self.v_max)
+            .field(su, &self.su)
+            .field(sv, &self.sv)
+            .field("second", &self.second)
+            .finish()
+    }
+}
+
+macro_rules! line_wu {
+    ($Cu:ty | $Ci:ty) => {
+        line_wu!($Cu, <$Cu as Coord>::U, <$Cu as Coord>::I, <$Cu as Coord>::U2);
+        line_wu!($Ci, <$Ci as Coord>::U, <$Ci as Coord>::I, <$Ci as Coord>::U2);
+    };
+    ($C:ty, $U:ty, $I:ty, $U2:ty) => {
+        impl<const YX: bool> LineWu<YX, $C> {
+            /// Returns `true` if the iterator is empty.
+            #[inline]
+            pub const fn is_empty(&self) -> bool {
+                self.u0 == self.u1
+            }
+
+            /// Returns the remaining number of steps along the major axis.
+            ///
+            /// Each step yields one or two points.
+            #[inline]
+            pub const fn steps(&self) -> $U {
+                ops::<$C>::wusub_s(self.u1, self.u0, self.su)
+            }
+
+            /// Returns the point at the start of the iterator.
+            /// This does not advance the iterator.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn head(&self) -> Option<($C, $C, u8)> {
+                let mut line = self.clone();
+                line.pop_head()
+            }
+
+            /// Consumes and returns the point at the start of the iterator.
+            /// This advances the iterator forwards.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn pop_head(&mut self) -> Option<($C, $C, u8)> {
+                loop {
+                    if self.is_empty() {
+                        return None;
+                    }
+                    let cov = self.coverage();
+                    let (u, v) = (self.u0, self.v0);
+                    let vs = ops::<$C>::wadd_i(v, self.sv as $I);
+                    if self.second {
+                        self.second = false;
+                        self.step();
+                        let (x, y) = if YX { (vs, u) } else { (u, vs) };
+                        return Some((x, y, cov));
+                    }
+                    self.second = cov != 0 && self.contains_v(vs);
+                    if !self.second {
+                        self.step();
+                    }
+                    if cov != u8::MAX && self.contains_v(v) {
+                        let (x, y) = if YX { (v, u) } else { (u, v) };
+                        return Some((x, y, u8::MAX - cov));
+                    }
+                }
+            }
+
+            #[inline]
+            const fn contains_v(&self, v: $C) -> bool {
+                self.v_min <= v && v <= self.v_max
+            }
+
+            #[inline]
+            const fn coverage(&self) -> u8 {
+                debug_assert!(self.rem < self.du);
+                (((self.rem as $U2) << 8) / self.du as $U2) as u8
+            }
+
+            #[inline]
+            const fn step(&mut self) {
+                let gap = self.du - self.dv;
+                if gap <= self.rem {
+                    self.rem -= gap;
+                    self.v0 = ops::<$C>::wadd_i(self.v0, self.sv as $I);
+                } else {
+                    self.rem += self.dv;
+                }
+                self.u0 = ops::<$C>::wadd_i(self.u0, self.su as $I);
+            }
+        }
+
+        impl<const YX: bool> Iterator for LineWu<YX, $C> {
+            type Item = ($C, $C, u8);
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.pop_head()
+            }
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match usize::try_from(self.steps()) {
+                    Ok(steps) => (steps, steps.checked_mul(2)),
+                    Err(_) => (usize::MAX, None),
+                }
+            }
+        }
+
+        impl<const YX: bool> core::iter::FusedIterator for LineWu<YX, $C> {}
+    };
+}
+
+clone!([const YX: bool, C: Coord] LineWu<YX, C>);
+
+line_wu!(u8 | i8);
+line_wu!(u16 | i16);
+line_wu!(u32 | i32);
+line_wu!(u64 | i64);
+line_wu!(usize | isize);
+
+/// An iterator over the anti-aliased points of a directed, half-open line segment
+/// with an arbitrary slope. W stands for Xiaolin Wu.
+///
+/// Yields `(x, y, coverage)`, where `coverage` is the intensity of the point
+/// scaled to `0..=255`. Each step along the major axis yields up to two points
+/// straddling the ideal segment, whose coverages sum up to `255`.
+/// Points with zero coverage are skipped.
+///
+/// # Warning
+///
+/// Internally, the coverage is computed in an integer twice the size of the coordinate type.
+/// Keep this in mind when using `u64`, `i64`, `usize` and `isize` coordinates.
+/// If you do not need the whole range, prefer smaller coordinate types.
+///
+/// [`Iterator::fold`] is implemented to forward to the underlying variant,
+/// thus using [`Iterator::for_each`] might be faster than a for loop.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum LineW<C: Coord> {
+    /// See [`LineWx`].
+    Wx(LineWx<C>),
+    /// See [`LineWy`].
+    Wy(LineWy<C>),
+}
+
+macro_rules! line_w {
+    ($Cu:ty | $Ci:ty) => {
+        line_w!($Cu, <$Cu as Coord>::U);
+        line_w!($Ci, <$Ci as Coord>::U);
+    };
+    ($C:ty, $U:ty) => {
+        impl LineW<$C> {
+            /// Returns a [`LineW`] over a directed, half-open line segment `(x0, y0) -> (x1, y1)`.
+            #[inline]
+            pub const fn new(x0: $C, y0: $C, x1: $C, y1: $C) -> Self {
+                let (dx, sx) = ops::<$C>::susub(x1, x0);
+                let (dy, sy) = ops::<$C>::susub(y1, y0);
+                let (x_min, x_max) = (ops::<$C>::min(x0, x1), ops::<$C>::max(x0, x1));
+                let (y_min, y_max) = (ops::<$C>::min(y0, y1), ops::<$C>::max(y0, y1));
+                if dx < dy {
+                    let (u0, v0, u1, du, dv, su, sv) = (y0, x0, y1, dy, dx, sy, sx);
+                    let (v_min, v_max) = (x_min, x_max);
+                    Self::Wy(LineWy {
+                        u0,
+                        v0,
+                        du,
+                        dv,
+                        rem: 0,
+                        u1,
+                        v_min,
+                        v_max,
+                        su,
+                        sv,
+                        second: false,
+                    })
+                } else {
+                    let (u0, v0, u1, du, dv, su, sv) = (x0, y0, x1, dx, dy, sx, sy);
+                    let (v_min, v_max) = (y_min, y_max);
+                    Self::Wx(LineWx {
+                        u0,
+                        v0,
+                        du,
+                        dv,
+                        rem: 0,
+                        u1,
+                        v_min,
+                        v_max,
+                        su,
+                        sv,
+                        second: false,
+                    })
+                }
+            }
+
+            /// Returns `true` if the iterator is empty.
+            #[inline]
+            pub const fn is_empty(&self) -> bool {
+                match self {
+                    Self::Wx(line) => line.is_empty(),
+                    Self::Wy(line) => line.is_empty(),
+                }
+            }
+
+            /// Returns the remaining number of steps along the major axis.
+            ///
+            /// Each step yields one or two points.
+            #[inline]
+            pub const fn steps(&self) -> $U {
+                match self {
+                    Self::Wx(line) => line.steps(),
+                    Self::Wy(line) => line.steps(),
+                }
+            }
+
+            /// Returns the point at the start of the iterator.
+            /// This does not advance the iterator.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn head(&self) -> Option<($C, $C, u8)> {
+                match self {
+                    Self::Wx(line) => line.head(),
+                    Self::Wy(line) => line.head(),
+                }
+            }
+
+            /// Consumes and returns the point at the start of the iterator.
+            /// This advances the iterator forwards.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn pop_head(&mut self) -> Option<($C, $C, u8)> {
+                match self {
+                    Self::Wx(line) => line.pop_head(),
+                    Self::Wy(line) => line.pop_head(),
+                }
+            }
+        }
+
+        impl Iterator for LineW<$C> {
+            type Item = ($C, $C, u8);
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.pop_head()
+            }
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match self {
+                    Self::Wx(line) => line.size_hint(),
+                    Self::Wy(line) => line.size_hint(),
+                }
+            }
+            #[inline]
+            fn fold<B, F>(self, accum: B, f: F) -> B
+            where
+                Self: Sized,
+                F: FnMut(B, Self::Item) -> B,
+            {
+                match self {
+                    Self::Wx(line) => line.fold(accum, f),
+                    Self::Wy(line) => line.fold(accum, f),
+                }
+            }
+        }
+
+        impl core::iter::FusedIterator for LineW<$C> {}
+    };
+}
+
+clone!([C: Coord] LineW<C> {Wx, Wy});
+
+line_w!(u8 | i8);
+line_w!(u16 | i16);
+line_w!(u32 | i32);
+line_w!(u64 | i64);
+line_w!(usize | isize);
@@ -8,6 +8,27 @@ macro_rules! clone {
             }
         }
     };
+    (
+        [$($generics:tt)+] $ty:ty,
+        fields = [$($field:ident),+]$(,
+        options = [$($option:ident),+])?
+    ) => {
+        impl<$($generics)*> $ty {
+            /// Clones this iterator.
+            #[inline]
+            #[allow(clippy::needless_update)]
+            pub const fn clone(&self) -> Self {
+                Self {
+                    $($field: self.$field.clone(),)+
+                    $($($option: match &self.$option {
+                        Some(v) => Some(v.clone()),
+                        None => None,
+                    },)+)?
+                    ..*self
+                }
+            }
+        }
+    };
     ([$($generics:tt)+] $ty:ty {$($var:ident),+}) => {
         impl<$($generics)*> $ty {
             /// Clones this iterator.
@@ -29,9 +50,11 @@ macro_rules! iter_methods {
         fn is_empty = $is_empty:expr,
         fn len = $len:expr,
         fn head = $head:expr,
-        fn pop_head = $pop_head:expr$(,
+        fn pop_head = $pop_head:expr,
         fn tail = $tail:expr,
-        fn pop_tail = $pop_tail:expr)?
+        fn pop_tail = $pop_tail:expr,
+        fn advance($n:ident) = $advance:expr,
+        fn truncate($m:ident) = $truncate:expr
     ) => {
         /// Returns `true` if the iterator is empty.
         #[inline]
@@ -53,7 +76,7 @@ macro_rules! iter_methods {
         ///
         /// Returns [`None`] if the iterator is empty.
         #[inline]
-        pub const fn pop_head(&mut $self) -> Option<($C, $C)> { $pop_head }$(
+        pub const fn pop_head(&mut $self) -> Option<($C, $C)> { $pop_head }
 
         /// Returns the last point of the iterator.
         /// This does not advance the iterator.
@@ -72,102 +95,155 @@ macro_rules! iter_methods {
         ///
         /// Returns [`None`] if the iterator is empty.
         #[inline]
-        pub const fn pop_tail(&mut $self) -> Option<($C, $C)> { $pop_tail })?
+        pub const fn pop_tail(&mut $self) -> Option<($C, $C)> { $pop_tail }
+
+        /// Returns the point at index `i` of the iterator.
+        /// This does not advance the iterator.
+        ///
+        /// Returns [`None`] if `i` is out of bounds.
+        #[inline]
+        pub const fn point_at(&$self, i: $U) -> Option<($C, $C)> {
+            if Self::len($self) <= i {
+                return None;
+            }
+            let mut line = $self.clone();
+            line.advance_unchecked(i);
+            line.head()
+        }
+
+        /// Advances the iterator forwards by `n` points in constant time.
+        ///
+        /// Returns `Err(k)` if the iterator ran out of points,
+        /// where `k` is the number of points that could not be skipped.
+        #[inline]
+        pub const fn advance_by(&mut $self, n: $U) -> Result<(), core::num::NonZero<$U>> {
+            let len = Self::len($self);
+            if len < n {
+                $self.advance_unchecked(len);
+                return match core::num::NonZero::new(n - len) {
+                    Some(k) => Err(k),
+                    None => Ok(()),
+                };
+            }
+            $self.advance_unchecked(n);
+            Ok(())
+        }
+
+        /// Advances the iterator backwards by `n` points in constant time.
+        ///
+        /// Returns `Err(k)` if the iterator ran out of points,
+        /// where `k` is the number of points that could not be skipped.
+        #[inline]
+        pub const fn advance_back_by(&mut $self, n: $U) -> Result<(), core::num::NonZero<$U>> {
+            let len = Self::len($self);
+            if len < n {
+                $self.truncate_unchecked(0);
+                return match core::num::NonZero::new(n - len) {
+                    Some(k) => Err(k),
+                    None => Ok(()),
+                };
+            }
+            $self.truncate_unchecked(len - n);
+            Ok(())
+        }
+
+        /// Splits the iterator into two at index `i` in constant time.
+        ///
+        /// The first iterator covers the points `[0, i)`, and the second covers `[i, len)`.
+        /// Chaining them yields the same points as the original iterator.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `i > len`.
+        #[inline]
+        pub const fn split_at($self, i: $U) -> (Self, Self) {
+            assert!(i <= Self::len(&$self), "split index out of bounds");
+            let mut head = $self.clone();
+            head.truncate_unchecked(i);
+            let mut tail = $self;
+            tail.advance_unchecked(i);
+            (head, tail)
+        }
+
+        /// Advances the iterator forwards by `n <= len` points.
+        #[inline]
+        const fn advance_unchecked(&mut $self, $n: $U) { $advance }
+
+        /// Shortens the iterator to `m <= len` points.
+        #[inline]
+        const fn truncate_unchecked(&mut $self, $m: $U) { $truncate }
     };
 }
 
-macro_rules! iter_fwd {
+macro_rules! iter_line {
     (
-        $Line:ident<$(const $YX:ident,)? $C:ty>$(,
-        fn fold($self:ident, $accum:ident, $f:ident) = $fold:expr)?
+        $Line:ident<$(const $P:ident: $T:ty,)? $C:ty>$(,
+        fn fold($line:ident, $accum:ident, $f:ident) = $fold:expr,
+        fn rfold($rline:ident, $raccum:ident, $rf:ident) = $rfold:expr)?$(,
+        exact = [$($ptr_size:literal),+])?
     ) => {
-        impl<$(const $YX: bool)?> Iterator for $Line<$($YX,)? $C> {
-            type Item = ($C, $C);
-            #[inline]
-            fn next(&mut self) -> Option<Self::Item> {
-                self.pop_head()
-            }
-            #[inline]
-            fn size_hint(&self) -> (usize, Option<usize>) {
-                let len = usize::from(self.len());
-                (len, Some(len))
-            }$(
-            #[inline]
-            fn fold<B, F>($self, $accum: B, $f: F) -> B
-            where
-                Self: Sized,
-                F: FnMut(B, Self::Item) -> B
-            {
-                $fold
-            })?
-        }
-        impl<$(const $YX: bool)?> ExactSizeIterator for $Line<$($YX,)? $C> {}
-        impl<$(const $YX: bool)?> core::iter::FusedIterator for $Line<$($YX,)? $C> {}
+        iter_line!(
+            @impl [$(const $P: $T)?] $Line<$($P,)? $C>, $C$(,
+            fn fold($line, $accum, $f) = $fold,
+            fn rfold($rline, $raccum, $rf) = $rfold)?$(,
+            exact = [$($ptr_size),+])?
+        );
     };
     (
-        $Line:ident<$(const $YX:ident,)? $C:ty>$(,
-        fn fold($self:ident, $accum:ident, $f:ident) = $fold:expr)?,
-        exact = [$($ptr_size:literal),+]
+        @impl [$($generics:tt)*] $Self:ty, $C:ty$(,
+        fn fold($line:ident, $accum:ident, $f:ident) = $fold:expr,
+        fn rfold($rline:ident, $raccum:ident, $rf:ident) = $rfold:expr)?$(,
+        exact = [$($ptr_size:literal),+])?
     ) => {
-        impl<$(const $YX: bool)?> Iterator for $Line<$($YX,)? $C> {
-            type Item = ($C, $C);
+        impl<$($generics)*> crate::raster_line::LineIter<$Self> for $C {
             #[inline]
-            fn next(&mut self) -> Option<Self::Item> {
-                self.pop_head()
+            fn next(line: &mut $Self) -> Option<($C, $C)> {
+                <$Self>::pop_head(line)
             }
-            #[cfg(any($(target_pointer_width = $ptr_size),+))]
             #[inline]
-            fn size_hint(&self) -> (usize, Option<usize>) {
-                let len = usize::try_from(self.len()).expect("cannot overflow");
-                (len, Some(len))
+            fn nth(line: &mut $Self, n: usize) -> Option<($C, $C)> {
+                let n = n.try_into().unwrap_or(<$Self>::len(line));
+                <$Self>::advance_by(line, n).ok()?;
+                <$Self>::pop_head(line)
             }
-            #[cfg(not(any($(target_pointer_width = $ptr_size),+)))]
             #[inline]
-            fn size_hint(&self) -> (usize, Option<usize>) {
-                if let Ok(len) = usize::try_from(self.len()) {
-                    (len, Some(len))
-                } else {
-                    (usize::MAX, None)
+            fn size_hint(line: &$Self) -> (usize, Option<usize>) {
+                match usize::try_from(<$Self>::len(line)) {
+                    Ok(len) => (len, Some(len)),
+                    Err(_) => (usize::MAX, None),
                 }
+            }
+            #[inline]
+            fn next_back(line: &mut $Self) -> Option<($C, $C)> {
+                <$Self>::pop_tail(line)
+            }
+            #[inline]
+            fn nth_back(line: &mut $Self, n: usize) -> Option<($C, $C)> {
+                let n = n.try_into().unwrap_or(<$Self>::len(line));
+                <$Self>::advance_back_by(line, n).ok()?;
+                <$Self>::pop_tail(line)
             }$(
             #[inline]
-            fn fold<B, F>($self, $accum: B, $f: F) -> B
+            fn fold<B, F>($line: $Self, $accum: B, $f: F) -> B
             where
-                Self: Sized,
-                F: FnMut(B, Self::Item) -> B
+                F: FnMut(B, ($C, $C)) -> B,
             {
                 $fold
-            })?
-        }
-        #[cfg(any($(target_pointer_width = $ptr_size),+))]
-        impl<$(const $YX: bool)?> ExactSizeIterator for $Line<$($YX,)? $C> {}
-        impl<$(const $YX: bool)?> core::iter::FusedIterator for $Line<$($YX,)? $C> {}
-    };
-}
-
-macro_rules! iter_rev {
-    (
-        $Line:ident<$(const $YX:ident,)? $C:ty>$(,
-        fn rfold($self:ident, $accum:ident, $f:ident) = $rfold:expr)?
-    ) => {
-        impl<$(const $YX: bool)?> DoubleEndedIterator for $Line<$($YX,)? $C> {
-            #[inline]
-            fn next_back(&mut self) -> Option<Self::Item> {
-                self.pop_tail()
-            }$(
+            }
             #[inline]
-            fn rfold<B, F>($self, $accum: B, $f: F) -> B
+            fn rfold<B, F>($rline: $Self, $raccum: B, $rf: F) -> B
             where
-                Self: Sized,
-                F: FnMut(B, Self::Item) -> B,
+                F: FnMut(B, ($C, $C)) -> B,
             {
                 $rfold
             })?
         }
+        $(#[cfg(any($(target_pointer_width = $ptr_size),+))])?
+        impl<$($generics)*> ExactSizeIterator for $Self {}
     };
 }
 
-pub(crate) use {clone, iter_fwd, iter_methods, iter_rev};
+pub(crate) use {clone, iter_line, iter_methods};
 
 #[rustfmt::skip]
 macro_rules! if_unsigned {
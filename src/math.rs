@@ -1,7 +1,19 @@
+use crate::line_a::{LineA, LineAx, LineAy};
+use crate::line_b::{LineB, LineBx, LineBy};
+use crate::line_d::{LineD, LineD2};
 use crate::macros::*;
+use crate::raster_line::LineCoord;
 
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A primitive integer type.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
 pub trait Num
 where
+    Self: sealed::Sealed,
     Self: Copy + Eq + Ord + Default,
     Self: core::hash::Hash,
     Self: core::fmt::Debug + core::fmt::Display,
@@ -10,17 +22,58 @@ where
 
 macro_rules! num {
     ($($T:ty),+) => {
-        $(impl Num for $T {})+
+        $(
+            impl sealed::Sealed for $T {}
+            impl Num for $T {}
+        )+
     };
 }
 
 num!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize);
 
-pub trait Coord: Num {
+/// A coordinate type: `u8`, `i8`, `u16`, `i16`, `u32`, `i32`, `u64`, `i64`, `usize` or `isize`.
+///
+/// Most methods of this crate are defined separately for each coordinate type,
+/// so they are available in `const` contexts. Code generic over `C: Coord` can construct
+/// and clip any [`RasterLine`](crate::RasterLine) over `C` instead, e.g. with
+/// [`Clip::clip_line`](crate::Clip::clip_line).
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+///
+/// # Example
+///
+/// ```
+/// use clipline::{Clip, Coord, LineB};
+///
+/// fn draw<C: Coord>(clip: &Clip<C>, x0: C, y0: C, x1: C, y1: C, pixel: impl FnMut((C, C))) {
+///     if let Some(line) = clip.clip_line::<LineB<C>>(x0, y0, x1, y1) {
+///         line.for_each(pixel);
+///     }
+/// }
+///
+/// let mut points = Vec::new();
+/// draw(&Clip::<i16>::from_max(3, 3).unwrap(), -2, -2, 8, 8, |p| points.push(p));
+/// assert_eq!(points, [(0, 0), (1, 1), (2, 2), (3, 3)]);
+/// let mut points = Vec::new();
+/// draw(&Clip::<u32>::from_max(3, 3), 1, 0, 1, 9, |p| points.push(p));
+/// assert_eq!(points, [(1, 0), (1, 1), (1, 2), (1, 3)]);
+/// ```
+pub trait Coord
+where
+    Self: Num,
+    Self: LineCoord<LineA<Self>> + LineCoord<LineAx<Self>> + LineCoord<LineAy<Self>>,
+    Self: LineCoord<LineB<Self>> + LineCoord<LineBx<Self>> + LineCoord<LineBy<Self>>,
+    Self: LineCoord<LineD<Self>> + LineCoord<LineD2<Self>>,
+{
+    /// The unsigned integer type of the same size, used for lengths and offsets.
     type U: Num;
+    /// The signed integer type of the same size.
     type I: Num;
+    /// The unsigned integer type of twice the size.
     type U2: Num;
+    /// The signed integer type of twice the size.
     type I2: Num;
+    /// Zero.
     const ZERO: Self;
 }
 
@@ -112,6 +165,41 @@ macro_rules! coord_ops {
                     (Self::usub(rhs, lhs), -1)
                 }
             }
+            /// Returns `Σ_{i < n} i` in wrapping arithmetic.
+            #[inline]
+            pub const fn pairs(n: <$UI as Coord>::U2) -> <$UI as Coord>::U2 {
+                if n % 2 == 0 {
+                    (n / 2).wrapping_mul(n.wrapping_sub(1))
+                } else {
+                    n.wrapping_mul(n / 2)
+                }
+            }
+            /// Returns `Σ_{i < n} ⌊(a * i + b) / m⌋` in wrapping arithmetic,
+            /// given that `m` and `n` fit into the unsigned type of the coordinate type.
+            #[inline]
+            pub const fn floor_sum(
+                mut n: <$UI as Coord>::U2,
+                mut m: <$UI as Coord>::U2,
+                mut a: <$UI as Coord>::U2,
+                mut b: <$UI as Coord>::U2,
+            ) -> <$UI as Coord>::U2 {
+                let mut sum: <$UI as Coord>::U2 = 0;
+                loop {
+                    if m <= a {
+                        sum = sum.wrapping_add(Self::pairs(n).wrapping_mul(a / m));
+                        a %= m;
+                    }
+                    if m <= b {
+                        sum = sum.wrapping_add(n.wrapping_mul(b / m));
+                        b %= m;
+                    }
+                    let y = a * n + b;
+                    if y < m {
+                        return sum;
+                    }
+                    (n, b, m, a) = (y / m, y % m, a, m);
+                }
+            }
         }
     };
     ($signedness:ident $UI:ty, $U:ty, $I:ty) => {
@@ -165,6 +253,22 @@ macro_rules! coord_ops {
                 })
             }
             #[inline]
+            pub const fn chsub_u(lhs: $UI, rhs: $U) -> Option<$UI> {
+                if_unsigned!($signedness {
+                    lhs.checked_sub(rhs)
+                } else {
+                    lhs.checked_sub_unsigned(rhs)
+                })
+            }
+            #[inline]
+            pub const fn chadd_i(lhs: $UI, rhs: $I) -> Option<$UI> {
+                if_unsigned!($signedness {
+                    lhs.checked_add_signed(rhs)
+                } else {
+                    lhs.checked_add(rhs)
+                })
+            }
+            #[inline]
             pub const fn wadd_i(lhs: $UI, rhs: $I) -> $UI {
                 if_unsigned!($signedness {
                     lhs.wrapping_add_signed(rhs)
@@ -0,0 +1,325 @@
+use crate::macros::*;
+use crate::math::Coord;
+
+/// The rule deciding which points are inside a [`PolygonFill`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum FillRule {
+    /// A point is inside if a ray from it crosses the edges an odd number of times.
+    #[default]
+    EvenOdd,
+    /// A point is inside if the edges wind around it a non-zero number of times.
+    NonZero,
+}
+
+/// An iterator over the horizontal spans of a filled polygon.
+///
+/// Yields `(y, x_start, x_end)`, where `x_start <= x_end` are both inside the span.
+/// Rows are yielded in increasing order of `y`, and spans within a row
+/// in increasing order of `x`. Spans in the same row are separated by at least one point.
+///
+/// The polygon is made of the closed [Bresenham][crate::LineB] segments
+/// from each vertex to the next one, and from the last vertex back to the first one,
+/// so the fill covers all points of the closed [`Polyline`](crate::Polyline)
+/// over the same vertices. Between the edges, each row is filled according to the [`FillRule`],
+/// counting the edges that span the row, excluding their last row from the top.
+///
+/// # Performance
+///
+/// Each span takes time quadratic in the number of vertices in the worst case.
+/// Rows without spans are skipped up to the next row where an edge starts, ends
+/// or reaches the clipped columns, so the height of the polygon does not matter.
+///
+/// # Warning
+///
+/// Internally, this uses integers twice the size of the coordinate type.
+/// Keep this in mind when using `u64`, `i64`, `usize` and `isize` coordinates.
+/// If you do not need the whole range, prefer smaller coordinate types.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct PolygonFill<'a, C: Coord> {
+    pub(crate) points: &'a [(C, C)],
+    pub(crate) rule: FillRule,
+    pub(crate) y: C::I2,
+    pub(crate) y1: C::I2,
+    /// The current span.
+    pub(crate) xa: C::I2,
+    pub(crate) xb: C::I2,
+    pub(crate) c0: C::I2,
+    pub(crate) c1: C::I2,
+}
+
+macro_rules! polygon_fill {
+    ($Cu:ty | $Ci:ty) => {
+        polygon_fill!($Cu, <$Cu as Coord>::U, <$Cu as Coord>::U2, <$Cu as Coord>::I2);
+        polygon_fill!($Ci, <$Ci as Coord>::U, <$Ci as Coord>::U2, <$Ci as Coord>::I2);
+    };
+    ($C:ty, $U:ty, $U2:ty, $I2:ty) => {
+        impl<'a> PolygonFill<'a, $C> {
+            /// Returns a [`PolygonFill`] over the polygon with the given vertices,
+            /// filled according to the `rule`.
+            #[inline]
+            pub const fn new(points: &'a [($C, $C)], rule: FillRule) -> Self {
+                let (min, max) = (<$C>::MIN as $I2, <$C>::MAX as $I2);
+                Self::raw(points, rule, (min, max), (min, max))
+            }
+
+            /// Returns a [`PolygonFill`] restricted to the closed ranges `rows` and `cols`.
+            #[inline]
+            pub(crate) const fn raw(
+                points: &'a [($C, $C)],
+                rule: FillRule,
+                rows: ($I2, $I2),
+                cols: ($I2, $I2),
+            ) -> Self {
+                let (mut y0, mut y1) = (rows.1 + 1, rows.0 - 1);
+                let mut i = 0;
+                while i < points.len() {
+                    let y = points[i].1 as $I2;
+                    if y < y0 {
+                        y0 = y;
+                    }
+                    if y1 < y {
+                        y1 = y;
+                    }
+                    i += 1;
+                }
+                let y0 = if y0 < rows.0 { rows.0 } else { y0 };
+                let y1 = if rows.1 < y1 { rows.1 } else { y1 };
+                let mut fill =
+                    Self { points, rule, y: y0, y1, xa: 0, xb: 0, c0: cols.0, c1: cols.1 };
+                fill.seek(cols.0);
+                fill
+            }
+
+            /// Returns the closed range of columns of edge `i` in row `y`.
+            ///
+            /// The edge covers the same points as [`LineB`](crate::LineB)
+            /// over the closed segment from vertex `i` to the next one.
+            #[inline]
+            const fn run(&self, i: usize, y: $I2) -> Option<($I2, $I2)> {
+                let (x0, y0) = self.points[i];
+                let (x1, y1) = self.points[(i + 1) % self.points.len()];
+                let (x0, y0, x1, y1) = (x0 as $I2, y0 as $I2, x1 as $I2, y1 as $I2);
+                if y < y0 && y < y1 || y0 < y && y1 < y {
+                    return None;
+                }
+                let (dx, sx) =
+                    if x0 <= x1 { ((x1 - x0) as $U2, 1) } else { ((x0 - x1) as $U2, -1) };
+                let (dy, t) = if y0 <= y1 {
+                    ((y1 - y0) as $U2, (y - y0) as $U2)
+                } else {
+                    ((y0 - y1) as $U2, (y0 - y) as $U2)
+                };
+                if dx < dy {
+                    let x = x0 + sx * ((dy / 2 + t * dx) / dy) as $I2;
+                    return Some((x, x));
+                }
+                if dy == 0 {
+                    return Some(if x0 <= x1 { (x0, x1) } else { (x1, x0) });
+                }
+                // The minor coordinate after n steps is floor((dx / 2 + n * dy) / dx).
+                let h = dx / 2;
+                let lo = if t * dx < h { 0 } else { (t * dx - h).div_ceil(dy) };
+                let hi = ((t + 1) * dx - h).div_ceil(dy) - 1;
+                let hi = if dx < hi { dx } else { hi };
+                let (xa, xb) = (x0 + sx * lo as $I2, x0 + sx * hi as $I2);
+                Some(if xa <= xb { (xa, xb) } else { (xb, xa) })
+            }
+
+            /// Returns the closed range of rows in which the run of edge `i`
+            /// reaches the columns `[c0, c1]`, or [`None`] if there are none.
+            #[inline]
+            const fn visible(&self, i: usize) -> Option<($I2, $I2)> {
+                let (x0, y0) = self.points[i];
+                let (x1, y1) = self.points[(i + 1) % self.points.len()];
+                let (x0, y0, x1, y1) = (x0 as $I2, y0 as $I2, x1 as $I2, y1 as $I2);
+                let (dx, dy) = ((x1 - x0).unsigned_abs(), (y1 - y0).unsigned_abs());
+                // The steps `na..=nb` along `X` from `x0` lie within the columns.
+                let (na, nb) = if x0 <= x1 {
+                    (self.c0 - x0, self.c1 - x0)
+                } else {
+                    (x0 - self.c1, x0 - self.c0)
+                };
+                let na = if na < 0 { 0 } else { na as $U2 };
+                if nb < 0 || dx < na {
+                    return None;
+                }
+                let nb = if dx < nb as $U2 { dx } else { nb as $U2 };
+                let (ta, tb) = if dy == 0 {
+                    (0, 0)
+                } else if dx < dy {
+                    // The step along `X` after t rows is floor((dy / 2 + t * dx) / dy).
+                    let h = dy / 2;
+                    let ta = if na == 0 { 0 } else { (na * dy - h).div_ceil(dx) };
+                    let tb = if nb == dx { dy } else { ((nb + 1) * dy - h).div_ceil(dx) - 1 };
+                    (ta, tb)
+                } else {
+                    // The row after n steps along `X` is floor((dx / 2 + n * dy) / dx).
+                    let h = dx / 2;
+                    ((h + na * dy) / dx, (h + nb * dy) / dx)
+                };
+                Some(if y0 <= y1 {
+                    (y0 + ta as $I2, y0 + tb as $I2)
+                } else {
+                    (y0 - tb as $I2, y0 - ta as $I2)
+                })
+            }
+
+            /// Returns the first row after `y` in which the filling may reach
+            /// the columns `[c0, c1]`, given that it does not in row `y`.
+            ///
+            /// Until an edge starts, ends or its run reaches the columns,
+            /// each row is filled within the columns exactly like row `y`.
+            #[inline]
+            const fn next_row(&self, y: $I2) -> $I2 {
+                let mut next = self.y1 + 1;
+                let mut i = 0;
+                while i < self.points.len() {
+                    let y0 = self.points[i].1 as $I2;
+                    let y1 = self.points[(i + 1) % self.points.len()].1 as $I2;
+                    let (lo, hi) = if y0 <= y1 { (y0, y1) } else { (y1, y0) };
+                    let enter = match self.visible(i) {
+                        Some((ya, _)) => ya,
+                        None => lo,
+                    };
+                    let events = [lo, enter, hi, hi + 1];
+                    let mut k = 0;
+                    while k < events.len() {
+                        if y < events[k] && events[k] < next {
+                            next = events[k];
+                        }
+                        k += 1;
+                    }
+                    i += 1;
+                }
+                next
+            }
+
+            /// Returns `true` if the point `(x, y)` is filled.
+            #[inline]
+            const fn covers(&self, x: $I2, y: $I2) -> bool {
+                let mut winding: $I2 = 0;
+                let mut i = 0;
+                while i < self.points.len() {
+                    if let Some((l, r)) = self.run(i, y) {
+                        if l <= x && x <= r {
+                            return true;
+                        }
+                        let y0 = self.points[i].1 as $I2;
+                        let y1 = self.points[(i + 1) % self.points.len()].1 as $I2;
+                        if r < x && y0 != y1 && (y < y0 || y < y1) {
+                            winding += if y0 < y1 { 1 } else { -1 };
+                        }
+                    }
+                    i += 1;
+                }
+                match self.rule {
+                    FillRule::EvenOdd => winding % 2 != 0,
+                    FillRule::NonZero => winding != 0,
+                }
+            }
+
+            /// Returns the first column after `x` where the filling may change in row `y`.
+            #[inline]
+            const fn next_break(&self, x: $I2, y: $I2) -> Option<$I2> {
+                let mut next = None;
+                let mut i = 0;
+                while i < self.points.len() {
+                    if let Some((l, r)) = self.run(i, y) {
+                        let b = if x < l { l } else { r + 1 };
+                        if x < b {
+                            next = match next {
+                                Some(n) if n <= b => Some(n),
+                                _ => Some(b),
+                            };
+                        }
+                    }
+                    i += 1;
+                }
+                next
+            }
+
+            /// Moves to the first span in or after the current row starting at `x` or later.
+            #[inline]
+            const fn seek(&mut self, mut x: $I2) {
+                while self.y <= self.y1 {
+                    let fresh = x == self.c0;
+                    while x <= self.c1 {
+                        if self.covers(x, self.y) {
+                            let mut end = x;
+                            while let Some(b) = self.next_break(end, self.y) {
+                                if !self.covers(b, self.y) {
+                                    end = b - 1;
+                                    break;
+                                }
+                                end = b;
+                            }
+                            self.xa = x;
+                            self.xb = if self.c1 < end { self.c1 } else { end };
+                            return;
+                        }
+                        x = match self.next_break(x, self.y) {
+                            Some(b) => b,
+                            None => break,
+                        };
+                    }
+                    self.y = if fresh { self.next_row(self.y) } else { self.y + 1 };
+                    x = self.c0;
+                }
+            }
+
+            /// Returns the fill rule of this iterator.
+            #[inline]
+            pub const fn rule(&self) -> FillRule {
+                self.rule
+            }
+
+            /// Returns `true` if the iterator is empty.
+            #[inline]
+            pub const fn is_empty(&self) -> bool {
+                self.y1 < self.y
+            }
+
+            /// Returns the span at the start of the iterator.
+            /// This does not advance the iterator.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn head(&self) -> Option<($C, $C, $C)> {
+                if self.is_empty() {
+                    return None;
+                }
+                Some((self.y as $C, self.xa as $C, self.xb as $C))
+            }
+
+            /// Consumes and returns the span at the start of the iterator.
+            /// This advances the iterator forwards.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn pop_head(&mut self) -> Option<($C, $C, $C)> {
+                let head = try_opt!(self.head());
+                // The point after a span is never filled.
+                self.seek(self.xb + 2);
+                Some(head)
+            }
+        }
+
+        impl Iterator for PolygonFill<'_, $C> {
+            type Item = ($C, $C, $C);
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.pop_head()
+            }
+        }
+
+        impl core::iter::FusedIterator for PolygonFill<'_, $C> {}
+    };
+}
+
+clone!(['a, C: Coord] PolygonFill<'a, C>);
+
+polygon_fill!(u8 | i8);
+polygon_fill!(u16 | i16);
+polygon_fill!(u32 | i32);
+polygon_fill!(u64 | i64);
+polygon_fill!(usize | isize);
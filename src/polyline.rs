@@ -0,0 +1,181 @@
+use crate::clip::Viewport;
+use crate::line_b::LineB;
+use crate::macros::*;
+use crate::math::Coord;
+
+/// An iterator over the rasterized points of a polyline made of [`LineB`] segments.
+///
+/// Each segment is directed and half-open, so the vertex shared by two consecutive segments
+/// is yielded once, as the first point of the latter. The last vertex of an open polyline
+/// is yielded at the end. A closed polyline is also connected from its last vertex
+/// back to the first one, which is yielded at the start. If all vertices coincide,
+/// either polyline yields that point once.
+///
+/// Points shared by non-consecutive segments, e.g. at self-intersections,
+/// are yielded once per segment.
+///
+/// # Warning
+///
+/// Internally, this uses an error accumulator twice the size of the coordinate type.
+/// Keep this in mind when using `u64`, `i64`, `usize` and `isize` coordinates.
+/// If you do not need the whole range, prefer smaller coordinate types.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Polyline<'a, C: Coord> {
+    pub(crate) points: &'a [(C, C)],
+    pub(crate) line: LineB<C>,
+    /// The index of the end vertex of the next segment.
+    pub(crate) i: usize,
+    /// The number of segments.
+    pub(crate) n: usize,
+    /// Whether the last vertex is yet to be yielded.
+    pub(crate) end: bool,
+    pub(crate) region: Option<Viewport<C>>,
+}
+
+macro_rules! polyline {
+    ($Cu:ty | $Ci:ty) => {
+        polyline!($Cu);
+        polyline!($Ci);
+    };
+    ($C:ty) => {
+        impl<'a> Polyline<'a, $C> {
+            /// Returns a [`Polyline`] over the segments connecting consecutive `points`,
+            /// with the last point connected back to the first one if `closed`.
+            #[inline]
+            pub const fn new(points: &'a [($C, $C)], closed: bool) -> Self {
+                Self::raw(points, closed, None)
+            }
+
+            /// Returns a [`Polyline`] whose segments are clipped to the `region`, if any.
+            #[inline]
+            pub(crate) const fn raw(
+                points: &'a [($C, $C)],
+                closed: bool,
+                region: Option<Viewport<$C>>,
+            ) -> Self {
+                let n = if closed { points.len() } else { points.len().saturating_sub(1) };
+                // A closed polyline of coincident vertices has only empty segments,
+                // so its point is yielded as the last vertex, as in an open polyline.
+                let end = match (points.last(), &region) {
+                    (Some(_), _) if closed && !Self::coincident(points) => false,
+                    (Some(&(x, y)), Some(region)) => region.point(x, y),
+                    (Some(_), None) => true,
+                    (None, _) => false,
+                };
+                let line = LineB::<$C>::new(0, 0, 0, 0);
+                let mut polyline = Self { points, line, i: 1, n, end, region };
+                polyline.seek();
+                polyline
+            }
+
+            /// Returns `true` if all `points` are the same.
+            #[inline]
+            const fn coincident(points: &[($C, $C)]) -> bool {
+                let mut i = 1;
+                while i < points.len() {
+                    if points[i].0 != points[0].0 || points[i].1 != points[0].1 {
+                        return false;
+                    }
+                    i += 1;
+                }
+                true
+            }
+
+            /// Moves to the next non-empty segment, if any.
+            #[inline]
+            const fn seek(&mut self) {
+                while self.i <= self.n {
+                    let (x0, y0) = self.points[self.i - 1];
+                    let (x1, y1) = self.points[self.i % self.points.len()];
+                    self.i += 1;
+                    let line = match &self.region {
+                        Some(region) => region.line_b(x0, y0, x1, y1),
+                        None => Some(LineB::<$C>::new(x0, y0, x1, y1)),
+                    };
+                    if let Some(line) = line {
+                        if !line.is_empty() {
+                            self.line = line;
+                            return;
+                        }
+                    }
+                }
+            }
+
+            /// Returns `true` if the iterator is empty.
+            #[inline]
+            pub const fn is_empty(&self) -> bool {
+                self.line.is_empty() && !self.end
+            }
+
+            /// Returns the point at the start of the iterator.
+            /// This does not advance the iterator.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn head(&self) -> Option<($C, $C)> {
+                if !self.line.is_empty() {
+                    return self.line.head();
+                }
+                if !self.end {
+                    return None;
+                }
+                match self.points.last() {
+                    Some(&point) => Some(point),
+                    None => None,
+                }
+            }
+
+            /// Consumes and returns the point at the start of the iterator.
+            /// This advances the iterator forwards.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn pop_head(&mut self) -> Option<($C, $C)> {
+                if self.line.is_empty() {
+                    let head = self.head();
+                    self.end = false;
+                    return head;
+                }
+                let head = self.line.pop_head();
+                if self.line.is_empty() {
+                    self.seek();
+                }
+                head
+            }
+        }
+
+        impl Iterator for Polyline<'_, $C> {
+            type Item = ($C, $C);
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.pop_head()
+            }
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = usize::try_from(self.line.len()).ok();
+                let end = usize::from(self.end);
+                let lo = len.map_or(usize::MAX, |len| len.saturating_add(end));
+                // Each of the remaining segments yields at most `U::MAX` points.
+                let rest = self.n + 1 - self.i;
+                let hi = match (len, usize::try_from(<<$C as Coord>::U>::MAX)) {
+                    (Some(len), Ok(max)) => rest
+                        .checked_mul(max)
+                        .and_then(|hi| hi.checked_add(len))
+                        .and_then(|hi| hi.checked_add(end)),
+                    _ => None,
+                };
+                (lo, hi)
+            }
+        }
+
+        impl core::iter::FusedIterator for Polyline<'_, $C> {}
+    };
+}
+
+clone!(['a, C: Coord] Polyline<'a, C>, fields = [line], options = [region]);
+
+polyline!(u8 | i8);
+polyline!(u16 | i16);
+polyline!(u32 | i32);
+polyline!(u64 | i64);
+polyline!(usize | isize);
@@ -0,0 +1,481 @@
+use crate::clip::{Clip, Viewport};
+use crate::line_a::{LineA, LineAu, LineAx, LineAy};
+use crate::line_b::{LineB, LineBu, LineBx, LineBy};
+use crate::line_bq::LineBq;
+use crate::line_d::{LineD, LineD2};
+use crate::math::Coord;
+
+/// A line iterator over the rasterized points of a directed, half-open line segment
+/// `(x0, y0) -> (x1, y1)`, for code generic over the rasterization algorithm.
+///
+/// This is implemented by [`LineA`], [`LineAx`], [`LineAy`], [`LineB`], [`LineBx`], [`LineBy`],
+/// [`LineD`] and [`LineD2`] for every [`Coord`] type. [`LineBq`] implements it for every
+/// concrete coordinate type, but code generic over `C: Coord` has to require
+/// `LineBq<F, C>: RasterLine<C>` itself. Each associated function and method
+/// forwards to the inherent one of the same name, or to the matching method of
+/// [`Clip`] or [`Viewport`]. [`LineAx`] and [`LineAy`] support only horizontal and vertical
+/// segments, and [`LineBx`] and [`LineBy`] only the segments that [`LineB`] iterates with them.
+/// Use [`Clip::clip_line`] and [`Viewport::clip_line`] to clip a segment with the algorithm
+/// chosen by a type parameter.
+///
+/// # Example
+///
+/// ```
+/// use clipline::{Clip, Coord, LineB, LineD, RasterLine};
+///
+/// fn draw<C: Coord, L: RasterLine<C>>(clip: &Clip<C>, x0: C, y0: C, x1: C, y1: C) -> usize {
+///     clip.clip_line::<L>(x0, y0, x1, y1).map_or(0, Iterator::count)
+/// }
+///
+/// let clip = Clip::<i16>::from_max(63, 63).unwrap();
+/// assert_eq!(draw::<_, LineB<_>>(&clip, -8, 0, 8, 4), 8);
+/// assert_eq!(draw::<_, LineD<_>>(&clip, -8, 0, 8, 4), 0);
+/// ```
+pub trait RasterLine<C: Coord>: Iterator<Item = (C, C)> + Sized {
+    /// Returns an iterator over the directed, half-open line segment `(x0, y0) -> (x1, y1)`,
+    /// or [`None`] if this algorithm does not support the segment.
+    fn new(x0: C, y0: C, x1: C, y1: C) -> Option<Self>;
+
+    /// Clips the directed, half-open line segment `(x0, y0) -> (x1, y1)` to `clip`.
+    ///
+    /// Returns an iterator over the portion of the segment inside the clipping region,
+    /// or [`None`] if the segment is not supported or lies fully outside.
+    fn clip(clip: &Clip<C>, x0: C, y0: C, x1: C, y1: C) -> Option<Self>;
+
+    /// Clips the directed, half-open line segment `(x0, y0) -> (x1, y1)` to `viewport`.
+    ///
+    /// Returns an iterator over the portion of the segment inside the viewport,
+    /// or [`None`] if the segment is not supported or lies fully outside.
+    fn viewport(viewport: &Viewport<C>, x0: C, y0: C, x1: C, y1: C) -> Option<Self>;
+
+    /// Returns `true` if the iterator is empty.
+    fn is_empty(&self) -> bool;
+
+    /// Returns the remaining length of this iterator.
+    fn len(&self) -> C::U;
+
+    /// Returns the point at the start of the iterator.
+    /// This does not advance the iterator.
+    ///
+    /// Returns [`None`] if the iterator is empty.
+    fn head(&self) -> Option<(C, C)>;
+
+    /// Consumes and returns the point at the start of the iterator.
+    /// This advances the iterator forwards.
+    ///
+    /// Returns [`None`] if the iterator is empty.
+    fn pop_head(&mut self) -> Option<(C, C)>;
+
+    /// Returns the last point of the iterator.
+    /// This does not advance the iterator.
+    ///
+    /// Returns [`None`] if the iterator is empty.
+    fn tail(&self) -> Option<(C, C)>;
+
+    /// Consumes and returns the last point of the iterator.
+    /// This advances the iterator backwards.
+    ///
+    /// Returns [`None`] if the iterator is empty.
+    fn pop_tail(&mut self) -> Option<(C, C)>;
+}
+
+/// The implementation of [`Iterator`] and [`DoubleEndedIterator`]
+/// for the line iterator `L` over this coordinate type.
+///
+/// The iterator traits are implemented once for all coordinate types,
+/// and forward to this trait, which is implemented separately for each of them.
+pub trait LineIter<L>: Sized {
+    /// See [`Iterator::next`].
+    fn next(line: &mut L) -> Option<(Self, Self)>;
+    /// See [`Iterator::nth`].
+    fn nth(line: &mut L, n: usize) -> Option<(Self, Self)>;
+    /// See [`Iterator::size_hint`].
+    fn size_hint(line: &L) -> (usize, Option<usize>);
+    /// See [`DoubleEndedIterator::next_back`].
+    fn next_back(line: &mut L) -> Option<(Self, Self)>;
+    /// See [`DoubleEndedIterator::nth_back`].
+    fn nth_back(line: &mut L, n: usize) -> Option<(Self, Self)>;
+
+    /// See [`Iterator::fold`].
+    #[inline]
+    fn fold<B, F>(mut line: L, mut accum: B, mut f: F) -> B
+    where
+        F: FnMut(B, (Self, Self)) -> B,
+    {
+        while let Some(point) = Self::next(&mut line) {
+            accum = f(accum, point);
+        }
+        accum
+    }
+
+    /// See [`DoubleEndedIterator::rfold`].
+    #[inline]
+    fn rfold<B, F>(mut line: L, mut accum: B, mut f: F) -> B
+    where
+        F: FnMut(B, (Self, Self)) -> B,
+    {
+        while let Some(point) = Self::next_back(&mut line) {
+            accum = f(accum, point);
+        }
+        accum
+    }
+}
+
+/// The implementation of [`RasterLine`] for the line iterator `L` over this coordinate type.
+///
+/// This is a supertrait of [`Coord`], which makes [`RasterLine`] available for all of them.
+/// It is public, but cannot be named outside of this crate.
+pub trait LineCoord<L>: LineIter<L> {
+    /// See [`RasterLine::new`].
+    fn line(x0: Self, y0: Self, x1: Self, y1: Self) -> Option<L>;
+    /// See [`RasterLine::clip`].
+    fn clip(clip: &Clip<Self>, x0: Self, y0: Self, x1: Self, y1: Self) -> Option<L>
+    where
+        Self: Coord;
+    /// See [`RasterLine::viewport`].
+    fn viewport(viewport: &Viewport<Self>, x0: Self, y0: Self, x1: Self, y1: Self) -> Option<L>
+    where
+        Self: Coord;
+    /// See [`RasterLine::is_empty`].
+    fn is_empty(line: &L) -> bool;
+    /// See [`RasterLine::len`].
+    fn len(line: &L) -> Self::U
+    where
+        Self: Coord;
+    /// See [`RasterLine::head`].
+    fn head(line: &L) -> Option<(Self, Self)>;
+    /// See [`RasterLine::pop_head`].
+    fn pop_head(line: &mut L) -> Option<(Self, Self)>;
+    /// See [`RasterLine::tail`].
+    fn tail(line: &L) -> Option<(Self, Self)>;
+    /// See [`RasterLine::pop_tail`].
+    fn pop_tail(line: &mut L) -> Option<(Self, Self)>;
+}
+
+macro_rules! iter_generic {
+    ($Line:ident<$(const $P:ident: $T:ty,)? C>) => {
+        impl<$(const $P: $T,)? C: Coord> Iterator for $Line<$($P,)? C>
+        where
+            C: LineIter<Self>,
+        {
+            type Item = (C, C);
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                C::next(self)
+            }
+            #[inline]
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                C::nth(self, n)
+            }
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                C::size_hint(self)
+            }
+            #[inline]
+            fn fold<B, G>(self, accum: B, f: G) -> B
+            where
+                G: FnMut(B, Self::Item) -> B,
+            {
+                C::fold(self, accum, f)
+            }
+        }
+
+        impl<$(const $P: $T,)? C: Coord> DoubleEndedIterator for $Line<$($P,)? C>
+        where
+            C: LineIter<Self>,
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                C::next_back(self)
+            }
+            #[inline]
+            fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+                C::nth_back(self, n)
+            }
+            #[inline]
+            fn rfold<B, G>(self, accum: B, f: G) -> B
+            where
+                G: FnMut(B, Self::Item) -> B,
+            {
+                C::rfold(self, accum, f)
+            }
+        }
+
+        impl<$(const $P: $T,)? C: Coord> core::iter::FusedIterator for $Line<$($P,)? C> where
+            C: LineIter<Self>
+        {
+        }
+    };
+}
+
+iter_generic!(LineA<C>);
+iter_generic!(LineAu<const YX: bool, C>);
+iter_generic!(LineB<C>);
+iter_generic!(LineBu<const YX: bool, C>);
+iter_generic!(LineBq<const F: u32, C>);
+iter_generic!(LineD<C>);
+iter_generic!(LineD2<C>);
+
+macro_rules! raster_line {
+    ($Line:ident<$(const $P:ident: $T:ty,)? C>) => {
+        impl<$(const $P: $T,)? C: Coord> RasterLine<C> for $Line<$($P,)? C>
+        where
+            C: LineCoord<Self>,
+        {
+            #[inline]
+            fn new(x0: C, y0: C, x1: C, y1: C) -> Option<Self> {
+                <C as LineCoord<Self>>::line(x0, y0, x1, y1)
+            }
+            #[inline]
+            fn clip(clip: &Clip<C>, x0: C, y0: C, x1: C, y1: C) -> Option<Self> {
+                <C as LineCoord<Self>>::clip(clip, x0, y0, x1, y1)
+            }
+            #[inline]
+            fn viewport(viewport: &Viewport<C>, x0: C, y0: C, x1: C, y1: C) -> Option<Self> {
+                <C as LineCoord<Self>>::viewport(viewport, x0, y0, x1, y1)
+            }
+            #[inline]
+            fn is_empty(&self) -> bool {
+                <C as LineCoord<Self>>::is_empty(self)
+            }
+            #[inline]
+            fn len(&self) -> C::U {
+                <C as LineCoord<Self>>::len(self)
+            }
+            #[inline]
+            fn head(&self) -> Option<(C, C)> {
+                <C as LineCoord<Self>>::head(self)
+            }
+            #[inline]
+            fn pop_head(&mut self) -> Option<(C, C)> {
+                <C as LineCoord<Self>>::pop_head(self)
+            }
+            #[inline]
+            fn tail(&self) -> Option<(C, C)> {
+                <C as LineCoord<Self>>::tail(self)
+            }
+            #[inline]
+            fn pop_tail(&mut self) -> Option<(C, C)> {
+                <C as LineCoord<Self>>::pop_tail(self)
+            }
+        }
+    };
+}
+
+raster_line!(LineA<C>);
+raster_line!(LineAx<C>);
+raster_line!(LineAy<C>);
+raster_line!(LineB<C>);
+raster_line!(LineBx<C>);
+raster_line!(LineBy<C>);
+raster_line!(LineBq<const F: u32, C>);
+raster_line!(LineD<C>);
+raster_line!(LineD2<C>);
+
+macro_rules! line_coord {
+    ($Cu:ty | $Ci:ty) => {
+        line_coord!($Cu);
+        line_coord!($Ci);
+    };
+    ($C:ty) => {
+        line_coord!(@impl LineA<$C>, line_a);
+        line_coord!(@impl LineD<$C>, line_d);
+        line_coord!(@impl LineD2<$C>, line_d2);
+
+        impl LineCoord<LineB<$C>> for $C {
+            #[inline]
+            fn line(x0: $C, y0: $C, x1: $C, y1: $C) -> Option<LineB<$C>> {
+                Some(LineB::<$C>::new(x0, y0, x1, y1))
+            }
+            #[inline]
+            fn clip(clip: &Clip<$C>, x0: $C, y0: $C, x1: $C, y1: $C) -> Option<LineB<$C>> {
+                clip.line_b(x0, y0, x1, y1)
+            }
+            #[inline]
+            fn viewport(
+                viewport: &Viewport<$C>,
+                x0: $C,
+                y0: $C,
+                x1: $C,
+                y1: $C,
+            ) -> Option<LineB<$C>> {
+                viewport.line_b(x0, y0, x1, y1)
+            }
+            line_coord!(@methods LineB<$C>, $C);
+        }
+
+        impl<const F: u32> LineCoord<LineBq<F, $C>> for $C {
+            #[inline]
+            fn line(x0: $C, y0: $C, x1: $C, y1: $C) -> Option<LineBq<F, $C>> {
+                Some(LineBq::<F, $C>::new(x0, y0, x1, y1))
+            }
+            #[inline]
+            fn clip(clip: &Clip<$C>, x0: $C, y0: $C, x1: $C, y1: $C) -> Option<LineBq<F, $C>> {
+                clip.line_bq::<F>(x0, y0, x1, y1)
+            }
+            #[inline]
+            fn viewport(
+                viewport: &Viewport<$C>,
+                x0: $C,
+                y0: $C,
+                x1: $C,
+                y1: $C,
+            ) -> Option<LineBq<F, $C>> {
+                viewport.line_bq::<F>(x0, y0, x1, y1)
+            }
+            line_coord!(@methods LineBq<F, $C>, $C);
+        }
+
+        line_coord!(@bu LineBx<$C>, Bx);
+        line_coord!(@bu LineBy<$C>, By);
+
+        impl LineCoord<LineAx<$C>> for $C {
+            #[inline]
+            fn line(x0: $C, y0: $C, x1: $C, y1: $C) -> Option<LineAx<$C>> {
+                if y0 != y1 {
+                    return None;
+                }
+                Some(LineAx::<$C>::new(y0, x0, x1))
+            }
+            #[inline]
+            fn clip(clip: &Clip<$C>, x0: $C, y0: $C, x1: $C, y1: $C) -> Option<LineAx<$C>> {
+                if y0 != y1 {
+                    return None;
+                }
+                clip.line_ax(y0, x0, x1)
+            }
+            #[inline]
+            fn viewport(
+                viewport: &Viewport<$C>,
+                x0: $C,
+                y0: $C,
+                x1: $C,
+                y1: $C,
+            ) -> Option<LineAx<$C>> {
+                if y0 != y1 {
+                    return None;
+                }
+                viewport.line_ax(y0, x0, x1)
+            }
+            line_coord!(@methods LineAx<$C>, $C);
+        }
+
+        impl LineCoord<LineAy<$C>> for $C {
+            #[inline]
+            fn line(x0: $C, y0: $C, x1: $C, y1: $C) -> Option<LineAy<$C>> {
+                if x0 != x1 {
+                    return None;
+                }
+                Some(LineAy::<$C>::new(x0, y0, y1))
+            }
+            #[inline]
+            fn clip(clip: &Clip<$C>, x0: $C, y0: $C, x1: $C, y1: $C) -> Option<LineAy<$C>> {
+                if x0 != x1 {
+                    return None;
+                }
+                clip.line_ay(x0, y0, y1)
+            }
+            #[inline]
+            fn viewport(
+                viewport: &Viewport<$C>,
+                x0: $C,
+                y0: $C,
+                x1: $C,
+                y1: $C,
+            ) -> Option<LineAy<$C>> {
+                if x0 != x1 {
+                    return None;
+                }
+                viewport.line_ay(x0, y0, y1)
+            }
+            line_coord!(@methods LineAy<$C>, $C);
+        }
+    };
+    (@impl $Line:ident<$C:ty>, $method:ident) => {
+        impl LineCoord<$Line<$C>> for $C {
+            #[inline]
+            fn line(x0: $C, y0: $C, x1: $C, y1: $C) -> Option<$Line<$C>> {
+                $Line::<$C>::new(x0, y0, x1, y1)
+            }
+            #[inline]
+            fn clip(clip: &Clip<$C>, x0: $C, y0: $C, x1: $C, y1: $C) -> Option<$Line<$C>> {
+                clip.$method(x0, y0, x1, y1)
+            }
+            #[inline]
+            fn viewport(
+                viewport: &Viewport<$C>,
+                x0: $C,
+                y0: $C,
+                x1: $C,
+                y1: $C,
+            ) -> Option<$Line<$C>> {
+                viewport.$method(x0, y0, x1, y1)
+            }
+            line_coord!(@methods $Line<$C>, $C);
+        }
+    };
+    (@bu $Line:ident<$C:ty>, $Variant:ident) => {
+        impl LineCoord<$Line<$C>> for $C {
+            #[inline]
+            fn line(x0: $C, y0: $C, x1: $C, y1: $C) -> Option<$Line<$C>> {
+                match LineB::<$C>::new(x0, y0, x1, y1) {
+                    LineB::$Variant(line) => Some(line),
+                    _ => None,
+                }
+            }
+            #[inline]
+            fn clip(clip: &Clip<$C>, x0: $C, y0: $C, x1: $C, y1: $C) -> Option<$Line<$C>> {
+                match clip.line_b(x0, y0, x1, y1) {
+                    Some(LineB::$Variant(line)) => Some(line),
+                    _ => None,
+                }
+            }
+            #[inline]
+            fn viewport(
+                viewport: &Viewport<$C>,
+                x0: $C,
+                y0: $C,
+                x1: $C,
+                y1: $C,
+            ) -> Option<$Line<$C>> {
+                match viewport.line_b(x0, y0, x1, y1) {
+                    Some(LineB::$Variant(line)) => Some(line),
+                    _ => None,
+                }
+            }
+            line_coord!(@methods $Line<$C>, $C);
+        }
+    };
+    (@methods $Line:ty, $C:ty) => {
+        #[inline]
+        fn is_empty(line: &$Line) -> bool {
+            line.is_empty()
+        }
+        #[inline]
+        fn len(line: &$Line) -> <$C as Coord>::U {
+            line.len()
+        }
+        #[inline]
+        fn head(line: &$Line) -> Option<($C, $C)> {
+            line.head()
+        }
+        #[inline]
+        fn pop_head(line: &mut $Line) -> Option<($C, $C)> {
+            line.pop_head()
+        }
+        #[inline]
+        fn tail(line: &$Line) -> Option<($C, $C)> {
+            line.tail()
+        }
+        #[inline]
+        fn pop_tail(line: &mut $Line) -> Option<($C, $C)> {
+            line.pop_tail()
+        }
+    };
+}
+
+line_coord!(u8 | i8);
+line_coord!(u16 | i16);
+line_coord!(u32 | i32);
+line_coord!(u64 | i64);
+line_coord!(usize | isize);
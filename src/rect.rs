@@ -0,0 +1,185 @@
+use crate::line_a::{LineAu, LineAx, LineAy};
+use crate::macros::*;
+use crate::math::{Coord, ops};
+
+/// An iterator over the rasterized points of the outline of an axis-aligned rectangle.
+///
+/// The outline is made of four half-open [`LineAx`] and [`LineAy`] segments,
+/// going from corner `(x0, y0)` along `X` to `(x1, y0)`, along `Y` to `(x1, y1)`,
+/// and back along `X` and `Y` to `(x0, y0)`, so that each corner is yielded once.
+///
+/// A rectangle one point wide or tall is yielded as the closed segment
+/// from `(x0, y0)` to `(x1, y1)`.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Rect<C: Coord> {
+    pub(crate) top: LineAx<C>,
+    pub(crate) right: LineAy<C>,
+    pub(crate) bottom: LineAx<C>,
+    pub(crate) left: LineAy<C>,
+}
+
+macro_rules! rect {
+    ($Cu:ty | $Ci:ty) => {
+        rect!($Cu, <$Cu as Coord>::U2);
+        rect!($Ci, <$Ci as Coord>::U2);
+    };
+    ($C:ty, $U2:ty) => {
+        impl Rect<$C> {
+            /// Returns a [`Rect`] over the outline of the closed rectangle
+            /// with opposite corners `(x0, y0)` and `(x1, y1)`.
+            #[inline]
+            pub const fn new(x0: $C, y0: $C, x1: $C, y1: $C) -> Self {
+                let mut rect = Self {
+                    top: LineAu::<false, $C>::new(y0, x0, x0),
+                    right: LineAu::<true, $C>::new(x1, y0, y0),
+                    bottom: LineAu::<false, $C>::new(y1, x1, x1),
+                    left: LineAu::<true, $C>::new(x0, y1, y1),
+                };
+                if y0 == y1 && x0 == x1 {
+                    rect.top = Self::single(y0, x0);
+                } else if y0 == y1 {
+                    rect.top = LineAu::<false, $C>::new(y0, x0, x1);
+                    rect.right = Self::single(x1, y0);
+                } else if x0 == x1 {
+                    rect.right = LineAu::<true, $C>::new(x1, y0, y1);
+                    rect.bottom = Self::single(y1, x1);
+                } else {
+                    rect.top = LineAu::<false, $C>::new(y0, x0, x1);
+                    rect.right = LineAu::<true, $C>::new(x1, y0, y1);
+                    rect.bottom = LineAu::<false, $C>::new(y1, x1, x0);
+                    rect.left = LineAu::<true, $C>::new(x0, y1, y0);
+                }
+                rect
+            }
+
+            /// Returns a segment over the single point `u`.
+            #[inline]
+            const fn single<const YX: bool>(v: $C, u: $C) -> LineAu<YX, $C> {
+                // The exclusive end wraps around, which the segment accounts for.
+                LineAu { u0: u, u1: ops::<$C>::wadd_i(u, 1), v, su: 1 }
+            }
+
+            /// Returns `true` if the iterator is empty.
+            #[inline]
+            pub const fn is_empty(&self) -> bool {
+                self.top.is_empty()
+                    && self.right.is_empty()
+                    && self.bottom.is_empty()
+                    && self.left.is_empty()
+            }
+
+            /// Returns the remaining length of this iterator.
+            #[inline]
+            pub const fn len(&self) -> $U2 {
+                self.top.len() as $U2
+                    + self.right.len() as $U2
+                    + self.bottom.len() as $U2
+                    + self.left.len() as $U2
+            }
+
+            /// Returns the point at the start of the iterator.
+            /// This does not advance the iterator.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn head(&self) -> Option<($C, $C)> {
+                if !self.top.is_empty() {
+                    return self.top.head();
+                }
+                if !self.right.is_empty() {
+                    return self.right.head();
+                }
+                if !self.bottom.is_empty() {
+                    return self.bottom.head();
+                }
+                self.left.head()
+            }
+
+            /// Consumes and returns the point at the start of the iterator.
+            /// This advances the iterator forwards.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn pop_head(&mut self) -> Option<($C, $C)> {
+                if !self.top.is_empty() {
+                    return self.top.pop_head();
+                }
+                if !self.right.is_empty() {
+                    return self.right.pop_head();
+                }
+                if !self.bottom.is_empty() {
+                    return self.bottom.pop_head();
+                }
+                self.left.pop_head()
+            }
+
+            /// Returns the last point of the iterator.
+            /// This does not advance the iterator.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn tail(&self) -> Option<($C, $C)> {
+                if !self.left.is_empty() {
+                    return self.left.tail();
+                }
+                if !self.bottom.is_empty() {
+                    return self.bottom.tail();
+                }
+                if !self.right.is_empty() {
+                    return self.right.tail();
+                }
+                self.top.tail()
+            }
+
+            /// Consumes and returns the last point of the iterator.
+            /// This advances the iterator backwards.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn pop_tail(&mut self) -> Option<($C, $C)> {
+                if !self.left.is_empty() {
+                    return self.left.pop_tail();
+                }
+                if !self.bottom.is_empty() {
+                    return self.bottom.pop_tail();
+                }
+                if !self.right.is_empty() {
+                    return self.right.pop_tail();
+                }
+                self.top.pop_tail()
+            }
+        }
+
+        impl Iterator for Rect<$C> {
+            type Item = ($C, $C);
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.pop_head()
+            }
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match usize::try_from(self.len()) {
+                    Ok(len) => (len, Some(len)),
+                    Err(_) => (usize::MAX, None),
+                }
+            }
+        }
+
+        impl DoubleEndedIterator for Rect<$C> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.pop_tail()
+            }
+        }
+
+        impl core::iter::FusedIterator for Rect<$C> {}
+    };
+}
+
+clone!([C: Coord] Rect<C>, fields = [top, right, bottom, left]);
+
+rect!(u8 | i8);
+rect!(u16 | i16);
+rect!(u32 | i32);
+rect!(u64 | i64);
+rect!(usize | isize);
@@ -0,0 +1,128 @@
+use crate::macros::*;
+use crate::math::Coord;
+
+/// An iterator over the horizontal spans of a filled axis-aligned rectangle.
+///
+/// Yields `(y, x_start, x_end)`, where `x_start <= x_end` are both inside the span.
+/// Rows are yielded in increasing order of `y`, each once.
+///
+/// The boundary of the filled rectangle is the [`Rect`](crate::Rect) with the same corners.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct RectFill<C: Coord> {
+    pub(crate) x0: C,
+    pub(crate) x1: C,
+    pub(crate) y0: C::I2,
+    pub(crate) y1: C::I2,
+}
+
+macro_rules! rect_fill {
+    ($Cu:ty | $Ci:ty) => {
+        rect_fill!($Cu, <$Cu as Coord>::U2, <$Cu as Coord>::I2);
+        rect_fill!($Ci, <$Ci as Coord>::U2, <$Ci as Coord>::I2);
+    };
+    ($C:ty, $U2:ty, $I2:ty) => {
+        impl RectFill<$C> {
+            /// Returns a [`RectFill`] over the filled closed rectangle
+            /// with opposite corners `(x0, y0)` and `(x1, y1)`.
+            #[inline]
+            pub const fn new(x0: $C, y0: $C, x1: $C, y1: $C) -> Self {
+                let (x0, x1) = if x0 <= x1 { (x0, x1) } else { (x1, x0) };
+                let (y0, y1) = if y0 <= y1 { (y0, y1) } else { (y1, y0) };
+                Self { x0, x1, y0: y0 as $I2, y1: y1 as $I2 }
+            }
+
+            /// Returns `true` if the iterator is empty.
+            #[inline]
+            pub const fn is_empty(&self) -> bool {
+                self.y1 < self.y0
+            }
+
+            /// Returns the remaining length of this iterator.
+            #[inline]
+            pub const fn len(&self) -> $U2 {
+                if self.is_empty() {
+                    return 0;
+                }
+                (self.y1 - self.y0) as $U2 + 1
+            }
+
+            /// Returns the span at the start of the iterator.
+            /// This does not advance the iterator.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn head(&self) -> Option<($C, $C, $C)> {
+                if self.is_empty() {
+                    return None;
+                }
+                Some((self.y0 as $C, self.x0, self.x1))
+            }
+
+            /// Consumes and returns the span at the start of the iterator.
+            /// This advances the iterator forwards.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn pop_head(&mut self) -> Option<($C, $C, $C)> {
+                let head = try_opt!(self.head());
+                self.y0 += 1;
+                Some(head)
+            }
+
+            /// Returns the span at the end of the iterator.
+            /// This does not advance the iterator.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn tail(&self) -> Option<($C, $C, $C)> {
+                if self.is_empty() {
+                    return None;
+                }
+                Some((self.y1 as $C, self.x0, self.x1))
+            }
+
+            /// Consumes and returns the span at the end of the iterator.
+            /// This advances the iterator backwards.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn pop_tail(&mut self) -> Option<($C, $C, $C)> {
+                let tail = try_opt!(self.tail());
+                self.y1 -= 1;
+                Some(tail)
+            }
+        }
+
+        impl Iterator for RectFill<$C> {
+            type Item = ($C, $C, $C);
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.pop_head()
+            }
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match usize::try_from(self.len()) {
+                    Ok(len) => (len, Some(len)),
+                    Err(_) => (usize::MAX, None),
+                }
+            }
+        }
+
+        impl DoubleEndedIterator for RectFill<$C> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.pop_tail()
+            }
+        }
+
+        impl core::iter::FusedIterator for RectFill<$C> {}
+    };
+}
+
+clone!([C: Coord] RectFill<C>);
+
+rect_fill!(u8 | i8);
+rect_fill!(u16 | i16);
+rect_fill!(u32 | i32);
+rect_fill!(u64 | i64);
+rect_fill!(usize | isize);
@@ -0,0 +1,429 @@
+use crate::macros::*;
+use crate::math::{Coord, ops};
+
+/// An iterator over the horizontal spans of a filled triangle.
+///
+/// Yields `(y, x_start, x_end, (w0, w1, w2))`, where `x_start <= x_end` are both inside the span,
+/// and `wi` is the edge function of the edge opposite to vertex `i` at `(x_start, y)`.
+/// Rows are yielded in increasing order of `y`, each at most once.
+///
+/// The edge function of the edge opposite to vertex `i` is twice the signed area
+/// of the triangle formed by that edge and the point, so that it is zero on the edge
+/// and equal to twice the area of the triangle at vertex `i`. Divided by their sum,
+/// the edge functions are the barycentric coordinates of the point.
+/// Along a span, they change by [`Triangle::weight_steps`] per point.
+///
+/// A point is inside the triangle if all edge functions are positive,
+/// or zero on a top or left edge. This is the top-left rule: triangles sharing an edge
+/// never cover the same point, and leave no gaps between each other.
+///
+/// # Performance
+///
+/// Rows outside the columns are skipped up front. The rows of a thin triangle
+/// that contain no point are skipped in time logarithmic in their number,
+/// by counting the points of a range of rows in closed form.
+///
+/// # Warning
+///
+/// Internally, this uses integers twice the size of the coordinate type.
+/// Keep this in mind when using `u64`, `i64`, `usize` and `isize` coordinates.
+/// If you do not need the whole range, prefer smaller coordinate types.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Triangle<C: Coord> {
+    pub(crate) v: [(C::I2, C::I2); 3],
+    /// Whether the vertices are in counterclockwise order with `Y` pointing down.
+    pub(crate) flip: bool,
+    pub(crate) y: C::I2,
+    pub(crate) y1: C::I2,
+    /// The current span.
+    pub(crate) xa: C::I2,
+    pub(crate) xb: C::I2,
+    pub(crate) c0: C::I2,
+    pub(crate) c1: C::I2,
+}
+
+macro_rules! triangle {
+    ($Cu:ty | $Ci:ty) => {
+        triangle!($Cu, <$Cu as Coord>::U, <$Cu as Coord>::U2, <$Cu as Coord>::I2);
+        triangle!($Ci, <$Ci as Coord>::U, <$Ci as Coord>::U2, <$Ci as Coord>::I2);
+    };
+    ($C:ty, $U:ty, $U2:ty, $I2:ty) => {
+        impl Triangle<$C> {
+            /// Returns a [`Triangle`] over the triangle with vertices
+            /// `(x0, y0)`, `(x1, y1)` and `(x2, y2)`, in any order.
+            ///
+            /// Returns [`None`] if twice the area of the triangle does not fit into `C::I2`.
+            #[inline]
+            pub const fn new(x0: $C, y0: $C, x1: $C, y1: $C, x2: $C, y2: $C) -> Option<Self> {
+                let v = [(x0 as $I2, y0 as $I2), (x1 as $I2, y1 as $I2), (x2 as $I2, y2 as $I2)];
+                let (min, max) = (<$C>::MIN as $I2, <$C>::MAX as $I2);
+                Self::raw(v, (min, max), (min, max))
+            }
+
+            /// Returns a [`Triangle`] restricted to the closed ranges `rows` and `cols`,
+            /// or [`None`] if twice its area does not fit into `C::I2`.
+            #[inline]
+            pub(crate) const fn raw(
+                v: [($I2, $I2); 3],
+                rows: ($I2, $I2),
+                cols: ($I2, $I2),
+            ) -> Option<Self> {
+                let (p, q) = (
+                    Self::smul(v[1].0 - v[0].0, v[2].1 - v[0].1),
+                    Self::smul(v[1].1 - v[0].1, v[2].0 - v[0].0),
+                );
+                let (neg, area) = if p.0 != q.0 {
+                    (p.0, try_opt!(p.1.checked_add(q.1)))
+                } else if q.1 <= p.1 {
+                    (p.0, p.1 - q.1)
+                } else {
+                    (!p.0, q.1 - p.1)
+                };
+                if <$I2>::MAX as $U2 < area {
+                    return None;
+                }
+                let mut y0 = if v[0].1 < v[1].1 { v[0].1 } else { v[1].1 };
+                let mut y1 = if v[0].1 < v[1].1 { v[1].1 } else { v[0].1 };
+                y0 = if v[2].1 < y0 { v[2].1 } else { y0 };
+                y1 = if y1 < v[2].1 { v[2].1 } else { y1 };
+                let y0 = if y0 < rows.0 { rows.0 } else { y0 };
+                let y1 = if rows.1 < y1 { rows.1 } else { y1 };
+                let (y0, y1) = match Self::strip_rows(&v, cols) {
+                    Some((ya, yb)) => (if y0 < ya { ya } else { y0 }, if yb < y1 { yb } else { y1 }),
+                    None => (y0, y0 - 1),
+                };
+                let mut triangle =
+                    Self { v, flip: neg, y: y0, y1, xa: 0, xb: 0, c0: cols.0, c1: cols.1 };
+                if area == 0 {
+                    triangle.y1 = triangle.y - 1;
+                }
+                triangle.seek();
+                Some(triangle)
+            }
+
+            /// Returns the sign and magnitude of `a * b`.
+            #[inline]
+            const fn smul(a: $I2, b: $I2) -> (bool, $U2) {
+                ((a < 0) != (b < 0) && a != 0 && b != 0, a.unsigned_abs() * b.unsigned_abs())
+            }
+
+            /// Returns the closed range of rows, rounded outwards, in which the triangle
+            /// with vertices `v` overlaps the columns `cols`, or [`None`] if it does not.
+            ///
+            /// The overlap is convex and bounded by the edges clipped to the columns,
+            /// so its rows are those between the ends of the clipped edges.
+            #[inline]
+            const fn strip_rows(v: &[($I2, $I2); 3], cols: ($I2, $I2)) -> Option<($I2, $I2)> {
+                let mut rows = None;
+                let mut i = 0;
+                while i < 3 {
+                    let (a, b) = (v[i], v[(i + 1) % 3]);
+                    i += 1;
+                    let (a, b) = if a.0 <= b.0 { (a, b) } else { (b, a) };
+                    let xa = if a.0 < cols.0 { cols.0 } else { a.0 };
+                    let xb = if cols.1 < b.0 { cols.1 } else { b.0 };
+                    if xb < xa {
+                        continue;
+                    }
+                    let (mut lo, mut hi) = if a.1 <= b.1 { (a.1, b.1) } else { (b.1, a.1) };
+                    if a.0 < b.0 {
+                        // The edge is at row a.1 + (x - a.0) * (b.1 - a.1) / (b.0 - a.0).
+                        let d = (b.0 - a.0).unsigned_abs();
+                        let (na, ma) = Self::smul(xa - a.0, b.1 - a.1);
+                        let (nb, mb) = Self::smul(xb - a.0, b.1 - a.1);
+                        let (fa, ca) = (a.1 - Self::div_ceil(!na, ma, d), a.1 + Self::div_ceil(na, ma, d));
+                        let (fb, cb) = (a.1 - Self::div_ceil(!nb, mb, d), a.1 + Self::div_ceil(nb, mb, d));
+                        (lo, hi) = (if fa < fb { fa } else { fb }, if ca < cb { cb } else { ca });
+                    }
+                    rows = match rows {
+                        Some((r0, r1)) => Some((if lo < r0 { lo } else { r0 }, if r1 < hi { hi } else { r1 })),
+                        None => Some((lo, hi)),
+                    };
+                }
+                rows
+            }
+
+            /// Returns the start `(ax, ay)` and the delta `(dx, dy)` of the edge opposite
+            /// to vertex `i`, directed clockwise with `Y` pointing down.
+            #[inline]
+            const fn edge(&self, i: usize) -> ($I2, $I2, $I2, $I2) {
+                let (a, b) = (self.v[(i + 1) % 3], self.v[(i + 2) % 3]);
+                let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+                if self.flip { (a.0, a.1, -dx, -dy) } else { (a.0, a.1, dx, dy) }
+            }
+
+            /// Returns `ceil(k / d)` for `k = ±m` and `d > 0`,
+            /// clamped to just outside the range of the coordinate type.
+            #[inline]
+            const fn div_ceil(neg: bool, m: $U2, d: $U2) -> $I2 {
+                const LIM: $U2 = <$U>::MAX as $U2 + 2;
+                let (q, r) = (m / d, m % d);
+                let q = if neg || r == 0 { q } else { q + 1 };
+                let q = (if LIM < q { LIM } else { q }) as $I2;
+                if neg { -q } else { q }
+            }
+
+            /// Returns the bound on the columns of row `y` of the edge opposite to vertex `i`,
+            /// which is the last column for `0 < dy` and the first one for `dy < 0`.
+            #[inline]
+            const fn bound(&self, i: usize, y: $I2) -> $I2 {
+                let (ax, ay, dx, dy) = self.edge(i);
+                // The edge function is dx * (y - ay) - dy * (x - ax).
+                // Left edges (dy < 0) include their zeros.
+                let (neg, m) = Self::smul(dx, y - ay);
+                if 0 < dy {
+                    ax + Self::div_ceil(neg, m, dy.unsigned_abs()) - 1
+                } else {
+                    ax + Self::div_ceil(!neg, m, dy.unsigned_abs())
+                }
+            }
+
+            /// Returns the span of row `y`.
+            #[inline]
+            const fn span(&self, y: $I2) -> ($I2, $I2) {
+                let (mut lo, mut hi) = (self.c0, self.c1);
+                let mut i = 0;
+                while i < 3 {
+                    let (_, ay, dx, dy) = self.edge(i);
+                    i += 1;
+                    // Top edges (dy = 0 < dx) include their zeros.
+                    if dy == 0 {
+                        let (neg, m) = Self::smul(dx, y - ay);
+                        if neg || m == 0 && dx < 0 {
+                            return (1, 0);
+                        }
+                    } else if 0 < dy {
+                        let x = self.bound(i - 1, y);
+                        if x < hi {
+                            hi = x;
+                        }
+                    } else {
+                        let x = self.bound(i - 1, y);
+                        if lo < x {
+                            lo = x;
+                        }
+                    }
+                }
+                (lo, hi)
+            }
+
+            /// Returns `Σ_{t < n} bound(i, y + t)` of [`Self::bound`] in wrapping arithmetic,
+            /// given that the bounds are inside the range of the coordinate type.
+            #[inline]
+            const fn bound_sum(&self, i: usize, y: $I2, n: $U2) -> $U2 {
+                let (ax, ay, dx, dy) = self.edge(i);
+                // Each bound is ax + ⌈dx * (y + t - ay) / dy⌉ (- 1 for 0 < dy),
+                // that is ax - ⌊(a * t + b) / |dy|⌋ with a = -dx and b = -dx * (y - ay)
+                // for 0 < dy, and the opposite signs otherwise.
+                let d = dy.unsigned_abs();
+                let a = if 0 < dy { -dx } else { dx };
+                let (qa, ra) = (a.div_euclid(d as $I2), a.rem_euclid(d as $I2));
+                let (neg, m) = Self::smul(dx, y - ay);
+                let (q, r) = (m / d, m % d);
+                let (qb, rb) = if neg == (0 < dy) {
+                    (q, r)
+                } else if r == 0 {
+                    (q.wrapping_neg(), 0)
+                } else {
+                    ((q + 1).wrapping_neg(), d - r)
+                };
+                let floors = ops::<$C>::floor_sum(n, d, ra as $U2, rb)
+                    .wrapping_add(ops::<$C>::pairs(n).wrapping_mul(qa as $U2))
+                    .wrapping_add(n.wrapping_mul(qb));
+                let ax = if 0 < dy { ax - 1 } else { ax };
+                n.wrapping_mul(ax as $U2).wrapping_sub(floors)
+            }
+
+            /// Returns the number of points in the `n` rows from `y`, between the edges
+            /// `l` and `r` if `left` and `right`, and the columns otherwise.
+            #[inline]
+            const fn count(
+                &self,
+                (l, left): (usize, bool),
+                (r, right): (usize, bool),
+                y: $I2,
+                n: $U2,
+            ) -> $U2 {
+                let c0 = n.wrapping_mul(self.c0 as $U2);
+                let c1 = n.wrapping_mul(self.c1 as $U2);
+                let lo = if left { self.bound_sum(l, y, n) } else { c0 };
+                let hi = if right { self.bound_sum(r, y, n) } else { c1 };
+                hi.wrapping_sub(lo).wrapping_add(n)
+            }
+
+            /// Returns the first row in `(y, e)` where the bound of edge `i` is no longer
+            /// `inner` than the column on its side, or `e` if there is none.
+            /// The bound of a left edge is inner if it is at least the first column,
+            /// and the bound of a right edge if it is at most the last one.
+            #[inline]
+            const fn exit(&self, i: usize, mut y: $I2, mut e: $I2, inner: bool) -> $I2 {
+                while 1 < e - y {
+                    let mid = y + (e - y) / 2;
+                    if self.inner(i, mid) == inner {
+                        y = mid;
+                    } else {
+                        e = mid;
+                    }
+                }
+                e
+            }
+
+            /// Returns `true` if the bound of edge `i` in row `y` is inner than the column
+            /// on its side, as in [`Self::exit`].
+            #[inline]
+            const fn inner(&self, i: usize, y: $I2) -> bool {
+                let x = self.bound(i, y);
+                if self.edge(i).3 < 0 { self.c0 <= x } else { x <= self.c1 }
+            }
+
+            /// Returns the first row after `y` that may hold a point.
+            ///
+            /// The rows strictly between those of the vertices and the last row are bounded
+            /// by one left and one right edge, or by the columns beyond which these lie.
+            /// While neither of these changes, the difference of the bounds of a row plus one
+            /// is its number of points, and never negative, so sums of floors count the points
+            /// of a range of rows.
+            #[inline]
+            const fn next_row(&self, y: $I2) -> $I2 {
+                let mut e = self.y1;
+                let (mut l, mut r) = (3, 3);
+                let mut i = 0;
+                while i < 3 {
+                    let (_, ay, _, dy) = self.edge(i);
+                    let (lo, hi) = if dy < 0 { (ay + dy, ay) } else { (ay, ay + dy) };
+                    if y < hi && hi < e {
+                        e = hi;
+                    }
+                    if y < lo && lo < e {
+                        e = lo;
+                    }
+                    i += 1;
+                }
+                while i > 0 {
+                    i -= 1;
+                    let (_, ay, _, dy) = self.edge(i);
+                    let (lo, hi) = if dy < 0 { (ay + dy, ay) } else { (ay, ay + dy) };
+                    if lo <= y && e <= hi {
+                        if dy < 0 {
+                            l = i;
+                        } else if 0 < dy {
+                            r = i;
+                        }
+                    }
+                }
+                if e - y <= 1 || l == 3 || r == 3 {
+                    return y + 1;
+                }
+                let (left, right) = (self.inner(l, y + 1), self.inner(r, y + 1));
+                let e = self.exit(l, y + 1, e, left);
+                let e = self.exit(r, y + 1, e, right);
+                let (l, r) = ((l, left), (r, right));
+                // Gallop, then bisect for the fewest rows that hold a point.
+                let len = (e - y - 1) as $U2;
+                let (mut lo, mut n): ($U2, $U2) = (0, 1);
+                loop {
+                    let hi = if len < n { len } else { n };
+                    if self.count(l, r, y + 1, hi) != 0 {
+                        n = hi;
+                        break;
+                    }
+                    if hi == len {
+                        return e;
+                    }
+                    (lo, n) = (hi, n * 2);
+                }
+                while 1 < n - lo {
+                    let mid = lo + (n - lo) / 2;
+                    if self.count(l, r, y + 1, mid) != 0 {
+                        n = mid;
+                    } else {
+                        lo = mid;
+                    }
+                }
+                y + n as $I2
+            }
+
+            /// Moves to the first non-empty span, starting with the current row.
+            #[inline]
+            const fn seek(&mut self) {
+                while self.y <= self.y1 {
+                    let (lo, hi) = self.span(self.y);
+                    if lo <= hi {
+                        (self.xa, self.xb) = (lo, hi);
+                        return;
+                    }
+                    self.y = self.next_row(self.y);
+                }
+            }
+
+            /// Returns the edge functions at the point `(x, y)` inside the triangle.
+            #[inline]
+            const fn weights(&self, x: $I2, y: $I2) -> ($I2, $I2, $I2) {
+                let mut w = [0; 3];
+                let mut i = 0;
+                while i < 3 {
+                    // The result fits, even if the intermediate products do not.
+                    let (ax, ay, dx, dy) = self.edge(i);
+                    w[i] = dx.wrapping_mul(y - ay).wrapping_sub(dy.wrapping_mul(x - ax));
+                    i += 1;
+                }
+                (w[0], w[1], w[2])
+            }
+
+            /// Returns the change in the edge functions between consecutive points of a span.
+            #[inline]
+            pub const fn weight_steps(&self) -> ($I2, $I2, $I2) {
+                (-self.edge(0).3, -self.edge(1).3, -self.edge(2).3)
+            }
+
+            /// Returns `true` if the iterator is empty.
+            #[inline]
+            pub const fn is_empty(&self) -> bool {
+                self.y1 < self.y
+            }
+
+            /// Returns the span at the start of the iterator.
+            /// This does not advance the iterator.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn head(&self) -> Option<($C, $C, $C, ($I2, $I2, $I2))> {
+                if self.is_empty() {
+                    return None;
+                }
+                let w = self.weights(self.xa, self.y);
+                Some((self.y as $C, self.xa as $C, self.xb as $C, w))
+            }
+
+            /// Consumes and returns the span at the start of the iterator.
+            /// This advances the iterator forwards.
+            ///
+            /// Returns [`None`] if the iterator is empty.
+            #[inline]
+            pub const fn pop_head(&mut self) -> Option<($C, $C, $C, ($I2, $I2, $I2))> {
+                let head = try_opt!(self.head());
+                self.y += 1;
+                self.seek();
+                Some(head)
+            }
+        }
+
+        impl Iterator for Triangle<$C> {
+            type Item = ($C, $C, $C, ($I2, $I2, $I2));
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.pop_head()
+            }
+        }
+
+        impl core::iter::FusedIterator for Triangle<$C> {}
+    };
+}
+
+clone!([C: Coord] Triangle<C>);
+
+triangle!(u8 | i8);
+triangle!(u16 | i16);
+triangle!(u32 | i32);
+triangle!(u64 | i64);
+triangle!(usize | isize);
@@ -47,6 +47,60 @@ prop_compose! {
     }
 }
 
+prop_compose! {
+    fn sample_clip3_u8()(
+        x_max in 0..=u8::MAX,
+        y_max in 0..=u8::MAX,
+        z_max in 0..=u8::MAX,
+    ) -> Clip3<u8> {
+        Clip3::<u8>::from_max(x_max, y_max, z_max)
+    }
+}
+
+prop_compose! {
+    fn sample_clip3_i8()(
+        x_max in 0..=i8::MAX,
+        y_max in 0..=i8::MAX,
+        z_max in 0..=i8::MAX,
+    ) -> Clip3<i8> {
+        Clip3::<i8>::from_max(x_max, y_max, z_max).unwrap()
+    }
+}
+
+prop_compose! {
+    fn sample_viewport3_u8()(
+        x_min in u8::MIN..u8::MAX,
+        y_min in u8::MIN..u8::MAX,
+        z_min in u8::MIN..u8::MAX,
+    )(
+        x_max in x_min..=u8::MAX,
+        y_max in y_min..=u8::MAX,
+        z_max in z_min..=u8::MAX,
+        x_min in Just(x_min),
+        y_min in Just(y_min),
+        z_min in Just(z_min),
+    ) -> Viewport3<u8> {
+        Viewport3::<u8>::from_min_max(x_min, y_min, z_min, x_max, y_max, z_max).unwrap()
+    }
+}
+
+prop_compose! {
+    fn sample_viewport3_i8()(
+        x_min in i8::MIN..i8::MAX,
+        y_min in i8::MIN..i8::MAX,
+        z_min in i8::MIN..i8::MAX,
+    )(
+        x_max in x_min..=i8::MAX,
+        y_max in y_min..=i8::MAX,
+        z_max in z_min..=i8::MAX,
+        x_min in Just(x_min),
+        y_min in Just(y_min),
+        z_min in Just(z_min),
+    ) -> Viewport3<i8> {
+        Viewport3::<i8>::from_min_max(x_min, y_min, z_min, x_max, y_max, z_max).unwrap()
+    }
+}
+
 fn u8_add_u8(u0: u8, du: u8, su: i8) -> u8 {
     if su > 0 { u0 + du } else { u0 - du }
 }
@@ -100,11 +154,27 @@ macro_rules! sample_line {
             })+
         }
     };
+    (@line_w $($UI:ident)|+) => {
+        paste::paste! {
+            $(fn [<sample_line_w _ $UI>]() -> impl Strategy<Value = ($UI, $UI, $UI, $UI)> {
+                any::<($UI, $UI, $UI, $UI)>()
+            })+
+        }
+    };
+    (@line_s $($UI:ident)|+) => {
+        paste::paste! {
+            $(fn [<sample_line_s _ $UI>]() -> impl Strategy<Value = ($UI, $UI, $UI, $UI)> {
+                any::<($UI, $UI, $UI, $UI)>()
+            })+
+        }
+    };
 }
 
 sample_line!(@line_a u8 | i8);
 sample_line!(@line_b u8 | i8);
 sample_line!(@line_d u8 | i8);
+sample_line!(@line_w u8 | i8);
+sample_line!(@line_s u8 | i8);
 
 macro_rules! test {
     (
@@ -148,7 +218,8 @@ macro_rules! test {
                     let smart = clip.$line(x0, y0, x1, y1);
                     if let Some(smart) = smart {
                         prop_assert!(!smart.is_empty() || is_empty, "clipped != empty");
-                        prop_assert!(naive.eq(smart), "naive != smart");
+                        prop_assert!(naive.clone().eq(smart.clone()), "naive != smart");
+                        prop_assert!(naive.rev().eq(smart.rev()), "naive.rev() != smart.rev()");
                     } else {
                         prop_assert_eq!(naive.count(), 0);
                     }
@@ -182,6 +253,71 @@ macro_rules! test {
                     let smart = clip.$line_proj(x0, y0, x1, y1);
                     if let Some(smart) = smart {
                         prop_assert!(!smart.is_empty() || is_empty, "clipped != empty");
+                        prop_assert!(naive.clone().eq(smart.clone()), "naive != smart");
+                        prop_assert!(naive.rev().eq(smart.rev()), "naive.rev() != smart.rev()");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! test_w {
+    ($N:literal) => {
+        test_w!(@ clip, u8, $N);
+        test_w!(@ clip, i8, $N);
+        test_w!(@ clip proj, i8, $N);
+        test_w!(@ viewport, u8, $N);
+        test_w!(@ viewport proj, u8, $N);
+        test_w!(@ viewport, i8, $N);
+        test_w!(@ viewport proj, i8, $N);
+    };
+    (@ $clip:ident, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _line_w_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, x1, y1) in [<sample_line_w _ $UI>](),
+                ) {
+                    let raw = LineW::<$UI>::new(x0, y0, x1, y1);
+                    let naive = raw.filter(|&(x, y, _)| clip.point(x, y));
+                    let smart = clip.line_w(x0, y0, x1, y1);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+    (@ $clip:ident proj, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _line_w_proj_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, x1, y1) in [<sample_line_w _ $UI>](),
+                ) {
+                    let raw = LineW::<$UI>::new(x0, y0, x1, y1);
+                    let naive = raw.filter_map(|(x, y, c)| clip.point_proj(x, y).map(|(x, y)| (x, y, c)));
+                    let smart = clip.line_w_proj(x0, y0, x1, y1);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
                         prop_assert!(naive.eq(smart), "naive != smart");
                     } else {
                         prop_assert_eq!(naive.count(), 0);
@@ -192,8 +328,2850 @@ macro_rules! test {
     };
 }
 
-test!(LineA + unwrap, line_ax, line_a, line_a_proj, 4_000_000);
-test!(LineA + unwrap, line_ay, line_a, line_a_proj, 4_000_000);
-test!(LineB, line_b, line_b, line_b_proj, 4_000_000);
-test!(LineD + unwrap, line_d, line_d, line_d_proj, 4_000_000);
-test!(LineD2 + unwrap, line_d2, line_d2, line_d2_proj, 4_000_000);
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 1_000_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn coverage_line_w_i8((x0, y0, x1, y1) in sample_line_w_i8()) {
+        let line = LineW::<i8>::new(x0, y0, x1, y1);
+        let yx = matches!(line, LineW::Wy(_));
+        let steps = usize::from(line.steps());
+        let mut sums = std::collections::BTreeMap::<i8, u32>::new();
+        for (x, y, c) in line {
+            prop_assert_ne!(c, 0);
+            *sums.entry(if yx { y } else { x }).or_default() += u32::from(c);
+        }
+        prop_assert_eq!(sums.len(), steps);
+        prop_assert!(sums.values().all(|&sum| sum == 255));
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 10_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn coverage_line_w_i16(
+        (x0, y0, x1, y1) in any::<(i16, i16, i16, i16)>(),
+        (t, w, h) in any::<(u8, u8, u8)>(),
+    ) {
+        // Steps of lines longer than 256 may have a primary point with zero coverage.
+        let line = LineW::<i16>::new(x0, y0, x1, y1);
+        let yx = matches!(line, LineW::Wy(_));
+        let steps = usize::from(line.steps());
+        let mut sums = std::collections::BTreeMap::<i16, u32>::new();
+        for (x, y, c) in line.clone() {
+            prop_assert_ne!(c, 0);
+            *sums.entry(if yx { y } else { x }).or_default() += u32::from(c);
+        }
+        prop_assert_eq!(sums.len(), steps);
+        prop_assert!(sums.values().all(|&sum| sum == 255));
+        // Look at a window around a point of the segment.
+        let at = |v0: i16, v1: i16| (i32::from(v0) + (i32::from(v1) - i32::from(v0)) * i32::from(t) / 256) as i16;
+        let (x, y) = (at(x0, x1), at(y0, y1));
+        let (x_max, y_max) = (x.saturating_add(w.into()), y.saturating_add(h.into()));
+        let clip = Viewport::<i16>::from_min_max(x, y, x_max, y_max).unwrap();
+        let naive = line.filter(|&(x, y, _)| clip.point(x, y));
+        if let Some(smart) = clip.line_w(x0, y0, x1, y1) {
+            prop_assert!(!smart.is_empty(), "clipped == empty");
+            let (lo, _) = smart.size_hint();
+            let smart: Vec<_> = smart.collect();
+            prop_assert!(lo <= smart.len(), "size_hint");
+            prop_assert!(naive.eq(smart), "naive != smart");
+        } else {
+            prop_assert_eq!(naive.count(), 0);
+        }
+    }
+}
+
+fn sample_cap() -> impl Strategy<Value = Cap> {
+    proptest::sample::select(vec![Cap::Butt, Cap::Square, Cap::Round])
+}
+
+macro_rules! test_thick {
+    ($N:literal) => {
+        test_thick!(@ clip, u8, $N);
+        test_thick!(@ clip, i8, $N);
+        test_thick!(@ clip proj, i8, $N);
+        test_thick!(@ viewport, u8, $N);
+        test_thick!(@ viewport proj, u8, $N);
+        test_thick!(@ viewport, i8, $N);
+        test_thick!(@ viewport proj, i8, $N);
+    };
+    (@ $clip:ident, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _line_thick_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, x1, y1) in [<sample_line_b _ $UI>](),
+                    width in 1..=u8::MAX,
+                    cap in sample_cap(),
+                ) {
+                    let raw = LineThick::<$UI>::new(x0, y0, x1, y1, width, cap).unwrap();
+                    let naive = raw.filter_map(|(y, xa, xb)| {
+                        let xa = xa.max(clip.x_min());
+                        let xb = xb.min(clip.x_max());
+                        (clip.y_min() <= y && y <= clip.y_max() && xa <= xb).then_some((y, xa, xb))
+                    });
+                    let smart = clip.line_thick(x0, y0, x1, y1, width, cap);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+    (@ $clip:ident proj, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _line_thick_proj_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, x1, y1) in [<sample_line_b _ $UI>](),
+                    width in 1..=u8::MAX,
+                    cap in sample_cap(),
+                ) {
+                    let raw = LineThick::<$UI>::new(x0, y0, x1, y1, width, cap).unwrap();
+                    let naive = raw.filter_map(|(y, xa, xb)| {
+                        let xa = xa.max(clip.x_min());
+                        let xb = xb.min(clip.x_max());
+                        let (xa, y) = clip.point_proj(xa, y)?;
+                        let (xb, _) = clip.point_proj(xb, clip.y_min())?;
+                        (xa <= xb).then_some((y, xa, xb))
+                    });
+                    let smart = clip.line_thick_proj(x0, y0, x1, y1, width, cap);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+}
+
+/// Rasterizes a thick line point by point, following the definition of [`LineThick`].
+fn naive_line_thick(x0: i8, y0: i8, x1: i8, y1: i8, width: u8, cap: Cap) -> Vec<(i8, i8, i8)> {
+    use std::collections::BTreeMap;
+    let yx = x0.abs_diff(x1) < y0.abs_diff(y1);
+    let (lo, hi) = (i16::from((width - 1) / 2), i16::from(width / 2));
+    let delta = hi - lo;
+    let w2 = i32::from(width).pow(2) - 1;
+    let mut rows = BTreeMap::<i16, Vec<i16>>::new();
+    let mut put = |x: i16, y: i16| rows.entry(y).or_default().push(x);
+    let run = |u: i16, v: i16, put: &mut dyn FnMut(i16, i16)| {
+        for o in v - lo..=v + hi {
+            if yx { put(o, u) } else { put(u, o) }
+        }
+    };
+    let line = LineB::<i8>::new(x0, y0, x1, y1).map(|(x, y)| (i16::from(x), i16::from(y)));
+    let (x0, y0, x1, y1) = (i16::from(x0), i16::from(y0), i16::from(x1), i16::from(y1));
+    for (x, y) in line.chain([(x1, y1)]) {
+        if yx { run(y, x, &mut put) } else { run(x, y, &mut put) }
+    }
+    let (u0, v0, u1, v1) = if yx { (y0, x0, y1, x1) } else { (x0, y0, x1, y1) };
+    let su = if u0 <= u1 { 1 } else { -1 };
+    match cap {
+        Cap::Butt => {}
+        Cap::Square => {
+            for j in 1..=hi {
+                run(u0 - su * j, v0, &mut put);
+                run(u1 + su * j, v1, &mut put);
+            }
+        }
+        Cap::Round => {
+            for (cu, cv) in [(u0, v0), (u1, v1)] {
+                for a in -i16::from(width)..=i16::from(width) {
+                    for m in -i16::from(width)..=i16::from(width) {
+                        let d = i32::from(2 * a).pow(2) + i32::from(2 * m - delta).pow(2);
+                        if d <= w2 {
+                            if yx { put(cv + m, cu + a) } else { put(cu + a, cv + m) }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    let domain = i16::from(i8::MIN)..=i16::from(i8::MAX);
+    rows.into_iter()
+        .filter(|(y, _)| domain.contains(y))
+        .map(|(y, xs)| {
+            let mut xs = xs.into_iter().filter(|x| domain.contains(x)).collect::<Vec<_>>();
+            xs.sort_unstable();
+            xs.dedup();
+            let (xa, xb) = (xs[0], xs[xs.len() - 1]);
+            assert_eq!(xs.len() as i16, xb - xa + 1, "row {y} is not contiguous");
+            (y as i8, xa as i8, xb as i8)
+        })
+        .collect()
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 100_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn shape_line_thick_i8(
+        (x0, y0, x1, y1) in sample_line_b_i8(),
+        width in 1..=24u8,
+        cap in sample_cap(),
+    ) {
+        let naive = naive_line_thick(x0, y0, x1, y1, width, cap);
+        let smart = LineThick::<i8>::new(x0, y0, x1, y1, width, cap).unwrap();
+        prop_assert!(smart.eq(naive), "naive != smart");
+    }
+}
+
+#[test]
+fn line_thick_entire_domain() {
+    let clip = Viewport::<i32>::from_min_max(0, i32::MIN, 0, i32::MAX).unwrap();
+    assert!(clip.line_thick(-10, i32::MIN, -10, i32::MAX, 1, Cap::Butt).is_none());
+    let clip = Viewport::<i64>::from_min_max(0, i64::MIN, 0, i64::MAX).unwrap();
+    assert!(clip.line_thick(-10, i64::MIN, -10, i64::MAX, 1, Cap::Butt).is_none());
+    let mut line = clip.line_thick(-10, i64::MIN, 10, i64::MAX, 3, Cap::Round).unwrap();
+    assert_eq!(line.next().map(|(_, xa, xb)| (xa, xb)), Some((0, 0)));
+}
+
+macro_rules! test_s {
+    ($Line:ident, $line:ident, $line_proj:ident, $N:literal) => {
+        test_s!(@ clip, $Line<u8>, $line, $N);
+        test_s!(@ clip, $Line<i8>, $line, $N);
+        test_s!(@ clip proj, $Line<i8>, $line_proj, $N);
+        test_s!(@ viewport, $Line<u8>, $line, $N);
+        test_s!(@ viewport proj, $Line<u8>, $line_proj, $N);
+        test_s!(@ viewport, $Line<i8>, $line, $N);
+        test_s!(@ viewport proj, $Line<i8>, $line_proj, $N);
+    };
+    (@ $clip:ident, $Line:ident<$UI:ty>, $line:ident, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _ $line _ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, x1, y1) in [<sample_line_s _ $UI>](),
+                ) {
+                    let raw = $Line::<$UI>::new(x0, y0, x1, y1);
+                    let naive = raw.filter(|&(x, y)| clip.point(x, y)).collect::<Vec<_>>();
+                    let smart = clip.$line(x0, y0, x1, y1);
+                    if let Some(smart) = smart {
+                        prop_assert_eq!(usize::from(smart.len()), naive.len(), "len()");
+                        prop_assert!(naive.into_iter().eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.len(), 0);
+                    }
+                }
+            }
+        }
+    };
+    (@ $clip:ident proj, $Line:ident<$UI:ty>, $line_proj:ident, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _ $line_proj _ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, x1, y1) in [<sample_line_s _ $UI>](),
+                ) {
+                    let raw = $Line::<$UI>::new(x0, y0, x1, y1);
+                    let naive = raw.filter_map(|(x, y)| clip.point_proj(x, y)).collect::<Vec<_>>();
+                    let smart = clip.$line_proj(x0, y0, x1, y1);
+                    if let Some(smart) = smart {
+                        prop_assert_eq!(usize::from(smart.len()), naive.len(), "len()");
+                        prop_assert!(naive.into_iter().eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.len(), 0);
+                    }
+                }
+            }
+        }
+    };
+}
+
+/// Returns the cells whose closed squares are touched by the closed segment
+/// between the centers of `(x0, y0)` and `(x1, y1)`, except for the last one.
+fn naive_line_s(x0: i8, y0: i8, x1: i8, y1: i8) -> Vec<(i8, i8)> {
+    let (x0, y0, x1, y1) = (i32::from(x0), i32::from(y0), i32::from(x1), i32::from(y1));
+    let (dx, dy) = (x1 - x0, y1 - y0);
+    let mut cells = Vec::new();
+    for x in x0.min(x1)..=x0.max(x1) {
+        for y in y0.min(y1)..=y0.max(y1) {
+            let side =
+                |cx: i32, cy: i32| (dx * (2 * (y - y0) + cy) - dy * (2 * (x - x0) + cx)).signum();
+            let sides = [side(-1, -1), side(-1, 1), side(1, -1), side(1, 1)];
+            let apart = sides.iter().all(|&s| s > 0) || sides.iter().all(|&s| s < 0);
+            if !apart && (x, y) != (x1, y1) {
+                cells.push((x as i8, y as i8));
+            }
+        }
+    }
+    cells.sort_unstable();
+    cells
+}
+
+prop_compose! {
+    fn sample_line_s_small()(
+        x0 in -12..=12i8,
+        y0 in -12..=12i8,
+        x1 in -12..=12i8,
+        y1 in -12..=12i8,
+    ) -> (i8, i8, i8, i8) {
+        (x0, y0, x1, y1)
+    }
+}
+
+prop_compose! {
+    fn sample_viewport_small()(
+        x_min in -12..=12i8,
+        y_min in -12..=12i8,
+    )(
+        x_max in x_min..=12,
+        y_max in y_min..=12,
+        x_min in Just(x_min),
+        y_min in Just(y_min),
+    ) -> Viewport<i8> {
+        Viewport::<i8>::from_min_max(x_min, y_min, x_max, y_max).unwrap()
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 100_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn shape_line_s_i8((x0, y0, x1, y1) in sample_line_s_i8()) {
+        let line = LineS::<i8>::new(x0, y0, x1, y1);
+        prop_assert_eq!(usize::from(line.len()), line.clone().count(), "len()");
+        let mut cells = line.collect::<Vec<_>>();
+        cells.sort_unstable();
+        prop_assert_eq!(cells, naive_line_s(x0, y0, x1, y1));
+
+        let line = LineS4::<i8>::new(x0, y0, x1, y1);
+        prop_assert_eq!(usize::from(line.len()), x0.abs_diff(x1) as usize + y0.abs_diff(y1) as usize);
+        let cells = line.chain([(x1, y1)]).collect::<Vec<_>>();
+        prop_assert!(cells.windows(2).all(|w| w[0].0.abs_diff(w[1].0) + w[0].1.abs_diff(w[1].1) == 1));
+        let naive = naive_line_s(x0, y0, x1, y1);
+        prop_assert!(cells[..cells.len() - 1].iter().all(|c| naive.binary_search(c).is_ok()));
+    }
+
+    #[test]
+    fn corners_line_s_i8(
+        clip in sample_viewport_small(),
+        (x0, y0, x1, y1) in sample_line_s_small(),
+    ) {
+        let raw = LineS::<i8>::new(x0, y0, x1, y1);
+        let naive = raw.filter(|&(x, y)| clip.point(x, y)).collect::<Vec<_>>();
+        let smart = clip.line_s(x0, y0, x1, y1).map_or(Vec::new(), |line| {
+            assert_eq!(usize::from(line.len()), line.clone().count());
+            line.collect()
+        });
+        prop_assert_eq!(naive, smart);
+
+        let raw = LineS4::<i8>::new(x0, y0, x1, y1);
+        let naive = raw.filter(|&(x, y)| clip.point(x, y)).collect::<Vec<_>>();
+        let smart = clip.line_s4(x0, y0, x1, y1).map_or(Vec::new(), |line| {
+            assert_eq!(usize::from(line.len()), line.clone().count());
+            line.collect()
+        });
+        prop_assert_eq!(naive, smart);
+    }
+}
+
+macro_rules! test_r {
+    ($F:literal, $N:literal) => {
+        test_r!(@ clip, u8, $F, $N);
+        test_r!(@ clip, i8, $F, $N);
+        test_r!(@ clip proj, i8, $F, $N);
+        test_r!(@ viewport, u8, $F, $N);
+        test_r!(@ viewport proj, u8, $F, $N);
+        test_r!(@ viewport, i8, $F, $N);
+        test_r!(@ viewport proj, i8, $F, $N);
+    };
+    (@ $clip:ident, $UI:ty, $F:literal, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _line_r $F _ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, x1, y1) in any::<($UI, $UI, $UI, $UI)>(),
+                ) {
+                    let raw = LineR::<$F, $UI>::new(x0, y0, x1, y1);
+                    let naive = raw.filter(|&(x, y)| clip.point(x, y)).collect::<Vec<_>>();
+                    let smart = clip.line_r::<$F>(x0, y0, x1, y1);
+                    if let Some(smart) = smart {
+                        prop_assert_eq!(usize::from(smart.len()), naive.len(), "len()");
+                        prop_assert!(naive.into_iter().eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.len(), 0);
+                    }
+                }
+            }
+        }
+    };
+    (@ $clip:ident proj, $UI:ty, $F:literal, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _line_r $F _proj_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, x1, y1) in any::<($UI, $UI, $UI, $UI)>(),
+                ) {
+                    let raw = LineR::<$F, $UI>::new(x0, y0, x1, y1);
+                    let naive = raw.filter_map(|(x, y)| clip.point_proj(x, y)).collect::<Vec<_>>();
+                    let smart = clip.line_r_proj::<$F>(x0, y0, x1, y1);
+                    if let Some(smart) = smart {
+                        prop_assert_eq!(usize::from(smart.len()), naive.len(), "len()");
+                        prop_assert!(naive.into_iter().eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.len(), 0);
+                    }
+                }
+            }
+        }
+    };
+}
+
+/// Traverses the cells of a ray by sorting the times of all boundary crossings
+/// in `[0, 1)` as exact fractions, stepping along `X` first on ties.
+fn naive_line_r(f: u32, x0: i8, y0: i8, x1: i8, y1: i8) -> Vec<(i8, i8)> {
+    if (x0, y0) == (x1, y1) {
+        return Vec::new();
+    }
+    let one = 1i64 << f;
+    let crossings = |p0: i8, p1: i8| {
+        let (p0, p1) = (i64::from(p0), i64::from(p1));
+        let s = if p0 <= p1 { 1 } else { -1 };
+        let cell = p0.div_euclid(one);
+        // boundaries strictly ahead in the direction of travel, or at the start going back
+        let first = if 0 < s { (cell + 1) * one } else { cell * one };
+        let times = (0..)
+            .map(move |k| (first + s * k * one - p0).abs())
+            .take_while(move |&r| r < (p1 - p0).abs())
+            .map(move |r| (r, (p1 - p0).abs()));
+        (cell, s, times)
+    };
+    let (cx, sx, tx) = crossings(x0, x1);
+    let (cy, sy, ty) = crossings(y0, y1);
+    let mut events = tx.map(|t| (t, 0)).chain(ty.map(|t| (t, 1))).collect::<Vec<_>>();
+    events.sort_by(|&((a, b), i), &((c, d), j)| (a * d).cmp(&(c * b)).then(i.cmp(&j)));
+    let (mut x, mut y) = (cx, cy);
+    let mut cells = vec![(x as i8, y as i8)];
+    for (_, axis) in events {
+        if axis == 0 {
+            x += sx
+        } else {
+            y += sy
+        }
+        cells.push((x as i8, y as i8));
+    }
+    cells
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 100_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn shape_line_r_i8((x0, y0, x1, y1) in any::<(i8, i8, i8, i8)>()) {
+        prop_assert!(LineR::<0, i8>::new(x0, y0, x1, y1).eq(naive_line_r(0, x0, y0, x1, y1)));
+        prop_assert!(LineR::<3, i8>::new(x0, y0, x1, y1).eq(naive_line_r(3, x0, y0, x1, y1)));
+        prop_assert!(LineR::<7, i8>::new(x0, y0, x1, y1).eq(naive_line_r(7, x0, y0, x1, y1)));
+        let line = LineR::<3, i8>::new(x0, y0, x1, y1);
+        prop_assert_eq!(usize::from(line.len()), line.count());
+    }
+
+    #[test]
+    fn region_line_r_i8(
+        clip in sample_viewport_small(),
+        (x0, y0, x1, y1) in any::<(i8, i8, i8, i8)>(),
+    ) {
+        let raw = LineR::<3, i8>::new(x0, y0, x1, y1);
+        let naive = raw.filter(|&(x, y)| clip.point(x, y)).collect::<Vec<_>>();
+        let smart = clip.line_r::<3>(x0, y0, x1, y1).map_or(Vec::new(), |line| {
+            assert_eq!(usize::from(line.len()), line.clone().count());
+            line.collect()
+        });
+        prop_assert_eq!(naive, smart);
+    }
+}
+
+macro_rules! test_b3 {
+    ($N:literal) => {
+        test_b3!(@ clip3, u8, $N);
+        test_b3!(@ clip3, i8, $N);
+        test_b3!(@ clip3 proj, i8, $N);
+        test_b3!(@ viewport3, u8, $N);
+        test_b3!(@ viewport3 proj, u8, $N);
+        test_b3!(@ viewport3, i8, $N);
+        test_b3!(@ viewport3 proj, i8, $N);
+    };
+    (@ $clip:ident, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _line_b3_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, z0, x1, y1, z1) in any::<($UI, $UI, $UI, $UI, $UI, $UI)>(),
+                ) {
+                    let raw = LineB3::<$UI>::new(x0, y0, z0, x1, y1, z1);
+                    let naive = raw.filter(|&(x, y, z)| clip.point(x, y, z));
+                    let smart = clip.line_b3(x0, y0, z0, x1, y1, z1);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+    (@ $clip:ident proj, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _line_b3_proj_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, z0, x1, y1, z1) in any::<($UI, $UI, $UI, $UI, $UI, $UI)>(),
+                ) {
+                    let raw = LineB3::<$UI>::new(x0, y0, z0, x1, y1, z1);
+                    let naive = raw.filter_map(|(x, y, z)| clip.point_proj(x, y, z));
+                    let smart = clip.line_b3_proj(x0, y0, z0, x1, y1, z1);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 1_000_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn shape_line_b3_i8((x0, y0, z0, x1, y1, z1) in any::<(i8, i8, i8, i8, i8, i8)>()) {
+        let line = LineB3::<i8>::new(x0, y0, z0, x1, y1, z1);
+        let (dx, dy, dz) = (x0.abs_diff(x1), y0.abs_diff(y1), z0.abs_diff(z1));
+        prop_assert_eq!(line.len(), dx.max(dy).max(dz));
+        if dy <= dx && dz <= dx {
+            prop_assert!(line.clone().map(|(x, y, _)| (x, y)).eq(LineB::<i8>::new(x0, y0, x1, y1)));
+            prop_assert!(line.map(|(x, _, z)| (x, z)).eq(LineB::<i8>::new(x0, z0, x1, z1)));
+        } else if dz <= dy {
+            prop_assert!(line.clone().map(|(x, y, _)| (x, y)).eq(LineB::<i8>::new(x0, y0, x1, y1)));
+            prop_assert!(line.map(|(_, y, z)| (z, y)).eq(LineB::<i8>::new(z0, y0, z1, y1)));
+        } else {
+            prop_assert!(line.clone().map(|(x, _, z)| (x, z)).eq(LineB::<i8>::new(x0, z0, x1, z1)));
+            prop_assert!(line.map(|(_, y, z)| (y, z)).eq(LineB::<i8>::new(y0, z0, y1, z1)));
+        }
+    }
+}
+
+macro_rules! test_rev {
+    ($Line:ident $(+ $unwrap:ident)?, $sample_line:ident, $N:literal) => {
+        test_rev!(@ $Line<u8> $(+ $unwrap)?, $sample_line, $N);
+        test_rev!(@ $Line<i8> $(+ $unwrap)?, $sample_line, $N);
+    };
+    (@ $Line:ident<$UI:ty> $(+ $unwrap:ident)?, $sample_line:ident, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<rev_ $sample_line _ $UI>](
+                    (x0, y0, x1, y1) in [<sample_ $sample_line _ $UI>](),
+                ) {
+                    let raw = $Line::<$UI>::new(x0, y0, x1, y1);
+                    $(let raw = raw.$unwrap();)?
+                    let mut fwd = raw.clone().collect::<Vec<_>>();
+                    fwd.reverse();
+                    prop_assert!(fwd.into_iter().eq(raw.rev()), "fwd.rev() != rev");
+                }
+            }
+        }
+    };
+}
+
+macro_rules! test_index {
+    ($Line:ident $(+ $unwrap:ident)?, $sample_line:ident, $N:literal) => {
+        test_index!(@ $Line<u8> $(+ $unwrap)?, $sample_line, $N);
+        test_index!(@ $Line<i8> $(+ $unwrap)?, $sample_line, $N);
+    };
+    (@ $Line:ident<$UI:ty> $(+ $unwrap:ident)?, $sample_line:ident, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<index_ $sample_line _ $UI>](
+                    (x0, y0, x1, y1) in [<sample_ $sample_line _ $UI>](),
+                    i in any::<u8>(),
+                ) {
+                    let raw = $Line::<$UI>::new(x0, y0, x1, y1);
+                    $(let raw = raw.$unwrap();)?
+                    let points = raw.clone().collect::<Vec<_>>();
+                    let len = points.len();
+                    let at = points.get(usize::from(i)).copied();
+                    prop_assert_eq!(raw.point_at(i), at, "point_at");
+                    prop_assert_eq!(raw.clone().nth(usize::from(i)), at, "nth");
+                    let back = len.checked_sub(usize::from(i) + 1).map(|j| points[j]);
+                    prop_assert_eq!(raw.clone().nth_back(usize::from(i)), back, "nth_back");
+                    let mut skipped = raw.clone();
+                    let res = skipped.advance_by(i);
+                    prop_assert_eq!(res.is_ok(), usize::from(i) <= len, "advance_by");
+                    prop_assert!(skipped.eq(points.iter().copied().skip(usize::from(i))));
+                    if usize::from(i) <= len {
+                        let (head, tail) = raw.split_at(i);
+                        prop_assert_eq!(usize::from(head.len()), usize::from(i), "head.len()");
+                        prop_assert!(head.chain(tail).eq(points), "split_at");
+                    }
+                }
+            }
+        }
+    };
+}
+
+test!(LineA + unwrap, line_ax, line_a, line_a_proj, 4_000_000);
+test!(LineA + unwrap, line_ay, line_a, line_a_proj, 4_000_000);
+test!(LineB, line_b, line_b, line_b_proj, 4_000_000);
+test!(LineD + unwrap, line_d, line_d, line_d_proj, 4_000_000);
+test!(LineD2 + unwrap, line_d2, line_d2, line_d2_proj, 4_000_000);
+test_w!(1_000_000);
+test_thick!(250_000);
+test_s!(LineS, line_s, line_s_proj, 1_000_000);
+test_s!(LineS4, line_s4, line_s4_proj, 1_000_000);
+test_r!(0, 250_000);
+test_r!(3, 1_000_000);
+test_b3!(1_000_000);
+
+test_rev!(LineB, line_b, 1_000_000);
+
+test_index!(LineA + unwrap, line_ax, 1_000_000);
+test_index!(LineA + unwrap, line_ay, 1_000_000);
+test_index!(LineB, line_b, 1_000_000);
+test_index!(LineD + unwrap, line_d, 1_000_000);
+test_index!(LineD2 + unwrap, line_d2, 1_000_000);
+
+macro_rules! test_circle {
+    ($N:literal) => {
+        test_circle!(@ clip, u8, $N);
+        test_circle!(@ clip, i8, $N);
+        test_circle!(@ clip proj, i8, $N);
+        test_circle!(@ viewport, u8, $N);
+        test_circle!(@ viewport proj, u8, $N);
+        test_circle!(@ viewport, i8, $N);
+        test_circle!(@ viewport proj, i8, $N);
+    };
+    (@ $clip:ident, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _circle_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (xc, yc, r) in any::<($UI, $UI, u8)>(),
+                    (start, end) in (0..8u8, 0..8u8),
+                ) {
+                    let raw = Circle::<$UI>::arc(xc, yc, r, start, end);
+                    let naive = raw.filter(|&(x, y)| clip.point(x, y));
+                    let smart = clip.arc(xc, yc, r, start, end);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        prop_assert_eq!(smart.len(), smart.clone().count() as u16);
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+    (@ $clip:ident proj, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _circle_proj_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (xc, yc, r) in any::<($UI, $UI, u8)>(),
+                    (start, end) in (0..8u8, 0..8u8),
+                ) {
+                    let raw = Circle::<$UI>::arc(xc, yc, r, start, end);
+                    let naive = raw.filter_map(|(x, y)| clip.point_proj(x, y));
+                    let smart = clip.arc_proj(xc, yc, r, start, end);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        prop_assert_eq!(smart.len(), smart.clone().count() as u16);
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_circle!(100_000);
+
+fn naive_circle(xc: i8, yc: i8, r: u8) -> Vec<(i8, i8)> {
+    let (xc, yc) = (i32::from(xc), i32::from(yc));
+    let (mut x, mut y, mut d) = (i32::from(r), 0, 1 - i32::from(r));
+    let mut points = Vec::new();
+    while y <= x {
+        for (px, py) in [(x, y), (y, x), (-y, x), (-x, y), (-x, -y), (-y, -x), (y, -x), (x, -y)] {
+            if let (Ok(px), Ok(py)) = (i8::try_from(xc + px), i8::try_from(yc + py)) {
+                points.push((px, py));
+            }
+        }
+        y += 1;
+        if d < 0 {
+            d += 2 * y + 1;
+        } else {
+            x -= 1;
+            d += 2 * (y - x) + 1;
+        }
+    }
+    points.sort_unstable();
+    points.dedup();
+    points
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 100_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn shape_circle_i8((xc, yc, r) in any::<(i8, i8, u8)>()) {
+        let circle = Circle::<i8>::new(xc, yc, r);
+        let len = circle.len();
+        let mut points: Vec<_> = circle.collect();
+        prop_assert_eq!(len, points.len() as u16);
+        let (x, y, d) = (i16::from(xc), i16::from(yc), i16::from(r));
+        if -128 <= x - d && x + d <= 127 && -128 <= y - d && y + d <= 127 {
+            for pair in points.windows(2) {
+                let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+                prop_assert!(x0.abs_diff(x1) <= 1 && y0.abs_diff(y1) <= 1, "gap");
+            }
+        }
+        points.sort_unstable();
+        let n = points.len();
+        points.dedup();
+        prop_assert_eq!(n, points.len(), "duplicates");
+        prop_assert_eq!(points, naive_circle(xc, yc, r));
+    }
+
+    #[test]
+    fn arcs_circle_i8((xc, yc, r) in any::<(i8, i8, u8)>(), (a, b) in (0..8u8, 0..8u8)) {
+        let circle = Circle::<i8>::new(xc, yc, r);
+        let first = Circle::<i8>::arc(xc, yc, r, a, b);
+        let second = Circle::<i8>::arc(xc, yc, r, b, a);
+        let mut arcs: Vec<_> = first.chain(second).collect();
+        let mut points: Vec<_> = circle.collect();
+        if a != b {
+            arcs.sort_unstable();
+            points.sort_unstable();
+            prop_assert_eq!(arcs, points);
+        }
+    }
+}
+
+macro_rules! test_disc {
+    ($N:literal) => {
+        test_disc!(@ clip, u8, $N);
+        test_disc!(@ clip, i8, $N);
+        test_disc!(@ clip proj, i8, $N);
+        test_disc!(@ viewport, u8, $N);
+        test_disc!(@ viewport proj, u8, $N);
+        test_disc!(@ viewport, i8, $N);
+        test_disc!(@ viewport proj, i8, $N);
+    };
+    (@ $clip:ident, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _disc_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (xc, yc, r) in any::<($UI, $UI, u8)>(),
+                ) {
+                    let raw = Disc::<$UI>::new(xc, yc, r);
+                    let naive = raw.filter_map(|(y, xa, xb)| {
+                        let xa = xa.max(clip.x_min());
+                        let xb = xb.min(clip.x_max());
+                        (clip.y_min() <= y && y <= clip.y_max() && xa <= xb).then_some((y, xa, xb))
+                    });
+                    let smart = clip.disc(xc, yc, r);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        prop_assert_eq!(smart.len(), smart.clone().count() as u16);
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+    (@ $clip:ident proj, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _disc_proj_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (xc, yc, r) in any::<($UI, $UI, u8)>(),
+                ) {
+                    let raw = Disc::<$UI>::new(xc, yc, r);
+                    let naive = raw.filter_map(|(y, xa, xb)| {
+                        let xa = xa.max(clip.x_min());
+                        let xb = xb.min(clip.x_max());
+                        let (xa, y) = clip.point_proj(xa, y)?;
+                        let (xb, _) = clip.point_proj(xb, clip.y_min())?;
+                        (xa <= xb).then_some((y, xa, xb))
+                    });
+                    let smart = clip.disc_proj(xc, yc, r);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        prop_assert_eq!(smart.len(), smart.clone().count() as u16);
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_disc!(100_000);
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 100_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn shape_disc_i8((xc, yc, r) in any::<(i8, i8, u8)>()) {
+        let mut rows = std::collections::BTreeMap::<i16, (i16, i16)>::new();
+        for (x, y) in Circle::<i16>::new(i16::from(xc), i16::from(yc), u16::from(r)) {
+            let span = rows.entry(y).or_insert((x, x));
+            *span = (span.0.min(x), span.1.max(x));
+        }
+        let naive = rows.into_iter().filter_map(|(y, (xa, xb))| {
+            let y = i8::try_from(y).ok()?;
+            let (xa, xb) = (xa.max(-128) as i8, xb.min(127) as i8);
+            (xa <= xb).then_some((y, xa, xb))
+        });
+        prop_assert!(naive.eq(Disc::<i8>::new(xc, yc, r)));
+    }
+}
+
+macro_rules! test_ellipse {
+    ($N:literal) => {
+        test_ellipse!(@ clip, u8, $N);
+        test_ellipse!(@ clip, i8, $N);
+        test_ellipse!(@ clip proj, i8, $N);
+        test_ellipse!(@ viewport, u8, $N);
+        test_ellipse!(@ viewport proj, u8, $N);
+        test_ellipse!(@ viewport, i8, $N);
+        test_ellipse!(@ viewport proj, i8, $N);
+    };
+    (@ $clip:ident, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _ellipse_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (xc, yc, a, b) in any::<($UI, $UI, u8, u8)>(),
+                    (start, end) in (0..4u8, 0..4u8),
+                ) {
+                    let raw = Ellipse::<$UI>::arc(xc, yc, a, b, start, end);
+                    let naive = raw.filter(|&(x, y)| clip.point(x, y));
+                    let smart = clip.ellipse_arc(xc, yc, a, b, start, end);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        let mut rest = smart.clone();
+                        for n in (0..=smart.clone().count()).rev() {
+                            let (lo, hi) = rest.size_hint();
+                            prop_assert!(lo <= n && hi.is_some_and(|hi| n <= hi), "size_hint");
+                            rest.next();
+                        }
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+
+                #[test]
+                fn [<$clip _ellipse_fill_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (xc, yc, a, b) in any::<($UI, $UI, u8, u8)>(),
+                ) {
+                    let raw = EllipseFill::<$UI>::new(xc, yc, a, b);
+                    let naive = raw.filter_map(|(y, xa, xb)| {
+                        let xa = xa.max(clip.x_min());
+                        let xb = xb.min(clip.x_max());
+                        (clip.y_min() <= y && y <= clip.y_max() && xa <= xb).then_some((y, xa, xb))
+                    });
+                    let smart = clip.ellipse_fill(xc, yc, a, b);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        prop_assert_eq!(smart.len(), smart.clone().count() as u16);
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+    (@ $clip:ident proj, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _ellipse_proj_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (xc, yc, a, b) in any::<($UI, $UI, u8, u8)>(),
+                    (start, end) in (0..4u8, 0..4u8),
+                ) {
+                    let raw = Ellipse::<$UI>::arc(xc, yc, a, b, start, end);
+                    let naive = raw.filter_map(|(x, y)| clip.point_proj(x, y));
+                    let smart = clip.ellipse_arc_proj(xc, yc, a, b, start, end);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+
+                #[test]
+                fn [<$clip _ellipse_fill_proj_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (xc, yc, a, b) in any::<($UI, $UI, u8, u8)>(),
+                ) {
+                    let raw = EllipseFill::<$UI>::new(xc, yc, a, b);
+                    let naive = raw.filter_map(|(y, xa, xb)| {
+                        let xa = xa.max(clip.x_min());
+                        let xb = xb.min(clip.x_max());
+                        let (xa, y) = clip.point_proj(xa, y)?;
+                        let (xb, _) = clip.point_proj(xb, clip.y_min())?;
+                        (xa <= xb).then_some((y, xa, xb))
+                    });
+                    let smart = clip.ellipse_fill_proj(xc, yc, a, b);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        prop_assert_eq!(smart.len(), smart.clone().count() as u16);
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_ellipse!(100_000);
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 10_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn shape_ellipse_i8((xc, yc, a, b) in any::<(i8, i8, u8, u8)>()) {
+        let mut points: Vec<_> = Ellipse::<i8>::new(xc, yc, a, b).collect();
+        let (x, y, a16, b16) = (i16::from(xc), i16::from(yc), i16::from(a), i16::from(b));
+        if -128 <= x - a16 && x + a16 <= 127 && -128 <= y - b16 && y + b16 <= 127 {
+            // Thin ellipses have spikes at the tips, so only quadrants are contiguous.
+            for q in 0..4 {
+                let quadrant: Vec<_> = Ellipse::<i8>::arc(xc, yc, a, b, q, q + 1).collect();
+                for pair in quadrant.windows(2) {
+                    let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+                    prop_assert!(x0.abs_diff(x1) <= 1 && y0.abs_diff(y1) <= 1, "gap");
+                }
+            }
+        }
+        points.sort_unstable();
+        let n = points.len();
+        points.dedup();
+        prop_assert_eq!(n, points.len(), "duplicates");
+        // The outline is made of the filled points with an outward neighbor outside.
+        let rows: std::collections::BTreeMap<_, _> = EllipseFill::<i16>::new(0, 0, a.into(), b.into())
+            .map(|(y, _, xb)| (y, xb))
+            .collect();
+        let filled = |x: i16, y: i16| rows.get(&y).is_some_and(|&w| x.abs() <= w);
+        let mut naive = Vec::new();
+        for (&dy, &w) in &rows {
+            for dx in -w..=w {
+                let (sx, sy) = (if dx < 0 { -1 } else { 1 }, if dy < 0 { -1 } else { 1 });
+                if !filled(dx + sx, dy) || !filled(dx, dy + sy) {
+                    if let (Ok(px), Ok(py)) = (i8::try_from(x + dx), i8::try_from(y + dy)) {
+                        naive.push((px, py));
+                    }
+                }
+            }
+        }
+        naive.sort_unstable();
+        prop_assert_eq!(points, naive);
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 100_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn arcs_ellipse_i8((xc, yc, a, b) in any::<(i8, i8, u8, u8)>(), (s, e) in (0..4u8, 0..4u8)) {
+        let ellipse = Ellipse::<i8>::new(xc, yc, a, b);
+        let first = Ellipse::<i8>::arc(xc, yc, a, b, s, e);
+        let second = Ellipse::<i8>::arc(xc, yc, a, b, e, s);
+        let mut arcs: Vec<_> = first.chain(second).collect();
+        let mut points: Vec<_> = ellipse.collect();
+        if s != e {
+            arcs.sort_unstable();
+            points.sort_unstable();
+            prop_assert_eq!(arcs, points);
+        }
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 10_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn ellipse_i64_matches_i32(
+        (xc, yc, a, b) in any::<(i32, i32, u32, u32)>(),
+        (t, q) in (0.0..=1.0f64, 0..4u8),
+    ) {
+        // Look at a window around a point near the ellipse in quadrant `q`.
+        let dx = (f64::from(a) * (1.0 - t * t).sqrt()) as i64;
+        let dy = (f64::from(b) * t) as i64;
+        let x = if q == 1 || q == 2 { i64::from(xc) - dx } else { i64::from(xc) + dx };
+        let y = if q < 2 { i64::from(yc) + dy } else { i64::from(yc) - dy };
+        let clamp = |v: i64| v.clamp(i32::MIN.into(), i32::MAX.into());
+        let (x_min, y_min, x_max, y_max) = (clamp(x - 8), clamp(y - 8), clamp(x + 8), clamp(y + 8));
+        let clip = Viewport::<i32>::from_min_max(
+            x_min as i32, y_min as i32, x_max as i32, y_max as i32,
+        ).unwrap();
+        let wide = Viewport::<i64>::from_min_max(x_min, y_min, x_max, y_max).unwrap();
+        let narrow = clip.ellipse(xc, yc, a, b).into_iter().flatten();
+        let narrow = narrow.map(|(x, y)| (i64::from(x), i64::from(y)));
+        let ellipse = wide.ellipse(xc.into(), yc.into(), a.into(), b.into());
+        prop_assert!(narrow.eq(ellipse.into_iter().flatten()), "i32 != i64");
+        let narrow = clip.ellipse_fill(xc, yc, a, b).into_iter().flatten();
+        let narrow = narrow.map(|(y, xa, xb)| (i64::from(y), i64::from(xa), i64::from(xb)));
+        let fill = wide.ellipse_fill(xc.into(), yc.into(), a.into(), b.into());
+        prop_assert!(narrow.eq(fill.into_iter().flatten()), "i32 != i64");
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 10_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn ellipse_i64_outline(
+        (a, b) in (0..1u64 << 62, 0..1u64 << 62),
+        (sa, sb) in (0..62u32, 0..62u32),
+        (t, q) in (0.0..=1.0f64, 0..4u8),
+    ) {
+        // Compare a window around a point near the ellipse with the outline of the fill.
+        let (a, b) = (a >> sa, b >> sb);
+        let dx = (a as f64 * (1.0 - t * t).sqrt()) as i64;
+        let dy = (b as f64 * t) as i64;
+        let x = if q == 1 || q == 2 { -dx } else { dx };
+        let y = if q < 2 { dy } else { -dy };
+        let clip = Viewport::<i64>::from_min_max(x - 8, y - 8, x + 8, y + 8).unwrap();
+        let wide = Viewport::<i64>::from_min_max(x - 9, y - 9, x + 9, y + 9).unwrap();
+        let rows: std::collections::BTreeMap<_, _> = wide
+            .ellipse_fill(0, 0, a, b)
+            .into_iter()
+            .flatten()
+            .map(|(y, xa, xb)| (y, (xa, xb)))
+            .collect();
+        let filled = |x: i64, y: i64| rows.get(&y).is_some_and(|&(xa, xb)| xa <= x && x <= xb);
+        let mut naive = Vec::new();
+        for py in y - 8..=y + 8 {
+            for px in x - 8..=x + 8 {
+                let (sx, sy) = (if px < 0 { -1 } else { 1 }, if py < 0 { -1 } else { 1 });
+                if filled(px, py) && (!filled(px + sx, py) || !filled(px, py + sy)) {
+                    naive.push((px, py));
+                }
+            }
+        }
+        let mut points: Vec<_> = clip.ellipse(0, 0, a, b).into_iter().flatten().collect();
+        points.sort_unstable();
+        naive.sort_unstable();
+        prop_assert_eq!(points, naive);
+    }
+}
+
+#[test]
+fn ellipse_entire_domain() {
+    let clip = Viewport::<i64>::from_min_max(0, i64::MAX - 1, 1, i64::MAX).unwrap();
+    let ellipse = clip.ellipse(0, 0, u64::MAX, u64::MAX >> 1).unwrap();
+    assert_eq!(ellipse.collect::<Vec<_>>(), [(1, i64::MAX), (0, i64::MAX)]);
+    let mut fill = EllipseFill::<u64>::new(0, 0, u64::MAX, u64::MAX);
+    assert_eq!(fill.next(), Some((0, 0, u64::MAX)));
+    assert_eq!(fill.size_hint(), (usize::MAX, Some(usize::MAX)));
+}
+
+macro_rules! test_polyline {
+    ($N:literal) => {
+        test_polyline!(@ clip, u8, $N);
+        test_polyline!(@ clip, i8, $N);
+        test_polyline!(@ viewport, u8, $N);
+        test_polyline!(@ viewport, i8, $N);
+    };
+    (@ $clip:ident, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _polyline_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    points in proptest::collection::vec(any::<($UI, $UI)>(), 0..8),
+                    closed in any::<bool>(),
+                ) {
+                    let raw = Polyline::<$UI>::new(&points, closed);
+                    let naive = raw.filter(|&(x, y)| clip.point(x, y));
+                    let smart = clip.polyline(&points, closed);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        let (lo, hi) = smart.size_hint();
+                        let len = smart.clone().count();
+                        prop_assert!(lo <= len && hi.is_some_and(|hi| len <= hi), "size_hint");
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_polyline!(100_000);
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 100_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn shape_polyline_i8(
+        points in proptest::collection::vec(any::<(i8, i8)>(), 0..8),
+        closed in any::<bool>(),
+    ) {
+        let mut naive = Vec::new();
+        let n = points.len();
+        let segments = if closed { n } else { n.saturating_sub(1) };
+        for i in 0..segments {
+            let ((x0, y0), (x1, y1)) = (points[i], points[(i + 1) % n]);
+            naive.extend(LineB::<i8>::new(x0, y0, x1, y1));
+        }
+        if let Some(&last) = points.last() {
+            if !closed || points.iter().all(|&point| point == last) {
+                naive.push(last);
+            }
+        }
+        let polyline: Vec<_> = Polyline::<i8>::new(&points, closed).collect();
+        let mut rest = Polyline::<i8>::new(&points, closed);
+        for len in (0..=polyline.len()).rev() {
+            let (lo, hi) = rest.size_hint();
+            prop_assert!(lo <= len && hi.is_some_and(|hi| len <= hi), "size_hint");
+            rest.next();
+        }
+        for pair in polyline.windows(2) {
+            let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+            prop_assert!(x0.abs_diff(x1) <= 1 && y0.abs_diff(y1) <= 1, "gap");
+            prop_assert!((x0, y0) != (x1, y1), "duplicate");
+        }
+        prop_assert_eq!(polyline, naive);
+    }
+}
+
+#[test]
+fn polyline_coincident() {
+    for points in [&[(3, 4)][..], &[(3, 4), (3, 4), (3, 4)]] {
+        assert!(Polyline::<i8>::new(points, false).eq([(3, 4)]));
+        assert!(Polyline::<i8>::new(points, true).eq([(3, 4)]));
+    }
+    let clip = Clip::<i8>::from_max(2, 2).unwrap();
+    assert!(clip.polyline(&[(3, 4), (3, 4)], true).is_none());
+    assert!(clip.polyline(&[(1, 2), (1, 2)], true).unwrap().eq([(1, 2)]));
+}
+
+macro_rules! test_polygon_fill {
+    ($N:literal) => {
+        test_polygon_fill!(@ clip, u8, $N);
+        test_polygon_fill!(@ clip, i8, $N);
+        test_polygon_fill!(@ viewport, u8, $N);
+        test_polygon_fill!(@ viewport, i8, $N);
+    };
+    (@ $clip:ident, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _polygon_fill_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    points in proptest::collection::vec(any::<($UI, $UI)>(), 0..6),
+                    rule in proptest::sample::select(vec![FillRule::EvenOdd, FillRule::NonZero]),
+                ) {
+                    let raw = PolygonFill::<$UI>::new(&points, rule);
+                    let naive = raw.filter_map(|(y, xa, xb)| {
+                        let xa = xa.max(clip.x_min());
+                        let xb = xb.min(clip.x_max());
+                        (clip.y_min() <= y && y <= clip.y_max() && xa <= xb).then_some((y, xa, xb))
+                    });
+                    let smart = clip.polygon_fill(&points, rule);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_polygon_fill!(10_000);
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 10_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn shape_polygon_fill_i8(points in proptest::collection::vec(any::<(i8, i8)>(), 0..6)) {
+        let even_odd: Vec<_> = PolygonFill::<i8>::new(&points, FillRule::EvenOdd).collect();
+        let non_zero: Vec<_> = PolygonFill::<i8>::new(&points, FillRule::NonZero).collect();
+        let inside = |spans: &[(i8, i8, i8)], x: i8, y: i8| {
+            spans.iter().any(|&(sy, xa, xb)| sy == y && xa <= x && x <= xb)
+        };
+        for spans in [&even_odd, &non_zero] {
+            for pair in spans.windows(2) {
+                let ((y0, _, xb0), (y1, xa1, _)) = (pair[0], pair[1]);
+                prop_assert!(y0 < y1 || y0 == y1 && i16::from(xb0) + 1 < i16::from(xa1), "order");
+            }
+            for (x, y) in Polyline::<i8>::new(&points, true) {
+                prop_assert!(inside(spans, x, y), "stroke outside of fill");
+            }
+        }
+        for &(y, xa, xb) in &even_odd {
+            for x in xa..=xb {
+                prop_assert!(inside(&non_zero, x, y), "even-odd outside of non-zero");
+            }
+        }
+    }
+}
+
+#[test]
+fn polygon_fill_entire_domain() {
+    let (min, max) = (i64::MIN, i64::MAX);
+    let clip = Viewport::<i64>::from_min_max(0, min, 0, max).unwrap();
+    let beside = [(-10, min), (-5, min), (-5, max), (-10, max)];
+    assert!(clip.polygon_fill(&beside, FillRule::EvenOdd).is_none());
+    let across = [(min, min), (min + 4, min), (max, max), (max - 4, max)];
+    let spans: Vec<_> = clip.polygon_fill(&across, FillRule::EvenOdd).unwrap().collect();
+    assert!(spans.iter().all(|&(y, xa, xb)| (-4..=4).contains(&y) && (xa, xb) == (0, 0)));
+    let clip = Viewport::<i64>::from_min_max(-2, -2, 2, 2).unwrap();
+    let around = [(min, min), (max, min), (0, max)];
+    let spans = clip.polygon_fill(&around, FillRule::NonZero).unwrap();
+    assert!(spans.eq((-2..=2).map(|y| (y, -2, 2))));
+}
+
+fn naive_triangle(v: [(i64, i64); 3], x: i64, y: i64) -> Option<(i64, i64, i64)> {
+    let area = (v[1].0 - v[0].0) * (v[2].1 - v[0].1) - (v[1].1 - v[0].1) * (v[2].0 - v[0].0);
+    let mut w = [0; 3];
+    for i in 0..3 {
+        let (a, b) = (v[(i + 1) % 3], v[(i + 2) % 3]);
+        let (dx, dy) = (area.signum() * (b.0 - a.0), area.signum() * (b.1 - a.1));
+        w[i] = dx * (y - a.1) - dy * (x - a.0);
+        let top_left = dy < 0 || dy == 0 && 0 < dx;
+        if area == 0 || w[i] < 0 || w[i] == 0 && !top_left {
+            return None;
+        }
+    }
+    Some((w[0], w[1], w[2]))
+}
+
+macro_rules! test_triangle {
+    ($N:literal) => {
+        test_triangle!(@ clip, u8, $N);
+        test_triangle!(@ clip, i8, $N);
+        test_triangle!(@ clip proj, i8, $N);
+        test_triangle!(@ viewport, u8, $N);
+        test_triangle!(@ viewport proj, u8, $N);
+        test_triangle!(@ viewport, i8, $N);
+        test_triangle!(@ viewport proj, i8, $N);
+    };
+    (@ $clip:ident, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _triangle_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, x1, y1, x2, y2) in any::<($UI, $UI, $UI, $UI, $UI, $UI)>(),
+                ) {
+                    let Some(raw) = Triangle::<$UI>::new(x0, y0, x1, y1, x2, y2) else {
+                        prop_assert!(clip.triangle(x0, y0, x1, y1, x2, y2).is_none());
+                        return Ok(());
+                    };
+                    let (s0, s1, s2) = raw.weight_steps();
+                    let naive = raw.filter_map(|(y, xa, xb, (w0, w1, w2))| {
+                        let xa_clip = xa.max(clip.x_min());
+                        let xb = xb.min(clip.x_max());
+                        let n = i16::from(xa_clip) - i16::from(xa);
+                        let w = (
+                            w0.wrapping_add(n.wrapping_mul(s0)),
+                            w1.wrapping_add(n.wrapping_mul(s1)),
+                            w2.wrapping_add(n.wrapping_mul(s2)),
+                        );
+                        (clip.y_min() <= y && y <= clip.y_max() && xa_clip <= xb)
+                            .then_some((y, xa_clip, xb, w))
+                    });
+                    let smart = clip.triangle(x0, y0, x1, y1, x2, y2);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+    (@ $clip:ident proj, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _triangle_proj_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, x1, y1, x2, y2) in any::<($UI, $UI, $UI, $UI, $UI, $UI)>(),
+                ) {
+                    let Some(raw) = Triangle::<$UI>::new(x0, y0, x1, y1, x2, y2) else {
+                        prop_assert!(clip.triangle_proj(x0, y0, x1, y1, x2, y2).is_none());
+                        return Ok(());
+                    };
+                    let (s0, s1, s2) = raw.weight_steps();
+                    let naive = raw.filter_map(|(y, xa, xb, (w0, w1, w2))| {
+                        let xa_clip = xa.max(clip.x_min());
+                        let xb = xb.min(clip.x_max());
+                        let n = i16::from(xa_clip) - i16::from(xa);
+                        let w = (
+                            w0.wrapping_add(n.wrapping_mul(s0)),
+                            w1.wrapping_add(n.wrapping_mul(s1)),
+                            w2.wrapping_add(n.wrapping_mul(s2)),
+                        );
+                        let (xa_clip, y) = clip.point_proj(xa_clip, y)?;
+                        let (xb, _) = clip.point_proj(xb, clip.y_min())?;
+                        (xa_clip <= xb).then_some((y, xa_clip, xb, w))
+                    });
+                    let smart = clip.triangle_proj(x0, y0, x1, y1, x2, y2);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_triangle!(100_000);
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 10_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn shape_triangle_i8((x0, y0, x1, y1, x2, y2) in any::<(i8, i8, i8, i8, i8, i8)>()) {
+        let v = [(x0, y0), (x1, y1), (x2, y2)].map(|(x, y)| (i64::from(x), i64::from(y)));
+        let Some(triangle) = Triangle::<i8>::new(x0, y0, x1, y1, x2, y2) else {
+            let area = (v[1].0 - v[0].0) * (v[2].1 - v[0].1) - (v[1].1 - v[0].1) * (v[2].0 - v[0].0);
+            prop_assert!(area.abs() > i64::from(i16::MAX));
+            return Ok(());
+        };
+        let (s0, s1, s2) = triangle.weight_steps();
+        let mut points = Vec::new();
+        for (y, xa, xb, (w0, w1, w2)) in triangle {
+            for x in xa..=xb {
+                let n = i16::from(x) - i16::from(xa);
+                let w = (w0 + n * s0, w1 + n * s1, w2 + n * s2);
+                points.push((x, y, (i64::from(w.0), i64::from(w.1), i64::from(w.2))));
+            }
+        }
+        let mut naive = Vec::new();
+        for y in i8::MIN..=i8::MAX {
+            for x in i8::MIN..=i8::MAX {
+                if let Some(w) = naive_triangle(v, i64::from(x), i64::from(y)) {
+                    naive.push((x, y, w));
+                }
+            }
+        }
+        prop_assert_eq!(points, naive);
+    }
+
+    #[test]
+    fn tiling_triangle_i8((x0, y0, x1, y1, x2, y2, x3, y3) in any::<(i8, i8, i8, i8, i8, i8, i8, i8)>()) {
+        let (Some(first), Some(second)) = (
+            Triangle::<i8>::new(x0, y0, x1, y1, x2, y2),
+            Triangle::<i8>::new(x2, y2, x1, y1, x3, y3),
+        ) else {
+            return Ok(());
+        };
+        let side = |x: i8, y: i8| {
+            let (x, y) = (i32::from(x), i32::from(y));
+            let (x1, y1, x2, y2) = (i32::from(x1), i32::from(y1), i32::from(x2), i32::from(y2));
+            ((x2 - x1) * (y - y1) - (y2 - y1) * (x - x1)).signum()
+        };
+        if side(x0, y0) * side(x3, y3) >= 0 {
+            return Ok(());
+        }
+        let mut points = std::collections::HashSet::new();
+        for (y, xa, xb, _) in first.chain(second) {
+            for x in xa..=xb {
+                prop_assert!(points.insert((x, y)), "overlap");
+            }
+        }
+        // The shared edge is covered by exactly one of the triangles.
+        for (x, y) in LineB::<i8>::new(x1, y1, x2, y2) {
+            let inside = |v: [(i8, i8); 3]| {
+                let v = v.map(|(x, y)| (i64::from(x), i64::from(y)));
+                naive_triangle(v, i64::from(x), i64::from(y)).is_some()
+            };
+            let on_edge = side(x, y) == 0;
+            let covered = inside([(x0, y0), (x1, y1), (x2, y2)]) || inside([(x2, y2), (x1, y1), (x3, y3)]);
+            prop_assert!(!on_edge || covered || (x, y) == (x1, y1) || (x, y) == (x2, y2), "gap");
+        }
+    }
+}
+
+#[test]
+fn triangle_entire_domain() {
+    let (min, max) = (i64::MIN, i64::MAX);
+    let clip = Viewport::<i64>::from_min_max(0, min, 0, max).unwrap();
+    assert!(clip.triangle(-10, min, -5, max, -10, max).is_none());
+    assert!(clip.triangle(min, min, max, max, min + 4, min).is_some_and(|triangle| {
+        triangle.map(|(y, xa, xb, _)| (y, xa, xb)).eq((-2..=0).map(|y| (y, 0, 0)))
+    }));
+    let clip = Viewport::<i64>::from_min_max(-2, -2, 2, 2).unwrap();
+    let triangle = clip.triangle(min / 2, min / 2, max / 2, min / 2, 0, max / 2).unwrap();
+    assert!(triangle.map(|(y, xa, xb, _)| (y, xa, xb)).eq((-2..=2).map(|y| (y, -2, 2))));
+}
+
+#[test]
+fn triangle_sliver() {
+    let sliver = Triangle::<i32>::new(0, 0, 1, 1 << 30, 0, 1).unwrap();
+    assert!(sliver.map(|(y, xa, xb, _)| (y, xa, xb)).eq([(1, 0, 0)]));
+    let sliver = Triangle::<i64>::new(0, 0, 1, 1 << 62, 0, 1).unwrap();
+    assert!(sliver.map(|(y, xa, xb, _)| (y, xa, xb)).eq([(1, 0, 0)]));
+    let sliver = Triangle::<i64>::new(0, 1, 2, 1 << 62, 2, (1 << 62) + 1).unwrap();
+    assert!(sliver.map(|(y, xa, xb, _)| (y, xa, xb)).eq([((1 << 61) + 1, 1, 1)]));
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 1_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn sliver_triangle_i16(
+        (x0, y0) in (-8000..8000i64, -8000..8000i64),
+        (dx, dy) in (-24000..24000i64, -24000..24000i64),
+        (ex, ey) in (-3..=3i64, -3..=3i64),
+        (cx, w) in (-64..64i64, 0..64i64),
+        far in any::<bool>(),
+    ) {
+        let (x1, y1) = (x0 + dx, y0 + dy);
+        let (x2, y2) = if far { (x1 + ex, y1 + ey) } else { (x0 + ex, y0 + ey) };
+        let v = [(x0, y0), (x1, y1), (x2, y2)];
+        let c = |v: i64| i16::try_from(v);
+        let (Ok(x1), Ok(y1), Ok(x2), Ok(y2)) = (c(x1), c(y1), c(x2), c(y2)) else {
+            return Ok(());
+        };
+        let (x0, y0, cx) = (x0 as i16, y0 as i16, (v[0].0 + dx / 2 + cx) as i16);
+        let clip = Viewport::<i16>::from_min_max(cx, i16::MIN, cx + w as i16, i16::MAX).unwrap();
+        let spans = clip.triangle(x0, y0, x1, y1, x2, y2).into_iter().flatten();
+        let spans: Vec<_> = spans.map(|(y, xa, xb, _)| (y, xa, xb)).collect();
+        let mut naive = Vec::new();
+        for y in y0.min(y1).min(y2)..=y0.max(y1).max(y2) {
+            let mut xs = (cx..=cx + w as i16).filter(|&x| naive_triangle(v, x.into(), y.into()).is_some());
+            if let Some(xa) = xs.next() {
+                naive.push((y, xa, xs.next_back().unwrap_or(xa)));
+            }
+        }
+        prop_assert_eq!(spans, naive);
+    }
+}
+
+macro_rules! test_rect {
+    ($N:literal) => {
+        test_rect!(@ clip, u8, $N);
+        test_rect!(@ clip, i8, $N);
+        test_rect!(@ clip proj, i8, $N);
+        test_rect!(@ viewport, u8, $N);
+        test_rect!(@ viewport proj, u8, $N);
+        test_rect!(@ viewport, i8, $N);
+        test_rect!(@ viewport proj, i8, $N);
+    };
+    (@ $clip:ident, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _rect_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, x1, y1) in any::<($UI, $UI, $UI, $UI)>(),
+                ) {
+                    let raw = RectFill::<$UI>::new(x0, y0, x1, y1);
+                    let naive = raw.filter_map(|(y, xa, xb)| {
+                        let xa = xa.max(clip.x_min());
+                        let xb = xb.min(clip.x_max());
+                        (clip.y_min() <= y && y <= clip.y_max() && xa <= xb).then_some((y, xa, xb))
+                    });
+                    let smart = clip.rect(x0, y0, x1, y1);
+                    if let Some(smart) = smart {
+                        let smart = RectFill::<$UI>::new(
+                            smart.x_min(),
+                            smart.y_min(),
+                            smart.x_max(),
+                            smart.y_max(),
+                        );
+                        prop_assert_eq!(smart.len(), smart.clone().count() as u16);
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+    (@ $clip:ident proj, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _rect_proj_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, x1, y1) in any::<($UI, $UI, $UI, $UI)>(),
+                ) {
+                    let raw = RectFill::<$UI>::new(x0, y0, x1, y1);
+                    let naive = raw.filter_map(|(y, xa, xb)| {
+                        let xa = xa.max(clip.x_min());
+                        let xb = xb.min(clip.x_max());
+                        let (xa, y) = clip.point_proj(xa, y)?;
+                        let (xb, _) = clip.point_proj(xb, clip.y_min())?;
+                        (xa <= xb).then_some((y, xa, xb))
+                    });
+                    let smart = clip.rect_proj(x0, y0, x1, y1);
+                    if let Some(smart) = smart {
+                        let smart = RectFill::<u8>::new(
+                            smart.x_min(),
+                            smart.y_min(),
+                            smart.x_max(),
+                            smart.y_max(),
+                        );
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_rect!(100_000);
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 100_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn shape_rect_i8((x0, y0, x1, y1) in any::<(i8, i8, i8, i8)>()) {
+        let rect = Rect::<i8>::new(x0, y0, x1, y1);
+        prop_assert_eq!(rect.len(), rect.clone().count() as u16);
+        let points = rect.clone().collect::<Vec<_>>();
+        let mut rev = rect.rev().collect::<Vec<_>>();
+        rev.reverse();
+        prop_assert_eq!(&points, &rev);
+        prop_assert_eq!(points.first(), Some(&(x0, y0)));
+        for pair in points.windows(2) {
+            let (dx, dy) = (pair[0].0.abs_diff(pair[1].0), pair[0].1.abs_diff(pair[1].1));
+            prop_assert_eq!(dx + dy, 1, "not 4-connected");
+        }
+        let mut naive = Vec::new();
+        for (y, xa, xb) in RectFill::<i8>::new(x0, y0, x1, y1) {
+            for x in xa..=xb {
+                if x == x0 || x == x1 || y == y0 || y == y1 {
+                    naive.push((x, y));
+                }
+            }
+        }
+        let mut sorted = points;
+        sorted.sort_unstable_by_key(|&(x, y)| (y, x));
+        prop_assert_eq!(sorted, naive);
+    }
+}
+
+macro_rules! test_region {
+    ($N:literal) => {
+        test_region!(@ u8, i8, $N);
+        test_region!(@ i8, i8, $N);
+    };
+    (@ $UI:ty, $I:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<region_ $UI>](
+                    a in [<sample_viewport_ $UI>](),
+                    b in [<sample_viewport_ $UI>](),
+                    (x, y) in any::<($UI, $UI)>(),
+                    (dx, dy) in any::<($I, $I)>(),
+                    (mx, my) in any::<(u8, u8)>(),
+                ) {
+                    let both = a.point(x, y) && b.point(x, y);
+                    let union = a.bounding_union(&b);
+                    prop_assert_eq!(a.intersect(&b).is_some_and(|r| r.point(x, y)), both);
+                    prop_assert!(union.contains_viewport(&a) && union.contains_viewport(&b));
+                    prop_assert_eq!(
+                        a.contains_viewport(&b),
+                        a.intersect(&b).as_ref() == Some(&b),
+                    );
+                    prop_assert_eq!(a.to_clip().map(|c| c.to_viewport()), (a.x_min() == 0 && a.y_min() == 0).then(|| a.clone()));
+
+                    let (x_min, y_min) = (i16::from(a.x_min()), i16::from(a.y_min()));
+                    let (x_max, y_max) = (i16::from(a.x_max()), i16::from(a.y_max()));
+                    let naive = |x_min: i16, y_min: i16, x_max: i16, y_max: i16| {
+                        let x_min = <$UI>::try_from(x_min).ok()?;
+                        let y_min = <$UI>::try_from(y_min).ok()?;
+                        let x_max = <$UI>::try_from(x_max).ok()?;
+                        let y_max = <$UI>::try_from(y_max).ok()?;
+                        Viewport::<$UI>::from_min_max(x_min, y_min, x_max, y_max)
+                    };
+                    let (dx, dy) = (i16::from(dx), i16::from(dy));
+                    prop_assert_eq!(
+                        a.translate(dx as $I, dy as $I),
+                        naive(x_min + dx, y_min + dy, x_max + dx, y_max + dy),
+                    );
+                    let (mx, my) = (i16::from(mx), i16::from(my));
+                    prop_assert_eq!(
+                        a.expand(mx as u8, my as u8),
+                        naive(x_min - mx, y_min - my, x_max + mx, y_max + my),
+                    );
+                    prop_assert_eq!(
+                        a.shrink(mx as u8, my as u8),
+                        naive(x_min + mx, y_min + my, x_max - mx, y_max - my),
+                    );
+                }
+            }
+        }
+    };
+}
+
+test_region!(100_000);
+
+macro_rules! test_size {
+    ($N:literal) => {
+        test_size!(@ u8, $N);
+        test_size!(@ i8, $N);
+    };
+    (@ $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<size_ $UI>](
+                    clip in [<sample_clip_ $UI>](),
+                    viewport in [<sample_viewport_ $UI>](),
+                    (x_min, y_min) in any::<($UI, $UI)>(),
+                    (width, height) in (0..=300u16, 0..=300u16),
+                ) {
+                    let w = |min: $UI, max: $UI| (i32::from(max) - i32::from(min) + 1) as u16;
+                    prop_assert_eq!(clip.width(), w(0, clip.x_max()));
+                    prop_assert_eq!(clip.height(), w(0, clip.y_max()));
+                    prop_assert_eq!(clip.area(), clip.width().checked_mul(clip.height()));
+                    prop_assert_eq!(viewport.width(), w(viewport.x_min(), viewport.x_max()));
+                    prop_assert_eq!(viewport.height(), w(viewport.y_min(), viewport.y_max()));
+                    prop_assert_eq!(viewport.area(), viewport.width().checked_mul(viewport.height()));
+
+                    let smart = Clip::<$UI>::from_wide_size(width, height);
+                    if let Some(smart) = smart {
+                        prop_assert_eq!((smart.width(), smart.height()), (width, height));
+                    } else {
+                        prop_assert!(
+                            width == 0 || height == 0
+                                || i32::from(<$UI>::MAX) < i32::from(width) - 1
+                                || i32::from(<$UI>::MAX) < i32::from(height) - 1
+                        );
+                    }
+
+                    let smart = Viewport::<$UI>::from_min_wide_size(x_min, y_min, width, height);
+                    let fits = |min: $UI, size: u16| {
+                        size != 0 && i32::from(min) + i32::from(size) - 1 <= i32::from(<$UI>::MAX)
+                    };
+                    prop_assert_eq!(smart.is_some(), fits(x_min, width) && fits(y_min, height));
+                    if let Some(smart) = smart {
+                        prop_assert_eq!((smart.x_min(), smart.y_min()), (x_min, y_min));
+                        prop_assert_eq!((smart.width(), smart.height()), (width, height));
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_size!(100_000);
+
+#[test]
+fn size_entire_domain() {
+    let clip = Clip::<u64>::from_wide_size(1 << 64, 1 << 64).unwrap();
+    assert_eq!((clip.x_max(), clip.y_max()), (u64::MAX, u64::MAX));
+    assert_eq!((clip.width(), clip.height(), clip.area()), (1 << 64, 1 << 64, None));
+    let viewport = Viewport::<i64>::from_min_wide_size(i64::MIN, i64::MIN, 1 << 64, 1).unwrap();
+    assert_eq!((viewport.x_max(), viewport.y_max()), (i64::MAX, i64::MIN));
+    assert_eq!((viewport.width(), viewport.height(), viewport.area()), (1 << 64, 1, Some(1 << 64)));
+    assert_eq!(Viewport::<i64>::from_min_wide_size(i64::MIN + 1, 0, 1 << 64, 1), None);
+}
+
+fn naive_hull(points: &[(i8, i8)]) -> Vec<(i8, i8)> {
+    let cross = |o: (i8, i8), a: (i8, i8), b: (i8, i8)| {
+        let (ox, oy) = (i32::from(o.0), i32::from(o.1));
+        (i32::from(a.0) - ox) * (i32::from(b.1) - oy)
+            - (i32::from(a.1) - oy) * (i32::from(b.0) - ox)
+    };
+    let mut sorted = points.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+    let mut hull: Vec<(i8, i8)> = Vec::new();
+    for pass in 0..2 {
+        let start = hull.len();
+        for &p in &sorted {
+            while hull.len() >= start + 2
+                && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0
+            {
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        hull.pop();
+        if pass == 0 {
+            sorted.reverse();
+        }
+    }
+    hull
+}
+
+fn naive_in_hull(hull: &[(i8, i8)], (x, y): (i8, i8)) -> bool {
+    let (x, y) = (i32::from(x), i32::from(y));
+    let n = hull.len();
+    (0..n).all(|i| {
+        let (ax, ay) = (i32::from(hull[i].0), i32::from(hull[i].1));
+        let (bx, by) = (i32::from(hull[(i + 1) % n].0), i32::from(hull[(i + 1) % n].1));
+        let side = (bx - ax) * (y - ay) - (by - ay) * (x - ax);
+        let within = ax.min(bx) <= x && x <= ax.max(bx) && ay.min(by) <= y && y <= ay.max(by);
+        if n < 3 { side == 0 && within } else { side >= 0 }
+    })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 10_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn convex_line_b_i8(
+        points in proptest::collection::vec(any::<(i8, i8)>(), 1..8),
+        reverse in any::<bool>(),
+        (x0, y0, x1, y1) in any::<(i8, i8, i8, i8)>(),
+    ) {
+        let hull = naive_hull(&points);
+        let mut ordered = hull.clone();
+        if reverse {
+            ordered.reverse();
+        }
+        let region = Convex::<i8>::new(&ordered).unwrap();
+        let inside = LineB::<i8>::new(x0, y0, x1, y1)
+            .map(|p| naive_in_hull(&hull, p).then_some(p))
+            .collect::<Vec<_>>();
+        let naive = inside.chunk_by(|a, b| a.is_some() == b.is_some())
+            .filter(|run| run[0].is_some())
+            .map(|run| run.iter().flatten().copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let smart = region.line_b(x0, y0, x1, y1);
+        if let Some(smart) = smart {
+            prop_assert!(!smart.is_empty(), "clipped == empty");
+            let smart = smart.map(|run| run.collect::<Vec<_>>()).collect::<Vec<_>>();
+            prop_assert_eq!(naive, smart);
+        } else {
+            prop_assert!(naive.is_empty());
+        }
+    }
+
+    #[test]
+    fn convex_new_i8(
+        points in proptest::collection::vec(any::<(i8, i8)>(), 1..6),
+        (x, y) in any::<(i8, i8)>(),
+    ) {
+        let hull = naive_hull(&points);
+        let Some(region) = Convex::<i8>::new(&points) else {
+            return Ok(());
+        };
+        prop_assert_eq!(region.point(x, y), naive_in_hull(&hull, (x, y)));
+        for &(x, y) in &points {
+            prop_assert!(region.point(x, y));
+        }
+    }
+}
+
+#[test]
+fn convex_line_b_along_edge() {
+    let diamond = [(0, 8), (16, 0), (32, 8), (16, 16)];
+    let region = Convex::<i8>::new(&diamond).unwrap();
+    let runs = region.line_b(-4, 10, 4, 6).unwrap().map(Iterator::collect::<Vec<_>>);
+    assert!(runs.eq([vec![(0, 8)], vec![(2, 7)]]));
+}
+
+#[test]
+fn convex_line_b_entire_domain() {
+    let (min, max) = (i64::MIN, i64::MAX);
+    let quad = [(min, -1), (max, 1 << 62), (max, max), (min, max)];
+    let region = Convex::<i64>::new(&quad).unwrap();
+    let line = LineB::<i64>::new(min, 0, max, 1 << 62);
+    let at = |x: i64| line.clone().nth(x.abs_diff(min) as usize).unwrap();
+    let inside = |x: i64| region.point(at(x).0, at(x).1);
+    let mut runs = region.line_b(min, 0, max, 1 << 62).unwrap();
+    assert_eq!(runs.next().unwrap().head(), Some((min, 0)));
+    for run in runs.skip(1 << 16).take(16) {
+        let (xa, xb) = (run.head().unwrap().0, run.tail().unwrap().0);
+        assert!(!inside(xa - 1) && !inside(xb + 1));
+        assert!((xa..=xb).all(inside));
+        assert!(run.eq((xa..=xb).map(at)));
+    }
+}
+
+#[test]
+fn convex_new_rejects() {
+    assert!(Convex::<i8>::new(&[]).is_none());
+    assert!(Convex::<i8>::new(&[(0, 0), (4, 0), (4, 4), (2, 1), (0, 4)]).is_none());
+    assert!(Convex::<i8>::new(&[(0, 0), (4, 0), (6, 0), (4, 0), (4, 4), (0, 4)]).is_none());
+    let pentagram = [(0, -10), (6, 8), (-10, -3), (10, -3), (-6, 8)];
+    assert!(Convex::<i8>::new(&pentagram).is_none());
+    assert!(Convex::<i8>::new(&[(0, 0), (4, 0), (4, 4), (4, 4), (0, 4)]).is_some());
+}
+
+macro_rules! test_excluding {
+    ($Line:ident $(+ $unwrap:ident)?, $sample_line:ident, $line:ident, $N:literal) => {
+        test_excluding!(@ $Line<u8> $(+ $unwrap)?, clip, $sample_line, $line, $N);
+        test_excluding!(@ $Line<i8> $(+ $unwrap)?, clip, $sample_line, $line, $N);
+        test_excluding!(@ $Line<u8> $(+ $unwrap)?, viewport, $sample_line, $line, $N);
+        test_excluding!(@ $Line<i8> $(+ $unwrap)?, viewport, $sample_line, $line, $N);
+    };
+    (@ $Line:ident<$UI:ty> $(+ $unwrap:ident)?, $clip:ident, $sample_line:ident, $line:ident, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _ $sample_line _excluding_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    holes in proptest::collection::vec([<sample_viewport_ $UI>](), 0..4),
+                    (x0, y0, x1, y1) in [<sample_ $sample_line _ $UI>](),
+                ) {
+                    let raw = $Line::<$UI>::new(x0, y0, x1, y1);
+                    $(let raw = raw.$unwrap();)?
+                    let visible = raw
+                        .map(|(x, y)| {
+                            let hidden = holes.iter().any(|hole| hole.point(x, y));
+                            (clip.point(x, y) && !hidden).then_some((x, y))
+                        })
+                        .collect::<Vec<_>>();
+                    let naive = visible
+                        .chunk_by(|a, b| a.is_some() == b.is_some())
+                        .filter(|run| run[0].is_some())
+                        .map(|run| run.iter().flatten().copied().collect::<Vec<_>>())
+                        .collect::<Vec<_>>();
+                    let smart = clip.[<$line _excluding>](&holes, x0, y0, x1, y1);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        let smart = smart.map(|piece| piece.collect::<Vec<_>>()).collect::<Vec<_>>();
+                        prop_assert_eq!(naive, smart);
+                    } else {
+                        prop_assert!(naive.is_empty());
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_excluding!(LineA + unwrap, line_ax, line_a, 100_000);
+test_excluding!(LineA + unwrap, line_ay, line_a, 100_000);
+test_excluding!(LineB, line_b, line_b, 100_000);
+test_excluding!(LineD + unwrap, line_d, line_d, 100_000);
+
+macro_rules! test_entry_exit {
+    ($N:literal) => {
+        test_entry_exit!(@ clip, u8, $N);
+        test_entry_exit!(@ clip, i8, $N);
+        test_entry_exit!(@ clip proj, i8, $N);
+        test_entry_exit!(@ viewport, u8, $N);
+        test_entry_exit!(@ viewport proj, u8, $N);
+        test_entry_exit!(@ viewport, i8, $N);
+        test_entry_exit!(@ viewport proj, i8, $N);
+    };
+    (@ $clip:ident, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _line_b_entry_exit_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, x1, y1) in [<sample_line_b_ $UI>](),
+                ) {
+                    let inside = LineB::<$UI>::new(x0, y0, x1, y1)
+                        .zip(0u8..)
+                        .filter(|&((x, y), _)| clip.point(x, y))
+                        .map(|((x, y), i)| (x, y, i))
+                        .collect::<Vec<_>>();
+                    let naive = inside.first().zip(inside.last()).map(|(&a, &b)| (a, b));
+                    prop_assert_eq!(clip.line_b_entry_exit(x0, y0, x1, y1), naive);
+                }
+            }
+        }
+    };
+    (@ $clip:ident proj, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _line_b_entry_exit_proj_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, x1, y1) in [<sample_line_b_ $UI>](),
+                ) {
+                    let inside = LineB::<$UI>::new(x0, y0, x1, y1)
+                        .zip(0u8..)
+                        .filter_map(|((x, y), i)| clip.point_proj(x, y).map(|(x, y)| (x, y, i)))
+                        .collect::<Vec<_>>();
+                    let naive = inside.first().zip(inside.last()).map(|(&a, &b)| (a, b));
+                    prop_assert_eq!(clip.line_b_entry_exit_proj(x0, y0, x1, y1), naive);
+                }
+            }
+        }
+    };
+}
+
+test_entry_exit!(1_000_000);
+
+macro_rules! test_classify {
+    ($N:literal) => {
+        test_classify!(@ clip, u8, $N);
+        test_classify!(@ clip, i8, $N);
+        test_classify!(@ viewport, u8, $N);
+        test_classify!(@ viewport, i8, $N);
+    };
+    (@ $clip:ident, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _classify_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, x1, y1) in [<sample_line_b_ $UI>](),
+                ) {
+                    let line = LineB::<$UI>::new(x0, y0, x1, y1);
+                    let inside = line.clone().filter(|&(x, y)| clip.point(x, y)).count();
+                    let naive = if inside == 0 {
+                        Visibility::Outside
+                    } else if inside == line.count() {
+                        Visibility::Inside
+                    } else {
+                        Visibility::Partial
+                    };
+                    prop_assert_eq!(clip.classify_line_b(x0, y0, x1, y1), naive);
+                    match clip.classify(x0, y0, x1, y1) {
+                        Visibility::Inside => prop_assert_ne!(naive, Visibility::Partial),
+                        Visibility::Outside => prop_assert_eq!(naive, Visibility::Outside),
+                        Visibility::Partial => {}
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_classify!(1_000_000);
+
+macro_rules! test_bezier {
+    ($N:literal) => {
+        test_bezier!(@ clip, u8, $N);
+        test_bezier!(@ clip, i8, $N);
+        test_bezier!(@ clip proj, i8, $N);
+        test_bezier!(@ viewport, u8, $N);
+        test_bezier!(@ viewport proj, u8, $N);
+        test_bezier!(@ viewport, i8, $N);
+        test_bezier!(@ viewport proj, i8, $N);
+    };
+    (@ $clip:ident, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _quad_bezier_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, x1, y1, x2, y2) in any::<($UI, $UI, $UI, $UI, $UI, $UI)>(),
+                ) {
+                    let raw = QuadBezier::<$UI>::new(x0, y0, x1, y1, x2, y2);
+                    let naive = raw.filter(|&(x, y)| clip.point(x, y));
+                    let smart = clip.quad_bezier(x0, y0, x1, y1, x2, y2);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+
+                #[test]
+                fn [<$clip _cubic_bezier_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, x1, y1) in any::<($UI, $UI, $UI, $UI)>(),
+                    (x2, y2, x3, y3) in any::<($UI, $UI, $UI, $UI)>(),
+                ) {
+                    let raw = CubicBezier::<$UI>::new(x0, y0, x1, y1, x2, y2, x3, y3);
+                    let naive = raw.filter(|&(x, y)| clip.point(x, y));
+                    let smart = clip.cubic_bezier(x0, y0, x1, y1, x2, y2, x3, y3);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+    (@ $clip:ident proj, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _quad_bezier_proj_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, x1, y1, x2, y2) in any::<($UI, $UI, $UI, $UI, $UI, $UI)>(),
+                ) {
+                    let raw = QuadBezier::<$UI>::new(x0, y0, x1, y1, x2, y2);
+                    let naive = raw.filter_map(|(x, y)| clip.point_proj(x, y));
+                    let smart = clip.quad_bezier_proj(x0, y0, x1, y1, x2, y2);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+
+                #[test]
+                fn [<$clip _cubic_bezier_proj_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, x1, y1) in any::<($UI, $UI, $UI, $UI)>(),
+                    (x2, y2, x3, y3) in any::<($UI, $UI, $UI, $UI)>(),
+                ) {
+                    let raw = CubicBezier::<$UI>::new(x0, y0, x1, y1, x2, y2, x3, y3);
+                    let naive = raw.filter_map(|(x, y)| clip.point_proj(x, y));
+                    let smart = clip.cubic_bezier_proj(x0, y0, x1, y1, x2, y2, x3, y3);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_bezier!(100_000);
+
+/// Checks that `points` form an 8-connected path from `p[0]` towards `p[N - 1]`,
+/// without consecutive repeated points, inside the bounding box of the control points.
+fn check_bezier_path<const N: usize>(
+    p: [(i8, i8); N],
+    points: &[(i8, i8)],
+) -> Result<(), TestCaseError> {
+    let end = p[N - 1];
+    if p.iter().all(|&q| q == end) {
+        prop_assert!(points.is_empty());
+        return Ok(());
+    }
+    let (&first, &last) = (points.first().unwrap_or(&end), points.last().unwrap_or(&p[0]));
+    prop_assert_eq!(first, p[0]);
+    prop_assert!(p[0] == end || last.0.abs_diff(end.0) <= 1 && last.1.abs_diff(end.1) <= 1);
+    for pair in points.windows(2).chain([[last, end].as_slice()]) {
+        let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+        prop_assert!(x0.abs_diff(x1).max(y0.abs_diff(y1)) <= 1, "gap");
+        prop_assert!(pair[0] != pair[1] || points.is_empty(), "repeat");
+    }
+    let (x_min, x_max) = (p.iter().map(|q| q.0).min(), p.iter().map(|q| q.0).max());
+    let (y_min, y_max) = (p.iter().map(|q| q.1).min(), p.iter().map(|q| q.1).max());
+    for &(x, y) in points {
+        prop_assert!(Some(x) >= x_min && Some(x) <= x_max && Some(y) >= y_min && Some(y) <= y_max);
+    }
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 100_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn shape_quad_bezier_i8((x0, y0, x1, y1, x2, y2) in any::<(i8, i8, i8, i8, i8, i8)>()) {
+        let points: Vec<_> = QuadBezier::<i8>::new(x0, y0, x1, y1, x2, y2).collect();
+        check_bezier_path([(x0, y0), (x1, y1), (x2, y2)], &points)?;
+    }
+
+    #[test]
+    fn shape_cubic_bezier_i8(
+        (x0, y0, x1, y1) in any::<(i8, i8, i8, i8)>(),
+        (x2, y2, x3, y3) in any::<(i8, i8, i8, i8)>(),
+    ) {
+        let points: Vec<_> = CubicBezier::<i8>::new(x0, y0, x1, y1, x2, y2, x3, y3).collect();
+        check_bezier_path([(x0, y0), (x1, y1), (x2, y2), (x3, y3)], &points)?;
+    }
+}
+
+#[test]
+fn bezier_entire_domain() {
+    let (min, max) = (i32::MIN, i32::MAX);
+    let curve = QuadBezier::<i32>::new(min, min, max, min, max, max);
+    assert!(curve.take(1000).eq((0..1000).map(|i| (min + i, min))));
+    let clip = Viewport::<i32>::from_min_max(min + 100, min, min + 200, min + 16).unwrap();
+    let curve = clip.quad_bezier(min, min, max, min, max, max).unwrap();
+    assert!(curve.eq((100..=200).map(|i| (min + i, min))));
+    let clip = Viewport::<i32>::from_min_max(max - 16, max - 16, max, max).unwrap();
+    let curve = clip.quad_bezier(min, min, max, min, max, max).unwrap();
+    assert!(curve.eq((max - 16..max).map(|y| (max, y))));
+    let (min, max) = (i16::MIN, i16::MAX);
+    let points: Vec<_> = CubicBezier::<i16>::new(min, min, max, min, min, max, max, max).collect();
+    assert_eq!(points.first(), Some(&(min, min)));
+    for pair in points.windows(2).chain([[*points.last().unwrap(), (max, max)].as_slice()]) {
+        let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+        assert_eq!(x0.abs_diff(x1).max(y0.abs_diff(y1)), 1);
+    }
+}
+
+macro_rules! test_bq {
+    ($F:literal, $N:literal) => {
+        test_bq!(@ clip, u8, $F, $N);
+        test_bq!(@ clip, i8, $F, $N);
+        test_bq!(@ clip proj, i8, $F, $N);
+        test_bq!(@ viewport, u8, $F, $N);
+        test_bq!(@ viewport proj, u8, $F, $N);
+        test_bq!(@ viewport, i8, $F, $N);
+        test_bq!(@ viewport proj, i8, $F, $N);
+        test_bq!(@ index, u8, $F, $N);
+        test_bq!(@ index, i8, $F, $N);
+    };
+    (@ index, $UI:ty, $F:literal, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<index_line_bq_ $F _ $UI>](
+                    (x0, y0, x1, y1) in any::<($UI, $UI, $UI, $UI)>(),
+                    i in any::<u8>(),
+                ) {
+                    let raw = LineBq::<$F, $UI>::new(x0, y0, x1, y1);
+                    let points = raw.clone().collect::<Vec<_>>();
+                    let len = points.len();
+                    prop_assert_eq!(usize::from(raw.len()), len, "len");
+                    prop_assert!(points.iter().rev().copied().eq(raw.clone().rev()), "rev");
+                    let at = points.get(usize::from(i)).copied();
+                    prop_assert_eq!(raw.point_at(i), at, "point_at");
+                    prop_assert_eq!(raw.clone().nth(usize::from(i)), at, "nth");
+                    let back = len.checked_sub(usize::from(i) + 1).map(|j| points[j]);
+                    prop_assert_eq!(raw.clone().nth_back(usize::from(i)), back, "nth_back");
+                    let mut skipped = raw.clone();
+                    let res = skipped.advance_by(i);
+                    prop_assert_eq!(res.is_ok(), usize::from(i) <= len, "advance_by");
+                    prop_assert!(skipped.eq(points.iter().copied().skip(usize::from(i))));
+                    if usize::from(i) <= len {
+                        let (head, tail) = raw.split_at(i);
+                        prop_assert_eq!(usize::from(head.len()), usize::from(i), "head.len()");
+                        prop_assert!(head.chain(tail).eq(points), "split_at");
+                    }
+                }
+            }
+        }
+    };
+    (@ $clip:ident, $UI:ty, $F:literal, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _line_bq_ $F _ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, x1, y1) in any::<($UI, $UI, $UI, $UI)>(),
+                ) {
+                    let raw = LineBq::<$F, $UI>::new(x0, y0, x1, y1);
+                    let naive = raw.filter(|&(x, y)| clip.point(x, y));
+                    let smart = clip.line_bq::<$F>(x0, y0, x1, y1);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        prop_assert_eq!(smart.len() as usize, smart.clone().count());
+                        prop_assert!(naive.clone().eq(smart.clone()), "naive != smart");
+                        prop_assert!(naive.rev().eq(smart.rev()), "naive.rev() != smart.rev()");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+    (@ $clip:ident proj, $UI:ty, $F:literal, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _line_bq_proj_ $F _ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, x1, y1) in any::<($UI, $UI, $UI, $UI)>(),
+                ) {
+                    let raw = LineBq::<$F, $UI>::new(x0, y0, x1, y1);
+                    let naive = raw.filter_map(|(x, y)| clip.point_proj(x, y));
+                    let smart = clip.line_bq_proj::<$F>(x0, y0, x1, y1);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_bq!(0, 100_000);
+test_bq!(3, 100_000);
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 100_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn line_bq_integer_i8((x0, y0, x1, y1) in any::<(i8, i8, i8, i8)>()) {
+        let line = LineBq::<0, i8>::new(x0, y0, x1, y1);
+        prop_assert!(line.eq(LineB::<i8>::new(x0, y0, x1, y1)));
+    }
+
+    #[test]
+    fn shape_line_bq_i16((x0, y0, x1, y1) in any::<(i16, i16, i16, i16)>()) {
+        let points: Vec<_> = LineBq::<8, i16>::new(x0, y0, x1, y1).collect();
+        for pair in points.windows(2) {
+            let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+            prop_assert_eq!(x0.abs_diff(x1).max(y0.abs_diff(y1)), 1, "gap");
+        }
+    }
+}
+
+#[test]
+fn line_bq_entire_domain() {
+    let (min, max) = (i64::MIN, i64::MAX);
+    let line = LineBq::<63, i64>::new(min, min, max, max - 1);
+    assert!(line.eq([(-1, -1), (0, 0)]));
+    let line = LineBq::<8, u64>::new(0, 128, u64::MAX, 128 + 256);
+    assert_eq!(line.clone().next(), Some((0, 1)));
+    assert_eq!(line.len(), (u64::MAX >> 8) + 1);
+    let clip = Clip::<u64>::from_max(u64::MAX >> 8, 1);
+    let line = clip.line_bq::<8>(0, 128, u64::MAX, 128 + 256).unwrap();
+    assert_eq!((line.head(), line.len()), (Some((0, 1)), (u64::MAX >> 8) + 1));
+    let viewport = Viewport::<u64>::from_min_max(u64::MAX >> 8, 0, u64::MAX, 1).unwrap();
+    let line = viewport.line_bq::<8>(0, 128, u64::MAX, 128 + 256).unwrap();
+    assert!(line.eq([(u64::MAX >> 8, 1)]));
+    let line = LineBq::<8, u64>::new(0, 128, u64::MAX, 128 + 256);
+    assert_eq!(line.tail(), Some((u64::MAX >> 8, 1)));
+    assert_eq!(line.point_at(1 << 55), Some((1 << 55, 1)));
+    let line = LineBq::<62, i64>::new(min, max, max, min);
+    let points: Vec<_> = line.clone().collect();
+    assert!(points.into_iter().rev().eq(line.rev()));
+}
+
+fn raster_line<L: RasterLine<i8>>(line: Option<L>) -> Option<Vec<(i8, i8)>> {
+    let mut line = line?;
+    let (len, head, tail) = (line.len(), line.head(), line.tail());
+    assert_eq!(line.is_empty(), len == 0);
+    let mut points = Vec::new();
+    while let Some(point) = line.pop_head() {
+        points.push(point);
+    }
+    assert_eq!(points.len(), usize::from(len));
+    assert_eq!((points.first().copied(), points.last().copied()), (head, tail));
+    Some(points)
+}
+
+macro_rules! test_raster_line {
+    (
+        $name:ident,
+        $Line:ty,
+        |$x0:ident, $y0:ident, $x1:ident, $y1:ident| $new:expr,
+        $method:ident($($arg:ident),+)
+    ) => {
+        test_raster_line!(
+            $name,
+            $Line,
+            |$x0, $y0, $x1, $y1| $new,
+            |region| region.$method($($arg),+)
+        );
+    };
+    (
+        $name:ident,
+        $Line:ty,
+        |$x0:ident, $y0:ident, $x1:ident, $y1:ident| $new:expr,
+        |$region:ident| $clipped:expr
+    ) => {
+        proptest! {
+            #![proptest_config(ProptestConfig {
+                cases: 100_000,
+                failure_persistence: None,
+                ..ProptestConfig::default()
+            })]
+            #[test]
+            fn $name(
+                clip in sample_clip_i8(),
+                viewport in sample_viewport_i8(),
+                ($x0, $y0, $x1, $y1) in any::<(i8, i8, i8, i8)>(),
+            ) {
+                let line = <$Line as RasterLine<i8>>::new($x0, $y0, $x1, $y1);
+                let expected: Option<$Line> = $new;
+                let ok = expected.is_some();
+                prop_assert_eq!(raster_line(line), raster_line(expected));
+                let line = clip.clip_line::<$Line>($x0, $y0, $x1, $y1);
+                let $region = &clip;
+                let expected = if ok { $clipped } else { None };
+                prop_assert_eq!(raster_line(line), raster_line(expected));
+                let line = viewport.clip_line::<$Line>($x0, $y0, $x1, $y1);
+                let $region = &viewport;
+                let expected = if ok { $clipped } else { None };
+                prop_assert_eq!(raster_line(line), raster_line(expected));
+            }
+        }
+    };
+}
+
+test_raster_line!(
+    raster_line_a_i8,
+    LineA<i8>,
+    |x0, y0, x1, y1| LineA::<i8>::new(x0, y0, x1, y1),
+    line_a(x0, y0, x1, y1)
+);
+test_raster_line!(
+    raster_line_ax_i8,
+    LineAx<i8>,
+    |x0, y0, x1, y1| (y0 == y1).then(|| LineAx::<i8>::new(y0, x0, x1)),
+    line_ax(y0, x0, x1)
+);
+test_raster_line!(
+    raster_line_ay_i8,
+    LineAy<i8>,
+    |x0, y0, x1, y1| (x0 == x1).then(|| LineAy::<i8>::new(x0, y0, y1)),
+    line_ay(x0, y0, y1)
+);
+test_raster_line!(
+    raster_line_b_i8,
+    LineB<i8>,
+    |x0, y0, x1, y1| Some(LineB::<i8>::new(x0, y0, x1, y1)),
+    line_b(x0, y0, x1, y1)
+);
+test_raster_line!(
+    raster_line_bx_i8,
+    LineBx<i8>,
+    |x0, y0, x1, y1| match LineB::<i8>::new(x0, y0, x1, y1) {
+        LineB::Bx(line) => Some(line),
+        LineB::By(_) => None,
+    },
+    |region| match region.line_b(x0, y0, x1, y1) {
+        Some(LineB::Bx(line)) => Some(line),
+        _ => None,
+    }
+);
+test_raster_line!(
+    raster_line_by_i8,
+    LineBy<i8>,
+    |x0, y0, x1, y1| match LineB::<i8>::new(x0, y0, x1, y1) {
+        LineB::By(line) => Some(line),
+        LineB::Bx(_) => None,
+    },
+    |region| match region.line_b(x0, y0, x1, y1) {
+        Some(LineB::By(line)) => Some(line),
+        _ => None,
+    }
+);
+test_raster_line!(
+    raster_line_bq_i8,
+    LineBq<3, i8>,
+    |x0, y0, x1, y1| Some(LineBq::<3, i8>::new(x0, y0, x1, y1)),
+    |region| region.line_bq::<3>(x0, y0, x1, y1)
+);
+test_raster_line!(
+    raster_line_d_i8,
+    LineD<i8>,
+    |x0, y0, x1, y1| LineD::<i8>::new(x0, y0, x1, y1),
+    line_d(x0, y0, x1, y1)
+);
+test_raster_line!(
+    raster_line_d2_i8,
+    LineD2<i8>,
+    |x0, y0, x1, y1| LineD2::<i8>::new(x0, y0, x1, y1),
+    line_d2(x0, y0, x1, y1)
+);
+
+fn coord_line_b<C: Coord>(clip: &Clip<C>, x0: C, y0: C, x1: C, y1: C) -> Vec<(C, C)> {
+    let Some(line) = clip.clip_line::<LineB<C>>(x0, y0, x1, y1) else {
+        return Vec::new();
+    };
+    assert_eq!(line.clone().rev().count(), line.size_hint().0);
+    line.collect()
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 100_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn coord_line_b_u8(
+        clip in sample_clip_u8(),
+        (x0, y0, x1, y1) in any::<(u8, u8, u8, u8)>(),
+    ) {
+        let naive: Vec<_> = clip.line_b(x0, y0, x1, y1).into_iter().flatten().collect();
+        prop_assert_eq!(coord_line_b(&clip, x0, y0, x1, y1), naive);
+    }
+
+    #[test]
+    fn coord_line_b_i8(
+        clip in sample_clip_i8(),
+        (x0, y0, x1, y1) in any::<(i8, i8, i8, i8)>(),
+    ) {
+        let naive: Vec<_> = clip.line_b(x0, y0, x1, y1).into_iter().flatten().collect();
+        prop_assert_eq!(coord_line_b(&clip, x0, y0, x1, y1), naive);
+    }
+}
+
+#[cfg(feature = "float")]
+prop_compose! {
+    fn sample_f64()(v in -160 * 16..160 * 16i32, huge in any::<bool>()) -> f64 {
+        if huge { f64::from(v) * 1e300 } else { f64::from(v) / 16.0 }
+    }
+}
+
+#[cfg(feature = "float")]
+macro_rules! test_line_f {
+    ($N:literal) => {
+        test_line_f!(@ clip, u8, $N);
+        test_line_f!(@ clip, i8, $N);
+        test_line_f!(@ clip proj, i8, $N);
+        test_line_f!(@ viewport, u8, $N);
+        test_line_f!(@ viewport proj, u8, $N);
+        test_line_f!(@ viewport, i8, $N);
+        test_line_f!(@ viewport proj, i8, $N);
+    };
+    (@ $clip:ident, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _line_f_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, x1, y1) in (sample_f64(), sample_f64(), sample_f64(), sample_f64()),
+                ) {
+                    let raw = LineF::<$UI>::new(x0, y0, x1, y1).unwrap();
+                    let naive = raw.filter(|&(x, y)| clip.point(x, y));
+                    let smart = clip.line_f(x0, y0, x1, y1);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        prop_assert_eq!(smart.len(), smart.clone().count() as u16);
+                        prop_assert!(smart.clone().rev().eq(naive.clone().rev()), "naive != smart");
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+    (@ $clip:ident proj, $UI:ty, $N:literal) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(ProptestConfig {
+                    cases: $N,
+                    failure_persistence: None,
+                    ..ProptestConfig::default()
+                })]
+                #[test]
+                fn [<$clip _line_f_proj_ $UI>](
+                    clip in [<sample_ $clip _ $UI>](),
+                    (x0, y0, x1, y1) in (sample_f64(), sample_f64(), sample_f64(), sample_f64()),
+                ) {
+                    let raw = LineF::<$UI>::new(x0, y0, x1, y1).unwrap();
+                    let naive = raw.filter_map(|(x, y)| clip.point_proj(x, y));
+                    let smart = clip.line_f_proj(x0, y0, x1, y1);
+                    if let Some(smart) = smart {
+                        prop_assert!(!smart.is_empty(), "clipped == empty");
+                        prop_assert!(naive.eq(smart), "naive != smart");
+                    } else {
+                        prop_assert_eq!(naive.count(), 0);
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "float")]
+test_line_f!(100_000);
+
+#[cfg(feature = "float")]
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 100_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn line_f_integer_i8((x0, y0, x1, y1) in any::<(i8, i8, i8, i8)>()) {
+        let (fx0, fy0) = (f64::from(x0), f64::from(y0));
+        let (fx1, fy1) = (f64::from(x1), f64::from(y1));
+        let line = LineF::<i8>::new(fx0, fy0, fx1, fy1).unwrap();
+        prop_assert!(line.eq(LineB::<i8>::new(x0, y0, x1, y1)));
+    }
+
+    #[test]
+    fn line_f_integer_i32(
+        (x0, y0) in any::<(i32, i32)>(),
+        (hu, hv) in any::<(i32, i32)>(),
+        yx in any::<bool>(),
+        (w, h) in (0..48i64, 0..48i64),
+    ) {
+        // The segment is a tie at its midpoint, around which the viewport lies.
+        let (du, dv) = (2 * i64::from(hu), 2 * i64::from(hv) + 1);
+        let (dx, dy) = if yx { (dv, du) } else { (du, dv) };
+        let (x1, y1) = (i64::from(x0) + dx, i64::from(y0) + dy);
+        let (Ok(x1), Ok(y1)) = (i32::try_from(x1), i32::try_from(y1)) else {
+            return Ok(());
+        };
+        let (min, max) = (i64::from(i32::MIN), i64::from(i32::MAX));
+        let x = (i64::from(x0) + dx / 2 - w / 2).clamp(min, max - w);
+        let y = (i64::from(y0) + dy / 2 - h / 2).clamp(min, max - h);
+        let (x, y, w, h) = (x as i32, y as i32, w as i32, h as i32);
+        let viewport = Viewport::<i32>::from_min_max(x, y, x + w, y + h).unwrap();
+        let (fx0, fy0, fx1, fy1) = (f64::from(x0), f64::from(y0), f64::from(x1), f64::from(y1));
+        let line_f = viewport.line_f(fx0, fy0, fx1, fy1).into_iter().flatten();
+        let line_b = viewport.line_b(x0, y0, x1, y1).into_iter().flatten();
+        prop_assert!(line_f.eq(line_b));
+    }
+
+    #[test]
+    fn line_f_fixed_i16((x0, y0, x1, y1) in any::<(i16, i16, i16, i16)>()) {
+        let (fx0, fy0) = (f64::from(x0) / 16.0, f64::from(y0) / 16.0);
+        let (fx1, fy1) = (f64::from(x1) / 16.0, f64::from(y1) / 16.0);
+        let line = LineF::<i16>::new(fx0, fy0, fx1, fy1).unwrap();
+        prop_assert!(line.eq(LineBq::<4, i16>::new(x0, y0, x1, y1)));
+    }
+
+    #[test]
+    fn shape_line_f_i8((x0, y0, x1, y1) in (sample_f64(), sample_f64(), sample_f64(), sample_f64())) {
+        let points: Vec<_> = LineF::<i8>::new(x0, y0, x1, y1).unwrap().collect();
+        for pair in points.windows(2) {
+            let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+            prop_assert_eq!(x0.abs_diff(x1).max(y0.abs_diff(y1)), 1, "gap");
+        }
+    }
+}
+
+/// Scales of a step of at most 8 that keep the endpoints exact and beyond 1e15.
+#[cfg(feature = "float")]
+const FAR: core::ops::RangeInclusive<i64> = 1_000_000_000_000_000..=1_125_000_000_000_000;
+
+#[cfg(feature = "float")]
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 100_000,
+        failure_persistence: None,
+        ..ProptestConfig::default()
+    })]
+    #[test]
+    fn line_f_far_endpoints_i32(
+        (px, py) in (-12..=12i64, -12..=12i64),
+        (dx, dy) in (-8..=8i64, -8..=8i64).prop_filter("zero", |&d| d != (0, 0)),
+        (s0, s1) in (FAR, FAR),
+    ) {
+        // The endpoints are exact, and the segment passes through `(px, py)`.
+        let (x0, y0) = ((px - s0 * dx) as f64, (py - s0 * dy) as f64);
+        let (x1, y1) = ((px + s1 * dx) as f64, (py + s1 * dy) as f64);
+        let clip = Viewport::<i32>::from_min_max(-8, -8, 8, 8).unwrap();
+        let points: Vec<_> = clip.line_f(x0, y0, x1, y1).into_iter().flatten().collect();
+        let (pu, pv, du, dv, yx) = if dx.abs() < dy.abs() {
+            (py, px, dy, dx, true)
+        } else {
+            (px, py, dx, dy, false)
+        };
+        let points = points.into_iter().map(|(x, y)| (i64::from(x), i64::from(y)));
+        let mut points = points.map(|(x, y)| if yx { (y, x) } else { (x, y) });
+        let mut next = points.next();
+        for i in 0..=16 {
+            let u = if du < 0 { 8 - i } else { i - 8 };
+            // The exact minor coordinate is `pv + n / d`: a tie may round either way.
+            let (n, d) = ((u - pu) * dv * du.signum(), du.abs());
+            let (v, r) = (pv + n.div_euclid(d), 2 * n.rem_euclid(d));
+            let rows = if r < d { v..=v } else if r > d { v + 1..=v + 1 } else { v..=v + 1 };
+            let rows = *rows.start().max(&-8)..=*rows.end().min(&8);
+            match next {
+                Some((a, b)) if a == u => {
+                    prop_assert!(rows.contains(&b), "{:?} off {:?}", (a, b), rows);
+                    next = points.next();
+                }
+                _ => prop_assert!(rows.is_empty() || r == d, "missing {}", u),
+            }
+        }
+        prop_assert_eq!(next, None);
+    }
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn line_f_integer_near_tie() {
+    let (x0, y0, x1, y1) = (-476176353, 598020586, 372512540, -265435738);
+    let viewport = Viewport::<i32>::from_min_max(-51831914, 166292416, -51831874, 166292456);
+    let viewport = viewport.unwrap();
+    let line_f = viewport.line_f(f64::from(x0), f64::from(y0), f64::from(x1), f64::from(y1));
+    let line_b = viewport.line_b(x0, y0, x1, y1).unwrap();
+    assert!(line_f.unwrap().eq(line_b));
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn line_f_far_endpoints() {
+    let clip = Viewport::<i32>::from_min_max(-5, -5, 5, 5).unwrap();
+    let line = clip.line_f(-1e20, -3e19, 1e20, 3e19).unwrap();
+    assert!(
+        line.filter(|&(x, _)| x.abs() < 5)
+            .eq((-4..5i32).map(|x| (x, (x * 3 + 5 * x.signum()) / 10)))
+    );
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn line_f_non_finite() {
+    assert!(LineF::<i8>::new(f64::NAN, 0.0, 1.0, 1.0).is_none());
+    assert!(LineF::<i8>::new(0.0, 0.0, f64::INFINITY, 1.0).is_none());
+    let clip = Clip::<i32>::from_max(15, 15).unwrap();
+    let line = clip.line_f(-1e12, -1e12, 1e12, 1e12).unwrap();
+    assert!(line.eq((0..16).map(|i| (i, i))));
+    let _ = clip.line_f(-1e300, -1e300, f64::MAX, 1e300).map(Iterator::count);
+}